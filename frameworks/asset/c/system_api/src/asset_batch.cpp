@@ -35,6 +35,25 @@ struct MutPairVec {
     size_t len;
 };
 
+struct MutBytes {
+    uint8_t *data;
+    size_t len;
+};
+
+struct CBatchItemResult {
+    uint32_t index;
+    int32_t errorCode;
+    MutBytes message;
+    MutBytes alias;
+    bool hasId;
+    uint32_t id;
+};
+
+struct MutBatchResultVec {
+    CBatchItemResult *data;
+    size_t len;
+};
+
 extern "C" {
 int32_t asset_batch_add(C2DArray &arr, MutPairVec &err_info);
 
@@ -43,8 +62,47 @@ int32_t asset_batch_remove(C2DArray &arr);
 int32_t asset_batch_update(C2DArray &arr, C2DArray &arr_to_update, MutPairVec &err_info);
 
 int32_t asset_free_mut_pair_vec(MutPairVec &vec);
+
+int32_t asset_batch_add_with_results(C2DArray &arr, MutBatchResultVec &results);
+
+int32_t asset_batch_remove_with_results(C2DArray &arr, MutBatchResultVec &results);
+
+int32_t asset_batch_update_with_results(C2DArray &arr, C2DArray &arr_to_update, MutBatchResultVec &results);
+
+int32_t asset_free_batch_result_vec(MutBatchResultVec &vec);
 };
 
+namespace {
+std::vector<CArray> ToCArrays(const std::vector<std::vector<AssetAttr>> &attrsArray)
+{
+    std::vector<CArray> cArrays;
+    for (const auto &inner : attrsArray) {
+        cArrays.push_back({
+            inner.data(),
+            inner.size()
+        });
+    }
+    return cArrays;
+}
+
+void ToBatchResults(MutBatchResultVec &outVec, std::vector<AssetBatchItemResult> &results)
+{
+    results.clear();
+    for (size_t i = 0; i < outVec.len; i++) {
+        const CBatchItemResult &item = outVec.data[i];
+        results.push_back({
+            item.index,
+            item.errorCode,
+            std::string(reinterpret_cast<const char *>(item.message.data), item.message.len),
+            std::vector<uint8_t>(item.alias.data, item.alias.data + item.alias.len),
+            item.hasId,
+            item.id
+        });
+    }
+    asset_free_batch_result_vec(outVec);
+}
+} // namespace
+
 int32_t AssetBatchAdd(std::vector<std::vector<AssetAttr>> &attrsArray,
     std::vector<std::pair<uint32_t, uint32_t>> &errInfoArray)
 {
@@ -124,3 +182,63 @@ int32_t AssetBatchUpdate(std::vector<std::vector<AssetAttr>> &attrsArray,
     asset_free_mut_pair_vec(outVec);
     return ret;
 }
+
+int32_t AssetBatchAddWithResults(std::vector<std::vector<AssetAttr>> &attrsArray,
+    std::vector<AssetBatchItemResult> &results)
+{
+    std::vector<CArray> cArrays = ToCArrays(attrsArray);
+    C2DArray arr {
+        cArrays.data(),
+        cArrays.size()
+    };
+    MutBatchResultVec outVec { nullptr, 0 };
+    int32_t ret = asset_batch_add_with_results(arr, outVec);
+    if (ret != SEC_ASSET_SUCCESS) {
+        return ret;
+    }
+    ToBatchResults(outVec, results);
+    return ret;
+}
+
+int32_t AssetBatchRemoveWithResults(std::vector<std::vector<AssetAttr>> &attrsArray,
+    std::vector<AssetBatchItemResult> &results)
+{
+    std::vector<CArray> cArrays = ToCArrays(attrsArray);
+    C2DArray arr {
+        cArrays.data(),
+        cArrays.size()
+    };
+    MutBatchResultVec outVec { nullptr, 0 };
+    int32_t ret = asset_batch_remove_with_results(arr, outVec);
+    if (ret != SEC_ASSET_SUCCESS) {
+        return ret;
+    }
+    ToBatchResults(outVec, results);
+    return ret;
+}
+
+int32_t AssetBatchUpdateWithResults(std::vector<std::vector<AssetAttr>> &attrsArray,
+    std::vector<std::vector<AssetAttr>> &attrsToUpdateArray,
+    std::vector<AssetBatchItemResult> &results)
+{
+    if (attrsArray.empty() || attrsToUpdateArray.empty()) {
+        return SEC_ASSET_INVALID_ARGUMENT;
+    }
+    std::vector<CArray> cArrays = ToCArrays(attrsArray);
+    C2DArray arr {
+        cArrays.data(),
+        cArrays.size()
+    };
+    std::vector<CArray> cArraysUpdate = ToCArrays(attrsToUpdateArray);
+    C2DArray arrToUpdate {
+        cArraysUpdate.data(),
+        cArraysUpdate.size()
+    };
+    MutBatchResultVec outVec { nullptr, 0 };
+    int32_t ret = asset_batch_update_with_results(arr, arrToUpdate, outVec);
+    if (ret != SEC_ASSET_SUCCESS) {
+        return ret;
+    }
+    ToBatchResults(outVec, results);
+    return ret;
+}
@@ -28,6 +28,8 @@ int32_t pre_query_asset(const AssetAttr *query, uint32_t query_cnt, AssetBlob *c
 int32_t query_asset(const AssetAttr *query, uint32_t query_cnt, AssetResultSet *result_set);
 int32_t post_query_asset(const AssetAttr *handle, uint32_t handle_cnt);
 int32_t query_sync_result(const AssetAttr *query, uint32_t query_cnt, AssetSyncResult *sync_result);
+int32_t count_asset(const AssetAttr *query, uint32_t query_cnt, uint32_t *count);
+int32_t exists_asset(const AssetAttr *query, uint32_t query_cnt, bool *exists);
 
 int32_t AssetAdd(const AssetAttr *attributes, uint32_t attrCnt)
 {
@@ -65,6 +67,16 @@ int32_t AssetQuerySyncResult(const AssetAttr *query, uint32_t queryCnt, AssetSyn
     return query_sync_result(query, queryCnt, syncResult);
 }
 
+int32_t AssetCount(const AssetAttr *query, uint32_t queryCnt, uint32_t *count)
+{
+    return count_asset(query, queryCnt, count);
+}
+
+int32_t AssetExists(const AssetAttr *query, uint32_t queryCnt, bool *exists)
+{
+    return exists_asset(query, queryCnt, exists);
+}
+
 AssetAttr *AssetParseAttr(const AssetResult *result, AssetTag tag)
 {
     if (result == NULL || result->attrs == NULL || result->count == 0) {
@@ -0,0 +1,386 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module provides typed builders that assemble the AssetMap for each Asset operation.
+
+use super::{
+    Accessibility, AssetMap, AuthType, ConflictResolution, CustomLabel, Extension, OperationType, OrderKey, Predicate,
+    ReturnOrder, ReturnType, SyncType, Tag, Value, WrapType,
+};
+
+/// Generate typed setters for byte array attributes.
+macro_rules! impl_bytes_setters {
+    ($($(#[$meta:meta])* $fn_name:ident => $tag:expr,)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $fn_name(mut self, value: &[u8]) -> Self {
+                self.map.insert_attr($tag, value.to_vec());
+                self
+            }
+        )*
+    };
+}
+
+/// Generate typed setters for attributes whose value is a bool, a number or an enum.
+macro_rules! impl_value_setters {
+    ($($(#[$meta:meta])* $fn_name:ident($ty:ty) => $tag:expr,)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $fn_name(mut self, value: $ty) -> Self {
+                self.map.insert_attr($tag, value);
+                self
+            }
+        )*
+    };
+}
+
+/// Generate setters for the user-defined custom labels, a label set again with the same name is replaced.
+macro_rules! impl_custom_label_setters {
+    ($($(#[$meta:meta])* $fn_name:ident => $tag:expr,)*) => {
+        $(
+            $(#[$meta])*
+            pub fn $fn_name(mut self, name: &[u8], value: &[u8]) -> Self {
+                let mut labels = match self.map.get(&$tag) {
+                    Some(Value::Bytes(bytes)) => CustomLabel::decode_list(bytes).unwrap_or_default(),
+                    _ => vec![],
+                };
+                labels.retain(|label| label.name != name);
+                labels.push(CustomLabel::new(name, value));
+                self.map.insert_attr($tag, CustomLabel::encode_list(&labels));
+                self
+            }
+        )*
+    };
+}
+
+/// Generate setters for the user-defined labels that are allowed to be updated.
+macro_rules! impl_normal_label_setters {
+    () => {
+        impl_bytes_setters! {
+            /// Set the first user-defined label (allow to update).
+            data_label_normal1 => Tag::DataLabelNormal1,
+            /// Set the second user-defined label (allow to update).
+            data_label_normal2 => Tag::DataLabelNormal2,
+            /// Set the third user-defined label (allow to update).
+            data_label_normal3 => Tag::DataLabelNormal3,
+            /// Set the fourth user-defined label (allow to update).
+            data_label_normal4 => Tag::DataLabelNormal4,
+            /// Set the first user-defined local label (allow to update), which will not be synchronized.
+            data_label_normal_local1 => Tag::DataLabelNormalLocal1,
+            /// Set the second user-defined local label (allow to update), which will not be synchronized.
+            data_label_normal_local2 => Tag::DataLabelNormalLocal2,
+            /// Set the third user-defined local label (allow to update), which will not be synchronized.
+            data_label_normal_local3 => Tag::DataLabelNormalLocal3,
+            /// Set the fourth user-defined local label (allow to update), which will not be synchronized.
+            data_label_normal_local4 => Tag::DataLabelNormalLocal4,
+        }
+
+        impl_custom_label_setters! {
+            /// Add a user-defined custom label with the name (allow to update).
+            data_label_custom_normal => Tag::DataLabelCustomNormal,
+            /// Add a user-defined custom local label with the name (allow to update), which will not be synchronized.
+            data_label_custom_normal_local => Tag::DataLabelCustomNormalLocal,
+        }
+    };
+}
+
+/// Generate setters for the label and access control attributes shared by adding, querying and removing.
+macro_rules! impl_common_setters {
+    () => {
+        impl_normal_label_setters!();
+
+        impl_bytes_setters! {
+            /// Set the first user-defined label (not allow to update).
+            data_label_critical1 => Tag::DataLabelCritical1,
+            /// Set the second user-defined label (not allow to update).
+            data_label_critical2 => Tag::DataLabelCritical2,
+            /// Set the third user-defined label (not allow to update).
+            data_label_critical3 => Tag::DataLabelCritical3,
+            /// Set the fourth user-defined label (not allow to update).
+            data_label_critical4 => Tag::DataLabelCritical4,
+            /// Set the group id the Asset belongs to.
+            group_id => Tag::GroupId,
+        }
+
+        impl_custom_label_setters! {
+            /// Add a user-defined custom label with the name (not allow to update).
+            data_label_custom_critical => Tag::DataLabelCustomCritical,
+        }
+
+        impl_value_setters! {
+            /// Set when the Asset can be accessed.
+            accessibility(Accessibility) => Tag::Accessibility,
+            /// Set whether a screen lock password is required to be set for the device.
+            require_password_set(bool) => Tag::RequirePasswordSet,
+            /// Set the user authentication type for Asset access control.
+            auth_type(AuthType) => Tag::AuthType,
+            /// Set whether the Asset is stored persistently.
+            is_persistent(bool) => Tag::IsPersistent,
+            /// Set whether the attributes of the Asset are required to be encrypted.
+            require_attr_encrypted(bool) => Tag::RequireAttrEncrypted,
+            /// Set the type of Asset encapsulation.
+            wrap_type(WrapType) => Tag::WrapType,
+            /// Set the specific user id.
+            user_id(u32) => Tag::UserId,
+        }
+
+        /// Add a type of Asset synchronization, the types set by repeated calls are combined.
+        pub fn sync_type(mut self, value: SyncType) -> Self {
+            let sync_type = match self.map.get(&Tag::SyncType) {
+                Some(Value::Number(origin)) => *origin | value as u32,
+                _ => value as u32,
+            };
+            self.map.insert_attr(Tag::SyncType, sync_type);
+            self
+        }
+    };
+}
+
+/// Generate the conversions from a builder to the AssetMap.
+macro_rules! impl_into_asset_map {
+    ($($name:ident),*) => {
+        $(
+            impl $name {
+                /// Get the AssetMap assembled so far.
+                pub fn as_map(&self) -> &AssetMap {
+                    &self.map
+                }
+
+                /// Consume the builder and get the assembled AssetMap.
+                pub fn build(self) -> AssetMap {
+                    self.map
+                }
+            }
+
+            impl From<$name> for AssetMap {
+                fn from(builder: $name) -> Self {
+                    builder.build()
+                }
+            }
+        )*
+    };
+}
+
+/// Builder of the attributes used to add an Asset.
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct AssetAttributes {
+    map: AssetMap,
+}
+
+impl AssetAttributes {
+    /// Create the builder with the required secret and alias.
+    pub fn new(secret: &[u8], alias: &[u8]) -> Self {
+        let mut map = AssetMap::new();
+        map.insert_attr(Tag::Secret, secret.to_vec());
+        map.insert_attr(Tag::Alias, alias.to_vec());
+        Self { map }
+    }
+
+    impl_common_setters!();
+
+    impl_value_setters! {
+        /// Set the strategy for resolving Asset conflicts.
+        conflict_resolution(ConflictResolution) => Tag::ConflictResolution,
+        /// Set the time (in seconds since the Unix epoch) after which the Asset expires.
+        expire_time(u32) => Tag::ExpireTime,
+        /// Set the maximum number of times the secret of the Asset can be read before it is removed.
+        max_access_count(u32) => Tag::MaxAccessCount,
+        /// Set whether the key protecting the Asset is invalidated when the biometric enrollment changes.
+        invalidate_on_biometric_change(bool) => Tag::InvalidateOnBiometricChange,
+        /// Set the synchronization operation type.
+        operation_type(OperationType) => Tag::OperationType,
+    }
+}
+
+/// Builder of the conditions used to query Assets, which is also used to count the Assets.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct QueryBuilder {
+    map: AssetMap,
+}
+
+impl QueryBuilder {
+    /// Create the builder without any condition.
+    pub fn new() -> Self {
+        Self { map: AssetMap::new() }
+    }
+
+    impl_common_setters!();
+
+    impl_bytes_setters! {
+        /// Set the alias of the Asset to be queried.
+        alias => Tag::Alias,
+        /// Set the authentication challenge returned by pre-query.
+        auth_challenge => Tag::AuthChallenge,
+        /// Set the authentication token after the user is verified.
+        auth_token => Tag::AuthToken,
+        /// Set the continuation token returned by the previous page of the paged query.
+        page_token => Tag::PageToken,
+    }
+
+    impl_value_setters! {
+        /// Set the return type of the queried Asset.
+        return_type(ReturnType) => Tag::ReturnType,
+        /// Set the direction in which the query results are sorted by the key set by `return_ordered_by`,
+        /// which is ascending by default.
+        return_order(ReturnOrder) => Tag::ReturnOrder,
+        /// Set the maximum number of returned Assets.
+        return_limit(u32) => Tag::ReturnLimit,
+        /// Set the offset of returned Assets.
+        return_offset(u32) => Tag::ReturnOffset,
+        /// Set the label or time by which the query results are sorted.
+        return_ordered_by(OrderKey) => Tag::ReturnOrderedBy,
+        /// Set the synchronization operation type.
+        operation_type(OperationType) => Tag::OperationType,
+    }
+
+    /// Set the labels or times by which the query results are sorted, each in its own direction,
+    /// the earlier key takes precedence.
+    pub fn return_ordered_by_keys(mut self, keys: &[(OrderKey, ReturnOrder)]) -> Self {
+        self.map.insert_attr(Tag::ReturnOrderedByKeys, OrderKey::encode_list(keys));
+        self
+    }
+
+    /// Match the Assets whose alias starts with the prefix.
+    pub fn alias_prefix(self, prefix: &[u8]) -> Self {
+        self.predicate(Predicate::Prefix(Tag::Alias, prefix))
+    }
+
+    /// Match the Assets whose user-defined label (allow to update) starts with the prefix.
+    pub fn label_prefix(self, label: Tag, prefix: &[u8]) -> Self {
+        self.predicate(Predicate::Prefix(label, prefix))
+    }
+
+    /// Match the Assets whose alias is one of the aliases.
+    pub fn aliases(self, aliases: &[&[u8]]) -> Self {
+        self.predicate(Predicate::In(Tag::Alias, aliases.to_vec()))
+    }
+
+    /// Match the Assets created within the inclusive range of milliseconds since the Unix epoch.
+    pub fn create_time_range(self, from: Option<u64>, to: Option<u64>) -> Self {
+        self.predicate(Predicate::Range(Tag::CreateTime, from, to))
+    }
+
+    /// Match the Assets updated within the inclusive range of milliseconds since the Unix epoch.
+    pub fn update_time_range(self, from: Option<u64>, to: Option<u64>) -> Self {
+        self.predicate(Predicate::Range(Tag::UpdateTime, from, to))
+    }
+
+    /// Add a predicate that the queried Assets must match in addition to the other predicates.
+    pub fn predicate(mut self, predicate: Predicate) -> Self {
+        let mut bytes = match self.map.get(&Tag::QueryFilter) {
+            Some(Value::Bytes(bytes)) => bytes.clone(),
+            _ => vec![],
+        };
+        bytes.extend(Predicate::encode_list(&[predicate]));
+        self.map.insert_attr(Tag::QueryFilter, bytes);
+        self
+    }
+}
+
+/// Builder of the conditions used to pre-query the Assets requiring user authentication.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct PreQueryBuilder {
+    map: AssetMap,
+}
+
+impl PreQueryBuilder {
+    /// Create the builder without any condition.
+    pub fn new() -> Self {
+        Self { map: AssetMap::new() }
+    }
+
+    impl_common_setters!();
+
+    impl_bytes_setters! {
+        /// Set the alias of the Asset to be pre-queried.
+        alias => Tag::Alias,
+    }
+
+    impl_value_setters! {
+        /// Set the validity period in seconds of user authentication.
+        auth_validity_period(u32) => Tag::AuthValidityPeriod,
+    }
+}
+
+/// Builder of the conditions used to remove Assets.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct RemoveBuilder {
+    map: AssetMap,
+}
+
+impl RemoveBuilder {
+    /// Create the builder without any condition.
+    pub fn new() -> Self {
+        Self { map: AssetMap::new() }
+    }
+
+    impl_common_setters!();
+
+    impl_bytes_setters! {
+        /// Set the alias of the Asset to be removed.
+        alias => Tag::Alias,
+    }
+
+    impl_value_setters! {
+        /// Set the synchronization operation type.
+        operation_type(OperationType) => Tag::OperationType,
+    }
+}
+
+/// Builder of the attributes used to update an Asset.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct AssetUpdate {
+    map: AssetMap,
+}
+
+impl AssetUpdate {
+    /// Create the builder without any attribute to update.
+    pub fn new() -> Self {
+        Self { map: AssetMap::new() }
+    }
+
+    impl_normal_label_setters!();
+
+    impl_bytes_setters! {
+        /// Set the new secret of the Asset.
+        secret => Tag::Secret,
+    }
+
+    /// Remove the user-defined custom label (allow to update) with the name.
+    pub fn remove_data_label_custom_normal(self, name: &[u8]) -> Self {
+        self.data_label_custom_normal(name, &[])
+    }
+
+    /// Remove the user-defined custom local label (allow to update) with the name.
+    pub fn remove_data_label_custom_normal_local(self, name: &[u8]) -> Self {
+        self.data_label_custom_normal_local(name, &[])
+    }
+
+    impl_value_setters! {
+        /// Set the synchronization operation type.
+        operation_type(OperationType) => Tag::OperationType,
+    }
+}
+
+impl_into_asset_map!(AssetAttributes, QueryBuilder, PreQueryBuilder, RemoveBuilder, AssetUpdate);
@@ -0,0 +1,95 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module defines the user-defined custom labels identified by name.
+//!
+//! The custom labels of one kind are carried by a single byte array tag, such as Tag::DataLabelCustomCritical.
+//! Each label is encoded as the length (4 bytes, little-endian) and content of its name, followed by those of its
+//! value. The labels are sorted by name, so that the same labels are always encoded to the same bytes.
+
+use super::{macros_lib, ErrCode, ErrReason, Result};
+
+const LEN_SIZE: usize = std::mem::size_of::<u32>();
+
+/// A user-defined label identified by its name.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Eq, Hash, PartialEq)]
+pub struct CustomLabel {
+    /// The name of the label.
+    pub name: Vec<u8>,
+    /// The value of the label.
+    pub value: Vec<u8>,
+}
+
+impl CustomLabel {
+    /// Create a custom label with the name and value.
+    pub fn new(name: &[u8], value: &[u8]) -> Self {
+        Self { name: name.to_vec(), value: value.to_vec() }
+    }
+
+    /// Encode the labels into a byte array, the labels are sorted by name.
+    pub fn encode_list(labels: &[CustomLabel]) -> Vec<u8> {
+        let mut sorted = labels.iter().collect::<Vec<_>>();
+        sorted.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut bytes = Vec::new();
+        for label in sorted {
+            for content in [&label.name, &label.value] {
+                bytes.extend((content.len() as u32).to_le_bytes());
+                bytes.extend(content);
+            }
+        }
+        bytes
+    }
+
+    /// Decode the labels from a byte array encoded by [`CustomLabel::encode_list`].
+    pub fn decode_list(bytes: &[u8]) -> Result<Vec<CustomLabel>> {
+        let pairs = Self::split_list(bytes)?;
+        Ok(pairs.into_iter().map(|(name, value)| CustomLabel::new(name, value)).collect())
+    }
+
+    /// Split a byte array encoded by [`CustomLabel::encode_list`] into the names and values of the labels,
+    /// which are borrowed from the byte array.
+    pub fn split_list(bytes: &[u8]) -> Result<Vec<(&[u8], &[u8])>> {
+        let mut pairs = Vec::new();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            let name = read_content(bytes, &mut pos)?;
+            let value = read_content(bytes, &mut pos)?;
+            pairs.push((name, value));
+        }
+        Ok(pairs)
+    }
+}
+
+fn read_content<'a>(bytes: &'a [u8], pos: &mut usize) -> Result<&'a [u8]> {
+    if bytes.len() - *pos < LEN_SIZE {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue, "[FATAL]The custom labels are truncated.");
+    }
+    let mut len_bytes = [0u8; LEN_SIZE];
+    len_bytes.copy_from_slice(&bytes[*pos..*pos + LEN_SIZE]);
+    *pos += LEN_SIZE;
+
+    let len = u32::from_le_bytes(len_bytes) as usize;
+    if bytes.len() - *pos < len {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue, "[FATAL]The custom labels are truncated.");
+    }
+    let content = &bytes[*pos..*pos + len];
+    *pos += len;
+    Ok(content)
+}
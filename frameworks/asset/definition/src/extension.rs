@@ -15,11 +15,11 @@
 
 //! This module extends the function of Asset data structure.
 
-use std::{collections::HashMap, fmt::Display, hash::Hash, io};
+use std::{collections::HashMap, fmt::Display, hash::Hash, io, sync::Arc};
 
 use super::{
-    macros_lib, Accessibility, AssetError, AuthType, Conversion, DataType, ErrCode, Extension,
-    Result, Tag, Value,
+    macros_lib, Accessibility, AssetError, AuthType, Conversion, DataType, ErrCode, ErrReason, Extension,
+    Result, SecretBytes, Tag, Value,
 };
 
 /// The mask used to obtain the data type of Asset attribute value.
@@ -87,6 +87,13 @@ impl Conversion for u32 {
     }
 }
 
+fn get_attr_failed_reason(value: Option<&Value>) -> ErrReason {
+    match value {
+        Some(_) => ErrReason::DataTypeMismatch,
+        None => ErrReason::MissingTag,
+    }
+}
+
 impl<K> Extension<K> for HashMap<K, Value>
 where
     K: Eq + PartialEq + Hash + std::fmt::Display,
@@ -96,38 +103,51 @@ where
     }
 
     fn get_bool_attr(&self, key: &K) -> Result<bool> {
-        if let Some(Value::Bool(b)) = self.get(key) {
-            Ok(*b)
-        } else {
-            macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]Get attribute of bool type failed, key: {}", key)
+        match self.get(key) {
+            Some(Value::Bool(b)) => Ok(*b),
+            value => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = get_attr_failed_reason(value),
+                "[FATAL]Get attribute of bool type failed, key: {}", key),
         }
     }
 
     fn get_enum_attr<T: TryFrom<u32, Error = AssetError>>(&self, key: &K) -> Result<T> {
-        if let Some(Value::Number(num)) = self.get(key) {
-            T::try_from(*num)
-        } else {
-            macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]Get attribute of enum type failed, key: {}", key)
+        match self.get(key) {
+            Some(Value::Number(num)) => T::try_from(*num),
+            value => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = get_attr_failed_reason(value),
+                "[FATAL]Get attribute of enum type failed, key: {}", key),
         }
     }
 
     fn get_num_attr(&self, key: &K) -> Result<u32> {
-        if let Some(Value::Number(num)) = self.get(key) {
-            Ok(*num)
-        } else {
-            macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]Get attribute of number type failed, key: {}", key)
+        match self.get(key) {
+            Some(Value::Number(num)) => Ok(*num),
+            value => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = get_attr_failed_reason(value),
+                "[FATAL]Get attribute of number type failed, key: {}", key),
         }
     }
 
     fn get_bytes_attr(&self, key: &K) -> Result<&Vec<u8>> {
-        if let Some(Value::Bytes(bytes)) = self.get(key) {
-            Ok(bytes)
-        } else {
-            macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]Get attribute of bytes type failed, key: {}", key)
+        match self.get(key) {
+            Some(Value::Bytes(bytes)) => Ok(bytes),
+            value => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = get_attr_failed_reason(value),
+                "[FATAL]Get attribute of bytes type failed, key: {}", key),
+        }
+    }
+
+    fn take_secret_attr(&mut self, key: &K) -> Result<SecretBytes> {
+        match self.get_mut(key) {
+            Some(Value::Bytes(bytes)) => {
+                let secret = SecretBytes::new(std::mem::take(bytes));
+                self.remove(key);
+                Ok(secret)
+            },
+            value => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = get_attr_failed_reason(value.map(|value| &*value)),
+                "[FATAL]Take attribute of bytes type failed, key: {}", key),
         }
     }
 }
@@ -138,6 +158,27 @@ impl Display for AssetError {
     }
 }
 
+impl std::error::Error for AssetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_deref().map(|e| e as &(dyn std::error::Error + 'static))
+    }
+}
+
+impl ErrReason {
+    /// Get the error code family the reason belongs to.
+    pub fn family(&self) -> Option<ErrCode> {
+        match (*self as u32) >> 8 {
+            0x01 => Some(ErrCode::InvalidArgument),
+            0x02 => Some(ErrCode::NotFound),
+            0x04 => Some(ErrCode::AccessDenied),
+            0x10 => Some(ErrCode::LimitExceeded),
+            0x11 => Some(ErrCode::Unsupported),
+            0x16 => Some(ErrCode::RevisionConflict),
+            _ => None,
+        }
+    }
+}
+
 impl AssetError {
     /// Create an AssetError instance.
     pub fn shorten_func_name(full_name: &'static str) -> &'static str {
@@ -155,28 +196,56 @@ impl AssetError {
     }
 
     /// Create a new AssetError.
+    #[allow(deprecated)]
     pub fn new(code: ErrCode, msg: String, func_name: &'static str) -> AssetError {
         AssetError {
             code,
             msg,
             call_chain: Self::shorten_func_name(func_name).to_string(),
+            reason: ErrReason::Unspecified,
+            source: None,
+            frames: vec![Self::shorten_func_name(func_name)],
         }
     }
 
+    /// Attach the machine-readable reason.
+    pub fn with_reason(mut self, reason: ErrReason) -> Self {
+        self.reason = reason;
+        self
+    }
+
+    /// Attach the lower-level error which caused this error.
+    pub fn with_source(mut self, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
     /// Track caller location when propagating error.
+    #[allow(deprecated)]
     pub fn track_internal(mut self, func_name: &'static str) -> Self {
+        let func_name = Self::shorten_func_name(func_name);
+        self.frames.push(func_name);
         self.call_chain.push_str(" <-- ");
-        self.call_chain.push_str(Self::shorten_func_name(func_name));
+        self.call_chain.push_str(func_name);
         self
     }
+
+    /// Get the call chain for error propagation. Format: "func1 <-- func2"
+    pub fn call_chain(&self) -> String {
+        self.frames.join(" <-- ")
+    }
 }
 
 impl From<io::Error> for AssetError {
+    #[allow(deprecated)]
     fn from(error: io::Error) -> Self {
         AssetError {
             code: ErrCode::FileOperationError,
             msg: format!("[FATAL]Backup db failed! error is [{error}]"),
             call_chain: String::new(),
+            reason: ErrReason::Unspecified,
+            source: Some(Arc::new(error)),
+            frames: Vec::new(),
         }
     }
 }
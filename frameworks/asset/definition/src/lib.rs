@@ -15,12 +15,22 @@
 
 //! This module defines asset-related data structures.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
+pub mod builder;
+pub mod custom_label;
 mod extension;
 #[macro_use]
 pub mod macros;
 pub mod macros_lib;
+pub mod query_filter;
+pub mod secret_bytes;
+pub mod serialization;
+
+pub use builder::{AssetAttributes, AssetUpdate, PreQueryBuilder, QueryBuilder, RemoveBuilder};
+pub use custom_label::CustomLabel;
+pub use query_filter::Predicate;
+pub use secret_bytes::SecretBytes;
 
 impl_enum_trait! {
     /// An enum type containing the data type definitions for Asset attribute value.
@@ -150,6 +160,58 @@ impl_tag_trait! {
         /// A tag whose value is a 32-bit unsigned integer indicating the type of Asset encapsulation.
         WrapType = DataType::Number as isize | 0x49,
 
+        /// A tag whose value is a 32-bit unsigned integer indicating the time (in seconds since the Unix epoch)
+        /// after which an Asset expires.
+        ExpireTime = DataType::Number as isize | 0x4A,
+
+        /// A tag whose value is a 32-bit unsigned integer indicating the maximum number of times the secret of an
+        /// Asset can be read, after which the Asset is removed.
+        MaxAccessCount = DataType::Number as isize | 0x4B,
+
+        /// A tag whose value is a byte array of the encoded user-defined custom labels (not allow to update).
+        DataLabelCustomCritical = DataType::Bytes as isize | 0x4C,
+
+        /// A tag whose value is a byte array of the encoded user-defined custom labels (allow to update),
+        /// and the label updated with an empty value is removed.
+        DataLabelCustomNormal = DataType::Bytes as isize | 0x4D,
+
+        /// A tag whose value is a byte array of the encoded user-defined custom labels (allow to update),
+        /// which will not be synchronized.
+        DataLabelCustomNormalLocal = DataType::Bytes as isize | 0x4E,
+
+        /// A tag whose value is a bool indicating whether the key protecting the Asset is invalidated
+        /// when the biometric enrollment (fingerprint or face) changes.
+        InvalidateOnBiometricChange = DataType::Bool as isize | 0x4F,
+
+        /// A tag whose value is a 32-bit unsigned integer indicating the status of the key protecting the Asset.
+        KeyStatus = DataType::Number as isize | 0x50,
+
+        /// A tag whose value is a byte array indicating the continuation token returned by the paged query.
+        PageToken = DataType::Bytes as isize | 0x51,
+
+        /// A tag whose value is a 32-bit unsigned integer indicating the revision of the Asset,
+        /// which increases each time the Asset is updated.
+        Revision = DataType::Number as isize | 0x52,
+
+        /// A tag whose value is a byte array indicating the create time of an Asset.
+        CreateTime = DataType::Bytes as isize | 0x53,
+
+        /// A tag whose value is a byte array of the encoded predicates that the queried Assets must match,
+        /// such as the prefix of the alias, the range of the update time or the list of aliases.
+        QueryFilter = DataType::Bytes as isize | 0x54,
+
+        /// A tag whose value is a 32-bit unsigned integer indicating the direction in which the query results are
+        /// sorted by the tag specified by [`Tag::ReturnOrderedBy`].
+        ReturnOrder = DataType::Number as isize | 0x55,
+
+        /// A tag whose value is a byte array of the tags by which the query results are sorted, each paired with the
+        /// direction of sorting, the earlier tag takes precedence.
+        ReturnOrderedByKeys = DataType::Bytes as isize | 0x56,
+
+        /// A tag whose value is a 32-bit unsigned integer indicating the remaining number of times the secret of an
+        /// Asset can be read, which is returned by the query only.
+        RemainingAccessCount = DataType::Number as isize | 0x57,
+
         /// A tag whose value is a 32-bit unsigned integer indicating the specific user id.
         UserId = DataType::Number as isize | 0x100,
     }
@@ -174,7 +236,7 @@ pub enum Value {
 impl Drop for Value {
     fn drop(&mut self) {
         if let Value::Bytes(bytes) = self {
-            bytes.fill(0);
+            secret_bytes::zeroize(bytes);
         }
     }
 }
@@ -254,6 +316,18 @@ impl_enum_trait! {
         /// Each value of Tag.GROUP_ID and Tag.REQUIRE_ATTR_ENCRYPTED in the array is not consistent.
         InconsistentAttribute = 24000019,
 
+        /// The error code indicates that the Asset has been removed because its access count is exhausted.
+        AccessCountExhausted = 24000020,
+
+        /// The error code indicates that the key protecting the Asset has been permanently invalidated.
+        KeyInvalidated = 24000021,
+
+        /// The error code indicates that the Asset has been modified since the expected revision.
+        RevisionConflict = 24000022,
+
+        /// The error code indicates that the request has been cancelled before it is sent to the service.
+        Canceled = 24000023,
+
         /// The HW Account error.
         HwAccountError = 24001001,
 
@@ -286,6 +360,105 @@ impl_enum_trait! {
     }
 }
 
+impl_enum_trait! {
+    /// An enum type containing the machine-readable reasons of the Asset errors.
+    /// The high byte of a reason identifies the error code family it belongs to, which is 0x01 for
+    /// [`ErrCode::InvalidArgument`] and the offset of the error code from 24000000 for the others.
+    #[derive(Clone, Copy)]
+    #[derive(Debug)]
+    #[derive(Eq, Hash, PartialEq)]
+    #[derive(Default)]
+    pub enum ErrReason {
+        /// The reason is not specified.
+        #[default]
+        Unspecified = 0,
+
+        /// The tag is not allowed in the current operation.
+        IllegalTag = 0x0101,
+
+        /// The required tag is missing.
+        MissingTag = 0x0102,
+
+        /// The data type of the value does not match that of the tag.
+        DataTypeMismatch = 0x0103,
+
+        /// The size of the byte array value is out of the valid range.
+        SizeOutOfRange = 0x0104,
+
+        /// The number value is not a legal enumeration variant.
+        InvalidEnumVariant = 0x0105,
+
+        /// The number value is out of the valid range.
+        NumberOutOfRange = 0x0106,
+
+        /// The number value is out of the valid bits.
+        BitsOutOfRange = 0x0107,
+
+        /// The number value is not a valid tag.
+        TagOutOfRange = 0x0108,
+
+        /// The specified user id does not exist.
+        UserIdNotExist = 0x0109,
+
+        /// The values of the tags conflict with each other.
+        ConflictingAttributes = 0x010A,
+
+        /// The byte array value is not well-formed.
+        MalformedValue = 0x010B,
+
+        /// The array contains items operating on the same Asset.
+        DuplicatedItem = 0x010C,
+
+        /// The Asset to operate on does not exist.
+        AssetNotFound = 0x0201,
+
+        /// The challenge does not exist or has expired.
+        ChallengeNotFound = 0x0202,
+
+        /// The verification of the auth token failed.
+        AuthTokenVerifyFailed = 0x0401,
+
+        /// The operation is out of the permission scope of the caller.
+        PermissionOutOfScope = 0x0402,
+
+        /// The number of the cached challenges exceeds the limit.
+        ChallengeLimitExceeded = 0x1001,
+
+        /// The number of the matched Assets exceeds the chunk size.
+        ChunkSizeExceeded = 0x1002,
+
+        /// The group is not supported for native processes.
+        GroupUnsupportedForNative = 0x1101,
+
+        /// The group is not supported for clone or sandbox applications.
+        GroupUnsupportedForCloneApp = 0x1102,
+
+        /// The tag is not supported by the service.
+        TagUnsupported = 0x1103,
+
+        /// The value of the tag is not supported by the service.
+        ValueUnsupported = 0x1104,
+
+        /// The feature or the operation is not supported.
+        FeatureUnsupported = 0x1105,
+
+        /// The caller does not support storing the data to be synchronized.
+        SyncDataUnsupported = 0x1106,
+
+        /// The caller does not support storing the data to be wrapped.
+        WrapDataUnsupported = 0x1107,
+
+        /// The Asset requiring user authentication is not supported by the operation.
+        AuthAssetUnsupported = 0x1108,
+
+        /// The Asset with limited access count is not supported by the operation.
+        LimitedAccessUnsupported = 0x1109,
+
+        /// The revision of the Asset does not match the expected one.
+        RevisionMismatch = 0x1601,
+    }
+}
+
 /// A struct containing the Asset result code and error message.
 #[derive(Clone, Debug)]
 pub struct AssetError {
@@ -296,7 +469,17 @@ pub struct AssetError {
     pub msg: String,
 
     /// Call chain for error propagation. Format: "func1 <-- func2"
+    #[deprecated(note = "use `frames` or `call_chain()` instead")]
     pub call_chain: String,
+
+    /// Machine-readable reason for error occurred.
+    pub reason: ErrReason,
+
+    /// The lower-level error which caused this error.
+    pub source: Option<Arc<dyn std::error::Error + Send + Sync>>,
+
+    /// Functions the error propagated through, from the origin to the outermost caller.
+    pub frames: Vec<&'static str>,
 }
 
 /// Alias of the Asset result type.
@@ -333,6 +516,18 @@ impl_enum_trait! {
         #[default]
         None = 0x00,
 
+        /// The access to an Asset requires user authentication using PIN/pattern/password only.
+        Pin = 0x01,
+
+        /// The access to an Asset requires user authentication using fingerprint only.
+        Fingerprint = 0x02,
+
+        /// The access to an Asset requires user authentication using face only.
+        Face = 0x04,
+
+        /// The access to an Asset requires user authentication using either fingerprint or face.
+        Biometric = 0x06,
+
         /// The access to an Asset requires user authentication using either PIN/pattern/password or biometric traits.
         Any = 0xFF,
     }
@@ -437,6 +632,120 @@ impl_enum_trait! {
         /// Specify that the return data contains only attributes.
         #[default]
         Attributes = 1,
+
+        /// Specify that the return data contains attributes and the status of the key protecting the Asset.
+        KeyStatus = 2,
+    }
+}
+
+impl_enum_trait! {
+    /// An enum type indicates the direction in which the query results are sorted.
+    #[derive(Clone, Copy)]
+    #[derive(Debug)]
+    #[derive(Default)]
+    #[derive(Eq, PartialEq)]
+    pub enum ReturnOrder {
+        /// Sort the query results in ascending order.
+        #[default]
+        Ascending = 0,
+
+        /// Sort the query results in descending order, such as the newest first when sorted by the update time.
+        Descending = 1,
+    }
+}
+
+impl_enum_trait! {
+    /// An enum type indicates the label or time by which the query results are sorted.
+    #[derive(Clone, Copy)]
+    #[derive(Debug)]
+    #[derive(Eq, PartialEq)]
+    pub enum OrderKey {
+        /// Sort by the first user-defined label (not allow to update).
+        DataLabelCritical1 = Tag::DataLabelCritical1 as isize,
+
+        /// Sort by the second user-defined label (not allow to update).
+        DataLabelCritical2 = Tag::DataLabelCritical2 as isize,
+
+        /// Sort by the third user-defined label (not allow to update).
+        DataLabelCritical3 = Tag::DataLabelCritical3 as isize,
+
+        /// Sort by the fourth user-defined label (not allow to update).
+        DataLabelCritical4 = Tag::DataLabelCritical4 as isize,
+
+        /// Sort by the first user-defined label (allow to update).
+        DataLabelNormal1 = Tag::DataLabelNormal1 as isize,
+
+        /// Sort by the second user-defined label (allow to update).
+        DataLabelNormal2 = Tag::DataLabelNormal2 as isize,
+
+        /// Sort by the third user-defined label (allow to update).
+        DataLabelNormal3 = Tag::DataLabelNormal3 as isize,
+
+        /// Sort by the fourth user-defined label (allow to update).
+        DataLabelNormal4 = Tag::DataLabelNormal4 as isize,
+
+        /// Sort by the first user-defined local label (allow to update).
+        DataLabelNormalLocal1 = Tag::DataLabelNormalLocal1 as isize,
+
+        /// Sort by the second user-defined local label (allow to update).
+        DataLabelNormalLocal2 = Tag::DataLabelNormalLocal2 as isize,
+
+        /// Sort by the third user-defined local label (allow to update).
+        DataLabelNormalLocal3 = Tag::DataLabelNormalLocal3 as isize,
+
+        /// Sort by the fourth user-defined local label (allow to update).
+        DataLabelNormalLocal4 = Tag::DataLabelNormalLocal4 as isize,
+
+        /// Sort by the time when the Asset is created.
+        CreateTime = Tag::CreateTime as isize,
+
+        /// Sort by the time when the Asset is last updated.
+        UpdateTime = Tag::UpdateTime as isize,
+    }
+}
+
+impl OrderKey {
+    /// Encode the sort keys into a byte array, each key is encoded as its tag (4 bytes, little-endian) followed by
+    /// the direction of sorting (4 bytes, little-endian).
+    pub fn encode_list(keys: &[(OrderKey, ReturnOrder)]) -> Vec<u8> {
+        keys.iter()
+            .flat_map(|(key, order)| [(*key as u32).to_le_bytes(), (*order as u32).to_le_bytes()])
+            .flatten()
+            .collect()
+    }
+
+    /// Decode the tags and the directions from a byte array encoded by [`OrderKey::encode_list`],
+    /// whether the tags are valid sort keys is left to the caller.
+    pub fn decode_list(bytes: &[u8]) -> Result<Vec<(Tag, ReturnOrder)>> {
+        let key_size = 2 * std::mem::size_of::<u32>();
+        if bytes.len() % key_size != 0 {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+                "[FATAL]The length[{}] of the sort keys is not a multiple of [{}].", bytes.len(), key_size);
+        }
+        bytes.chunks_exact(key_size)
+            .map(|chunk| Ok((
+                Tag::try_from(u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))?,
+                ReturnOrder::try_from(u32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]))?,
+            )))
+            .collect()
+    }
+}
+
+impl_enum_trait! {
+    /// An enum type indicates the status of the key protecting the Asset.
+    #[derive(Debug)]
+    #[derive(Clone, Copy)]
+    #[derive(PartialEq, Eq)]
+    pub enum KeyStatus {
+        /// The key is available for encryption and decryption.
+        Valid = 0,
+
+        /// The key has been permanently invalidated, for example, after the biometric enrollment changed.
+        Invalidated = 1,
+
+        /// The key does not exist.
+        Missing = 2,
     }
 }
 
@@ -456,6 +765,41 @@ impl_enum_trait! {
     }
 }
 
+impl_enum_trait! {
+    /// An enum type containing the optional features of the Asset service.
+    #[derive(Clone, Copy)]
+    #[derive(Debug)]
+    #[derive(Eq, PartialEq)]
+    pub enum Feature {
+        /// The batch operations of Assets.
+        Batch = 1 << 0,
+
+        /// The synchronization of Assets.
+        Sync = 1 << 1,
+
+        /// The encapsulation of Assets.
+        Wrap = 1 << 2,
+
+        /// The paged query of Assets.
+        QueryPage = 1 << 3,
+
+        /// The batch query of the secrets of Assets.
+        BatchQuery = 1 << 4,
+
+        /// The atomic transaction of the operations on Assets.
+        Transaction = 1 << 5,
+
+        /// The renaming of Assets, which re-encrypts the secrets on the service side.
+        Rename = 1 << 6,
+
+        /// The transfer of Assets between the private space of the application and its groups.
+        Transfer = 1 << 7,
+
+        /// The counting and the existence check of Assets, which read no attributes of the Assets.
+        Count = 1 << 8,
+    }
+}
+
 /// Expended abililty for HashMap.
 pub trait Extension<K> {
     /// Insert an attribute into the collection.
@@ -472,6 +816,9 @@ pub trait Extension<K> {
 
     /// Get an attribute of bytes type from the collection.
     fn get_bytes_attr(&self, key: &K) -> Result<&Vec<u8>>;
+
+    /// Remove an attribute of bytes type from the collection and take it over as a secret without copying.
+    fn take_secret_attr(&mut self, key: &K) -> Result<SecretBytes>;
 }
 
 /// Conversion between a specific type and the Asset Value type.
@@ -496,6 +843,156 @@ pub struct SyncResult {
     pub failed_count: u32,
 }
 
+/// A page of Assets returned by the paged query.
+#[derive(Debug)]
+#[derive(Default)]
+pub struct AssetPage {
+    /// The attributes of the Assets in the page.
+    pub assets: Vec<AssetMap>,
+    /// The opaque token used to query the next page, which is absent if the page is the last one.
+    pub next_token: Option<Vec<u8>>,
+}
+
+/// The result of an item in a batch operation.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Eq, PartialEq)]
+pub struct BatchItemResult {
+    /// The index of the item in the batch.
+    pub index: u32,
+    /// The error code of the item, which is absent if the item succeeds.
+    pub code: Option<ErrCode>,
+    /// The message describing why the item fails, which is empty if the item succeeds.
+    pub message: String,
+    /// The alias of the Asset that the item operates on.
+    pub alias: Vec<u8>,
+    /// The Id of the row added or updated by the item, which is absent if the item fails or removes the row.
+    pub id: Option<u32>,
+}
+
+impl BatchItemResult {
+    /// Create the result of a succeeded item.
+    pub fn success(index: u32, alias: Vec<u8>, id: Option<u32>) -> Self {
+        Self { index, code: None, message: String::new(), alias, id }
+    }
+
+    /// Create the result of a failed item.
+    pub fn failure(index: u32, alias: Vec<u8>, code: ErrCode, message: String) -> Self {
+        Self { index, code: Some(code), message, alias, id: None }
+    }
+
+    /// Check whether the item succeeds.
+    pub fn is_success(&self) -> bool {
+        self.code.is_none()
+    }
+
+    /// Collect the pairs of the error code and the index of the failed items, which is the layout of the result
+    /// returned by the batch operations of an earlier version.
+    pub fn into_err_info(results: &[BatchItemResult]) -> Vec<(u32, u32)> {
+        results.iter().filter_map(|item| item.code.map(|code| (code as u32, item.index))).collect()
+    }
+}
+
+/// The result of an item in a batch query.
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct BatchQueryItem {
+    /// The result of the item, the index of which is the index of the query matching the Asset.
+    pub result: BatchItemResult,
+    /// The attributes of the Asset queried, including the secret, which are empty if the item fails.
+    pub attributes: AssetMap,
+}
+
+/// The attributes of an Asset queried by the service, the secret of which is kept apart as [`SecretBytes`] until it
+/// is written to the reply, so that the plaintext is never held by a [`Value`] which can be cloned.
+#[derive(Debug)]
+pub struct AssetWithSecret {
+    /// The attributes of the Asset, excluding the secret.
+    pub attributes: AssetMap,
+    /// The secret of the Asset, which is absent if it is not queried.
+    pub secret: Option<SecretBytes>,
+}
+
+impl From<AssetMap> for AssetWithSecret {
+    fn from(attributes: AssetMap) -> Self {
+        Self { attributes, secret: None }
+    }
+}
+
+/// An operation in a transaction, the operations in which either all take effect or none does.
+#[derive(Clone)]
+#[derive(Debug)]
+pub enum TransactionOperation {
+    /// Add an Asset with the attributes.
+    Add(AssetMap),
+    /// Update the Asset matching the query with the attributes.
+    Update(AssetMap, AssetMap),
+    /// Remove the Assets matching the query.
+    Remove(AssetMap),
+}
+
+impl TransactionOperation {
+    /// Get the attributes to add, or the query to locate the Assets to update or remove.
+    pub fn attributes(&self) -> &AssetMap {
+        match self {
+            Self::Add(attributes) | Self::Update(attributes, _) | Self::Remove(attributes) => attributes,
+        }
+    }
+}
+
+/// The tags whose value is an enumeration, with the valid values of them.
+pub const ENUM_TAG_VALUES: [(Tag, &[u32]); 8] = [
+    (Tag::Accessibility, Accessibility::VALUES),
+    (Tag::AuthType, AuthType::VALUES),
+    (Tag::ConflictResolution, ConflictResolution::VALUES),
+    (Tag::ReturnType, ReturnType::VALUES),
+    (Tag::ReturnOrder, ReturnOrder::VALUES),
+    (Tag::OperationType, OperationType::VALUES),
+    (Tag::WrapType, WrapType::VALUES),
+    (Tag::KeyStatus, KeyStatus::VALUES),
+];
+
+/// The capabilities of the Asset service, which are used to negotiate the protocol between the client and the service.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct Capabilities {
+    /// The version of the IPC protocol.
+    pub protocol_version: u32,
+    /// The tags supported by the service.
+    pub supported_tags: Vec<Tag>,
+    /// The valid values of the tags whose value is an enumeration.
+    pub enum_values: Vec<(Tag, Vec<u32>)>,
+    /// The maximum size of the secret in bytes.
+    pub max_secret_size: u32,
+    /// The maximum size of a label in bytes.
+    pub max_label_size: u32,
+    /// The maximum number of Assets returned in a query.
+    pub max_return_limit: u32,
+    /// The bitmask of the optional features supported by the service.
+    pub features: u32,
+}
+
+impl Capabilities {
+    /// Check whether the tag is supported by the service.
+    pub fn supports_tag(&self, tag: Tag) -> bool {
+        self.supported_tags.contains(&tag)
+    }
+
+    /// Check whether the value of the tag is supported by the service, the values of non-enumeration tags always are.
+    pub fn supports_value(&self, tag: Tag, value: u32) -> bool {
+        match self.enum_values.iter().find(|(t, _)| *t == tag) {
+            Some((_, values)) => values.contains(&value),
+            None => true,
+        }
+    }
+
+    /// Check whether the optional feature is supported by the service.
+    pub fn supports_feature(&self, feature: Feature) -> bool {
+        self.features & feature as u32 != 0
+    }
+}
+
 /// The T is array and get array_len
 pub trait IsArray {
     /// the data type is array or not.
@@ -508,5 +1005,11 @@ impl<T> IsArray for Vec<T> {
     fn is_array(&self) -> bool { true }
     fn array_len(&self) -> usize { self.len() }
 }
+impl IsArray for AssetPage {
+    fn is_array(&self) -> bool { true }
+    fn array_len(&self) -> usize { self.assets.len() }
+}
 impl IsArray for SyncResult {}
 impl IsArray for () {}
+impl IsArray for u32 {}
+impl IsArray for bool {}
@@ -15,7 +15,7 @@
 
 //! This module defines the tool macro of the enumeration type.
 
-/// Macro to implement TryFrom and Display for enumeration types.
+/// Macro to implement TryFrom, Display and name lookup for enumeration types.
 ///
 /// # Examples
 ///
@@ -61,6 +61,26 @@ macro_rules! impl_tag_trait {
                 }
             }
         }
+
+        impl $name {
+            /// All the variants of the enumeration.
+            pub const ALL: &'static [$name] = &[$($name::$vname,)*];
+
+            /// Get the name of the variant.
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $($name::$vname => stringify!($vname),)*
+                }
+            }
+
+            /// Find the variant by its name.
+            pub fn from_name(name: &str) -> Option<Self> {
+                match name {
+                    $(x if x == stringify!($vname) => Some($name::$vname),)*
+                    _ => None,
+                }
+            }
+        }
     }
 }
 
@@ -111,6 +131,11 @@ macro_rules! impl_enum_trait {
             }
         }
 
+        impl $name {
+            /// The values of all the variants of the enumeration.
+            pub const VALUES: &'static [u32] = &[$($name::$vname as u32,)*];
+        }
+
         impl macros_lib::Conversion for $name {
             fn data_type(&self) -> macros_lib::DataType {
                 macros_lib::DataType::Number
@@ -129,9 +154,16 @@ macro_rules! impl_enum_trait {
 ///
 /// ```
 /// log_throw_error!(macros_lib::hisysevent::function!(), ErrCode::InvalidArgument, "hello, {}", "world");
+/// log_throw_error!(macros_lib::hisysevent::function!(), ErrCode::InvalidArgument,
+///     reason = ErrReason::MissingTag, "hello, {}", "world");
 /// ```
 #[macro_export]
 macro_rules! log_throw_error {
+    ($func:expr, $code:expr, reason = $reason:expr, $($arg:tt)*) => {{
+        let str = format!($($arg)*);
+        macros_lib::loge!("{}", str);
+        Err(macros_lib::AssetError::new($code, str, $func).with_reason($reason))
+    }};
     ($func:expr, $code:expr, $($arg:tt)*) => {{
         let str = format!($($arg)*);
         macros_lib::loge!("{}", str);
@@ -148,6 +180,11 @@ macro_rules! log_throw_error {
 /// ```
 #[macro_export]
 macro_rules! log_and_into_asset_error {
+    ($func:expr, $code:expr, reason = $reason:expr, $($arg:tt)*) => {{
+        let str = format!($($arg)*);
+        macros_lib::loge!("{}", str);
+        macros_lib::AssetError::new($code, str, $func).with_reason($reason)
+    }};
     ($func:expr, $code:expr, $($arg:tt)*) => {{
         let str = format!($($arg)*);
         macros_lib::loge!("{}", str);
@@ -164,6 +201,10 @@ macro_rules! log_and_into_asset_error {
 /// ```
 #[macro_export]
 macro_rules! throw_error {
+    ($func:expr, $code:expr, reason = $reason:expr, $($arg:tt)*) => {{
+        let str = format!($($arg)*);
+        Err(macros_lib::AssetError::new($code, str, $func).with_reason($reason))
+    }};
     ($func:expr, $code:expr, $($arg:tt)*) => {{
         let str = format!($($arg)*);
         Err(macros_lib::AssetError::new($code, str, $func))
@@ -0,0 +1,142 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module defines the predicates of a query, which are matched in addition to the exact-match conditions.
+//!
+//! The predicates of a query are carried by the byte array tag Tag::QueryFilter, and all of them must be matched.
+//! Each predicate is encoded as its kind, its tag and the count of its operands (4 bytes each, little-endian),
+//! followed by the length (4 bytes, little-endian) and content of each operand. The bound of a range is encoded as
+//! 8 bytes (little-endian), or as empty content if the range is not bounded on that side.
+
+use super::{macros_lib, ErrCode, ErrReason, Result, Tag};
+
+const LEN_SIZE: usize = std::mem::size_of::<u32>();
+const BOUND_SIZE: usize = std::mem::size_of::<u64>();
+const RANGE_OPERAND_COUNT: usize = 2;
+
+const KIND_PREFIX: u32 = 0;
+const KIND_RANGE: u32 = 1;
+const KIND_IN: u32 = 2;
+
+/// A predicate of a query, the operands of which are borrowed from the encoded byte array.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Eq, PartialEq)]
+pub enum Predicate<'a> {
+    /// The value of the tag starts with the prefix.
+    Prefix(Tag, &'a [u8]),
+    /// The value of the tag, which is a time in milliseconds, is within the inclusive bounds.
+    Range(Tag, Option<u64>, Option<u64>),
+    /// The value of the tag is one of the values.
+    In(Tag, Vec<&'a [u8]>),
+}
+
+impl<'a> Predicate<'a> {
+    /// Get the tag matched by the predicate.
+    pub fn tag(&self) -> Tag {
+        match self {
+            Predicate::Prefix(tag, _) | Predicate::Range(tag, _, _) | Predicate::In(tag, _) => *tag,
+        }
+    }
+
+    /// Encode the predicates into a byte array, the predicates encoded separately can be concatenated.
+    pub fn encode_list(predicates: &[Predicate]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        for predicate in predicates {
+            let (kind, operands) = match predicate {
+                Predicate::Prefix(_, prefix) => (KIND_PREFIX, vec![prefix.to_vec()]),
+                Predicate::Range(_, from, to) => (KIND_RANGE, vec![encode_bound(from), encode_bound(to)]),
+                Predicate::In(_, values) => (KIND_IN, values.iter().map(|value| value.to_vec()).collect()),
+            };
+            bytes.extend(kind.to_le_bytes());
+            bytes.extend((predicate.tag() as u32).to_le_bytes());
+            bytes.extend((operands.len() as u32).to_le_bytes());
+            for operand in operands {
+                bytes.extend((operand.len() as u32).to_le_bytes());
+                bytes.extend(operand);
+            }
+        }
+        bytes
+    }
+
+    /// Decode the predicates from a byte array encoded by [`Predicate::encode_list`].
+    pub fn decode_list(bytes: &'a [u8]) -> Result<Vec<Predicate<'a>>> {
+        let mut predicates = Vec::new();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            let kind = read_u32(bytes, &mut pos)?;
+            let tag = Tag::try_from(read_u32(bytes, &mut pos)?)?;
+            let count = read_u32(bytes, &mut pos)? as usize;
+            let mut operands = Vec::new();
+            for _ in 0..count {
+                operands.push(read_content(bytes, &mut pos)?);
+            }
+            let predicate = match kind {
+                KIND_PREFIX if count == 1 => Predicate::Prefix(tag, operands[0]),
+                KIND_RANGE if count == RANGE_OPERAND_COUNT => {
+                    Predicate::Range(tag, decode_bound(operands[0])?, decode_bound(operands[1])?)
+                },
+                KIND_IN => Predicate::In(tag, operands),
+                _ => {
+                    return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                        ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+                        "[FATAL]The predicate of kind[{}] with [{}] operands is invalid.", kind, count);
+                },
+            };
+            predicates.push(predicate);
+        }
+        Ok(predicates)
+    }
+}
+
+fn encode_bound(bound: &Option<u64>) -> Vec<u8> {
+    bound.map(|bound| bound.to_le_bytes().to_vec()).unwrap_or_default()
+}
+
+fn decode_bound(bytes: &[u8]) -> Result<Option<u64>> {
+    match bytes.len() {
+        0 => Ok(None),
+        BOUND_SIZE => {
+            let mut bound_bytes = [0u8; BOUND_SIZE];
+            bound_bytes.copy_from_slice(bytes);
+            Ok(Some(u64::from_le_bytes(bound_bytes)))
+        },
+        _ => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+            "[FATAL]The bound of the range is [{}] bytes.", bytes.len()),
+    }
+}
+
+fn read_u32(bytes: &[u8], pos: &mut usize) -> Result<u32> {
+    if bytes.len() - *pos < LEN_SIZE {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue, "[FATAL]The query filter is truncated.");
+    }
+    let mut u32_bytes = [0u8; LEN_SIZE];
+    u32_bytes.copy_from_slice(&bytes[*pos..*pos + LEN_SIZE]);
+    *pos += LEN_SIZE;
+    Ok(u32::from_le_bytes(u32_bytes))
+}
+
+fn read_content<'a>(bytes: &'a [u8], pos: &mut usize) -> Result<&'a [u8]> {
+    let len = read_u32(bytes, pos)? as usize;
+    if bytes.len() - *pos < len {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue, "[FATAL]The query filter is truncated.");
+    }
+    let content = &bytes[*pos..*pos + len];
+    *pos += len;
+    Ok(content)
+}
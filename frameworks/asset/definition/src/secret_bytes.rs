@@ -0,0 +1,192 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module defines the byte array type holding the plaintext secret of an Asset.
+
+use std::{
+    ffi::c_void,
+    fmt,
+    ops::Deref,
+    ptr,
+    sync::atomic::{compiler_fence, Ordering},
+};
+
+use super::{Conversion, DataType, Value};
+
+extern "C" {
+    fn mlock(addr: *const c_void, len: usize) -> i32;
+    fn munlock(addr: *const c_void, len: usize) -> i32;
+}
+
+/// Overwrite the bytes with zeros, which is not optimized away by the compiler.
+pub(crate) fn zeroize(bytes: &mut [u8]) {
+    for byte in bytes.iter_mut() {
+        // SAFETY: the pointer comes from a valid mutable reference.
+        unsafe { ptr::write_volatile(byte, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// A byte array holding a secret, which is zeroed when it is dropped or reallocated.
+///
+/// The type does not implement Clone, so copying a secret is always explicit through [`SecretBytes::duplicate`].
+/// The equality comparison takes constant time for the inputs of the same length.
+pub struct SecretBytes {
+    buf: Vec<u8>,
+    locked: bool,
+}
+
+impl SecretBytes {
+    /// Take over the bytes as a secret without copying.
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self { buf: bytes, locked: false }
+    }
+
+    /// Create a secret filled with zeros, which is used as the output buffer of decryption.
+    pub fn zeroed(len: usize) -> Self {
+        Self::new(vec![0; len])
+    }
+
+    /// Copy the bytes into a secret.
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        Self::new(bytes.to_vec())
+    }
+
+    /// Lock the memory of the secret to prevent it from being swapped out, return whether the lock succeeds.
+    pub fn lock_memory(&mut self) -> bool {
+        if self.locked || self.buf.capacity() == 0 {
+            return self.locked;
+        }
+        // SAFETY: the range is the allocation owned by the buffer.
+        self.locked = unsafe { mlock(self.buf.as_ptr() as *const c_void, self.buf.capacity()) } == 0;
+        self.locked
+    }
+
+    /// Check whether the memory of the secret is locked.
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Get the buffer of the secret without copying, for the interfaces taking a byte vector, e.g. the IPC parcel.
+    pub fn as_vec(&self) -> &Vec<u8> {
+        &self.buf
+    }
+
+    /// Get the mutable bytes of the secret.
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        self.buf.as_mut_slice()
+    }
+
+    /// Shorten the secret and zero the removed bytes.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.buf.len() {
+            zeroize(&mut self.buf[len..]);
+            self.buf.truncate(len);
+        }
+    }
+
+    /// Append the bytes to the secret. The previous buffer is zeroed if it has to be reallocated.
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        let len = self.buf.len() + bytes.len();
+        if len <= self.buf.capacity() {
+            self.buf.extend_from_slice(bytes);
+            return;
+        }
+
+        let mut buf = Vec::with_capacity(len.max(self.buf.capacity() * 2));
+        buf.extend_from_slice(&self.buf);
+        buf.extend_from_slice(bytes);
+        let relock = self.locked;
+        self.release();
+        self.buf = buf;
+        if relock {
+            self.lock_memory();
+        }
+    }
+
+    /// Copy the secret explicitly.
+    pub fn duplicate(&self) -> Self {
+        Self::from_slice(&self.buf)
+    }
+
+    /// Compare the secret with the bytes in constant time.
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        if self.buf.len() != other.len() {
+            return false;
+        }
+        let diff = self.buf.iter().zip(other.iter()).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        // SAFETY: the pointer comes from a valid reference, reading it volatilely keeps the loop from shortcut.
+        unsafe { ptr::read_volatile(&diff) == 0 }
+    }
+
+    fn release(&mut self) {
+        zeroize(&mut self.buf);
+        if self.locked {
+            // SAFETY: the range is the allocation locked in lock_memory.
+            unsafe { munlock(self.buf.as_ptr() as *const c_void, self.buf.capacity()) };
+            self.locked = false;
+        }
+    }
+}
+
+impl Drop for SecretBytes {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+impl Deref for SecretBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+impl From<Vec<u8>> for SecretBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl PartialEq for SecretBytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(&other.buf)
+    }
+}
+
+impl Eq for SecretBytes {}
+
+impl fmt::Debug for SecretBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretBytes(<redacted>, len: {})", self.buf.len())
+    }
+}
+
+impl Conversion for SecretBytes {
+    fn data_type(&self) -> DataType {
+        DataType::Bytes
+    }
+
+    /// Move the buffer into the value without copying, the value zeroes it when dropped.
+    fn into_value(mut self) -> Value {
+        if self.locked {
+            // SAFETY: the range is the allocation locked in lock_memory.
+            unsafe { munlock(self.buf.as_ptr() as *const c_void, self.buf.capacity()) };
+            self.locked = false;
+        }
+        Value::Bytes(std::mem::take(&mut self.buf))
+    }
+}
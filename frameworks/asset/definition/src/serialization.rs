@@ -0,0 +1,269 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module implements the serde-based export format of Tag, Value and AssetMap.
+//!
+//! Tags are written with their names, such as "Alias". Byte arrays are written as hex strings in human-readable
+//! formats (such as JSON) and as raw bytes in binary formats (such as CBOR).
+//! The values of [`SENSITIVE_TAGS`] are redacted by default, the unredacted export is only for the trusted storage.
+
+use std::fmt;
+
+use serde::{
+    de::{self, Deserializer, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, Serializer},
+    Deserialize, Serialize,
+};
+
+use super::{macros_lib, AssetMap, ErrCode, ErrReason, Result, Tag, Value};
+
+/// The placeholder written in place of the sensitive bytes.
+pub const REDACTED_PLACEHOLDER: &str = "<redacted>";
+
+/// The tags whose values are never written by the redacting serializer.
+pub const SENSITIVE_TAGS: [Tag; 3] = [Tag::Secret, Tag::AuthToken, Tag::AuthChallenge];
+
+impl Serialize for Tag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct TagVisitor;
+
+        impl Visitor<'_> for TagVisitor {
+            type Value = Tag;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "the name of an Asset tag")
+            }
+
+            fn visit_str<E: de::Error>(self, name: &str) -> std::result::Result<Tag, E> {
+                Tag::from_name(name).ok_or_else(|| E::custom(format!("unknown tag name [{}]", name)))
+            }
+        }
+
+        deserializer.deserialize_str(TagVisitor)
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [high, low] => Some(((*high as char).to_digit(16)? << 4 | (*low as char).to_digit(16)?) as u8),
+            _ => None,
+        })
+        .collect()
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Value::Bool(b) => serializer.serialize_bool(*b),
+            Value::Number(n) => serializer.serialize_u32(*n),
+            Value::Bytes(bytes) if serializer.is_human_readable() => serializer.serialize_str(&to_hex(bytes)),
+            Value::Bytes(bytes) => serializer.serialize_bytes(bytes),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct ValueVisitor;
+
+        impl<'de> Visitor<'de> for ValueVisitor {
+            type Value = Value;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a bool, a 32-bit unsigned integer or a byte array")
+            }
+
+            fn visit_bool<E: de::Error>(self, v: bool) -> std::result::Result<Value, E> {
+                Ok(Value::Bool(v))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> std::result::Result<Value, E> {
+                u32::try_from(v).map(Value::Number).map_err(|_| E::custom(format!("number [{}] exceeds u32", v)))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> std::result::Result<Value, E> {
+                u32::try_from(v).map(Value::Number).map_err(|_| E::custom(format!("number [{}] exceeds u32", v)))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> std::result::Result<Value, E> {
+                from_hex(v).map(Value::Bytes).ok_or_else(|| E::custom("invalid hex string"))
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> std::result::Result<Value, E> {
+                Ok(Value::Bytes(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> std::result::Result<Value, E> {
+                Ok(Value::Bytes(v))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<Value, A::Error> {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element::<u8>()? {
+                    bytes.push(byte);
+                }
+                Ok(Value::Bytes(bytes))
+            }
+        }
+
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+fn sorted_entries(map: &AssetMap) -> Vec<(&Tag, &Value)> {
+    let mut entries: Vec<(&Tag, &Value)> = map.iter().collect();
+    entries.sort_by_key(|(tag, _)| **tag as u32);
+    entries
+}
+
+fn serialize_sorted<S>(map: &AssetMap, redact: bool, serializer: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut state = serializer.serialize_map(Some(map.len()))?;
+    for (tag, value) in sorted_entries(map) {
+        if redact && SENSITIVE_TAGS.contains(tag) {
+            state.serialize_entry(tag, REDACTED_PLACEHOLDER)?;
+        } else {
+            state.serialize_entry(tag, value)?;
+        }
+    }
+    state.end()
+}
+
+/// A wrapper that serializes an AssetMap with the values of [`SENSITIVE_TAGS`] replaced by a placeholder.
+pub struct Redacted<'a>(pub &'a AssetMap);
+
+impl Serialize for Redacted<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serialize_sorted(self.0, true, serializer)
+    }
+}
+
+/// A wrapper that serializes an AssetMap including the values of [`SENSITIVE_TAGS`].
+pub struct Unredacted<'a>(pub &'a AssetMap);
+
+impl Serialize for Unredacted<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serialize_sorted(self.0, false, serializer)
+    }
+}
+
+/// A wrapper that deserializes an AssetMap and rejects the tags appearing more than once.
+struct Strict(AssetMap);
+
+impl<'de> Deserialize<'de> for Strict {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct MapVisitor;
+
+        impl<'de> Visitor<'de> for MapVisitor {
+            type Value = Strict;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a map from the Asset tag names to the values")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> std::result::Result<Strict, A::Error> {
+                let mut map = AssetMap::with_capacity(access.size_hint().unwrap_or(0));
+                while let Some((tag, value)) = access.next_entry::<Tag, Value>()? {
+                    if map.insert(tag, value).is_some() {
+                        return Err(de::Error::custom(format!("duplicated tag [{}]", tag.name())));
+                    }
+                }
+                Ok(Strict(map))
+            }
+        }
+
+        deserializer.deserialize_map(MapVisitor)
+    }
+}
+
+fn check_value_types(map: &AssetMap) -> Result<()> {
+    for (tag, value) in map.iter() {
+        if macros_lib::Conversion::data_type(tag) != macros_lib::Conversion::data_type(value) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch,
+                "[FATAL]The value type of tag [{}] mismatches.", tag);
+        }
+    }
+    Ok(())
+}
+
+/// Export the AssetMap into a JSON string with the sensitive values redacted.
+pub fn to_json(map: &AssetMap) -> Result<String> {
+    ylong_json::to_string(&Redacted(map)).map_err(|e| macros_lib::log_and_into_asset_error!(
+        macros_lib::hisysevent::function!(), ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+        "[FATAL]Export asset map to json failed, err: {}", e))
+}
+
+/// Export the AssetMap into a JSON string including the sensitive values.
+pub fn to_json_unredacted(map: &AssetMap) -> Result<String> {
+    ylong_json::to_string(&Unredacted(map)).map_err(|e| macros_lib::log_and_into_asset_error!(
+        macros_lib::hisysevent::function!(), ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+        "[FATAL]Export unredacted asset map to json failed, err: {}", e))
+}
+
+/// Import the AssetMap from a JSON string.
+pub fn from_json(json: &str) -> Result<AssetMap> {
+    let Strict(map) = ylong_json::from_str(json).map_err(|e| macros_lib::log_and_into_asset_error!(
+        macros_lib::hisysevent::function!(), ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+        "[FATAL]Import asset map from json failed, err: {}", e))?;
+    check_value_types(&map)?;
+    Ok(map)
+}
+
+fn write_cbor<T: Serialize>(value: &T) -> Result<Vec<u8>> {
+    let mut out = Vec::new();
+    ciborium::into_writer(value, &mut out).map_err(|e| macros_lib::log_and_into_asset_error!(
+        macros_lib::hisysevent::function!(), ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+        "[FATAL]Export asset map to cbor failed, err: {}", e))?;
+    Ok(out)
+}
+
+/// Export the AssetMap into a CBOR (RFC 8949) byte array with the sensitive values redacted.
+pub fn to_cbor(map: &AssetMap) -> Result<Vec<u8>> {
+    write_cbor(&Redacted(map))
+}
+
+/// Export the AssetMap into a CBOR (RFC 8949) byte array including the sensitive values.
+pub fn to_cbor_unredacted(map: &AssetMap) -> Result<Vec<u8>> {
+    write_cbor(&Unredacted(map))
+}
+
+/// Import the AssetMap from a CBOR (RFC 8949) byte array.
+pub fn from_cbor(data: &[u8]) -> Result<AssetMap> {
+    let mut reader = data;
+    let Strict(map) = ciborium::from_reader(&mut reader).map_err(|e| macros_lib::log_and_into_asset_error!(
+        macros_lib::hisysevent::function!(), ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+        "[FATAL]Import asset map from cbor failed, err: {}", e))?;
+    if !reader.is_empty() {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue, "[FATAL]The cbor data has trailing bytes.");
+    }
+    check_value_types(&map)?;
+    Ok(map)
+}
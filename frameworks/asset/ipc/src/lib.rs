@@ -18,7 +18,8 @@
 use ipc::{parcel::{MsgParcel, Deserialize}, IpcStatusCode};
 
 use asset_definition::{
-    macros_lib, AssetError, AssetMap, Conversion, DataType, ErrCode, Result, SyncResult, Tag, Value
+    macros_lib, AssetError, AssetMap, AssetPage, AssetWithSecret, BatchItemResult, BatchQueryItem, Capabilities,
+    Conversion, DataType, ErrCode, ErrReason, Result, SecretBytes, SyncResult, Tag, TransactionOperation, Value,
 };
 
 /// SA id for Asset service.
@@ -27,10 +28,20 @@ pub const SA_ID: i32 = 8100;
 pub const SA_NAME: &str = "security_asset_service";
 /// IPC result code.
 pub const IPC_SUCCESS: u32 = 0;
+/// Version of the IPC protocol, which increases when the IPC interfaces change.
+pub const PROTOCOL_VERSION: u32 = 7;
+/// The first version of the IPC protocol replying the result of each item in a batch.
+pub const BATCH_ITEM_RESULT_VERSION: u32 = 2;
+/// Max number of items transferred in one batch request, the larger batches are split into chunks by the client.
+pub const MAX_BATCH_CHUNK_SIZE: u32 = 100;
 
 const MAX_MAP_CAPACITY: u32 = 64;
 const MAX_VEC_CAPACITY: u32 = 0x10000;
-const MAX_ATTR_ARRAY_CAPACITY: u32 = 100;
+const MAX_CAPABILITY_ITEM_CAPACITY: u32 = 0x100;
+
+const TRANSACTION_ADD: u32 = 0;
+const TRANSACTION_UPDATE: u32 = 1;
+const TRANSACTION_REMOVE: u32 = 2;
 
 macros_lib::impl_enum_trait! {
     /// Code used to identify the function to be called.
@@ -57,6 +68,22 @@ macros_lib::impl_enum_trait! {
         BatchRemove,
         /// Code for BatchUpdate.
         BatchUpdate,
+        /// Code for QueryPage.
+        QueryPage,
+        /// Code for GetCapabilities.
+        GetCapabilities,
+        /// Code for BatchQuery.
+        BatchQuery,
+        /// Code for Transact.
+        Transact,
+        /// Code for Rename.
+        Rename,
+        /// Code for Transfer.
+        Transfer,
+        /// Code for Count.
+        Count,
+        /// Code for Exists.
+        Exists,
     }
 }
 
@@ -64,21 +91,29 @@ macros_lib::impl_enum_trait! {
 pub fn deserialize<T: Deserialize>(parcel: &mut MsgParcel) -> Result<T> {
     let value = parcel.read::<T>()
         .map_err(|_| macros_lib::log_and_into_asset_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL]deserialize T from parcel failed!" ))?;
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+            "[FATAL]deserialize T from parcel failed!" ))?;
     Ok(value)
 }
 
 /// serialize the map to parcel
 pub fn serialize_map(map: &AssetMap, parcel: &mut MsgParcel) -> Result<()> {
-    if map.len() as u32 > MAX_MAP_CAPACITY {
+    serialize_map_with_secret(map, None, parcel)
+}
+
+/// Serialize the map to parcel followed by the secret, which is written from the [`SecretBytes`] without copying.
+pub fn serialize_map_with_secret(map: &AssetMap, secret: Option<&SecretBytes>, parcel: &mut MsgParcel) -> Result<()> {
+    let len = map.len() as u32 + secret.is_some() as u32;
+    if len > MAX_MAP_CAPACITY {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][IPC]The map size exceeds the limit.");
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][IPC]The map size exceeds the limit.");
     }
-    parcel.write(&(map.len() as u32)).map_err(ipc_err_handle)?;
+    parcel.write(&len).map_err(ipc_err_handle)?;
     for (&tag, value) in map.iter() {
         if tag.data_type() != value.data_type() {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument,
+                ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch,
                 "[FATAL][IPC]Data type mismatch, key type: {}, value type: {}", tag.data_type(), value.data_type() );
         }
         parcel.write(&(tag as u32)).map_err(ipc_err_handle)?;
@@ -88,6 +123,10 @@ pub fn serialize_map(map: &AssetMap, parcel: &mut MsgParcel) -> Result<()> {
             Value::Bytes(a) => parcel.write::<Vec<u8>>(a).map_err(ipc_err_handle)?,
         }
     }
+    if let Some(secret) = secret {
+        parcel.write(&(Tag::Secret as u32)).map_err(ipc_err_handle)?;
+        parcel.write::<Vec<u8>>(secret.as_vec()).map_err(ipc_err_handle)?;
+    }
     Ok(())
 }
 
@@ -96,7 +135,8 @@ pub fn deserialize_map(parcel: &mut MsgParcel) -> Result<AssetMap> {
     let len = parcel.read::<u32>().map_err(ipc_err_handle)?;
     if len > MAX_MAP_CAPACITY {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][IPC]The map size exceeds the limit.");
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][IPC]The map size exceeds the limit.");
     }
     let mut map = AssetMap::with_capacity(len as usize);
     for _ in 0..len {
@@ -121,10 +161,11 @@ pub fn deserialize_map(parcel: &mut MsgParcel) -> Result<AssetMap> {
 }
 
 /// Serialize the collection of map to parcel.
-pub fn serialize_maps(vec: &Vec<AssetMap>, parcel: &mut MsgParcel) -> Result<()> {
+pub fn serialize_maps(vec: &[AssetMap], parcel: &mut MsgParcel) -> Result<()> {
     if vec.len() as u32 > MAX_VEC_CAPACITY {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][IPC]The vector size exceeds the limit.");
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][IPC]The vector size exceeds the limit.");
     }
     parcel.write::<u32>(&(vec.len() as u32)).map_err(ipc_err_handle)?;
     for map in vec.iter() {
@@ -133,12 +174,27 @@ pub fn serialize_maps(vec: &Vec<AssetMap>, parcel: &mut MsgParcel) -> Result<()>
     Ok(())
 }
 
+/// Serialize the collection of Assets with their secrets to parcel, which is deserialized as the collection of map.
+pub fn serialize_assets_with_secret(assets: &[AssetWithSecret], parcel: &mut MsgParcel) -> Result<()> {
+    if assets.len() as u32 > MAX_VEC_CAPACITY {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][IPC]The vector size exceeds the limit.");
+    }
+    parcel.write::<u32>(&(assets.len() as u32)).map_err(ipc_err_handle)?;
+    for asset in assets.iter() {
+        serialize_map_with_secret(&asset.attributes, asset.secret.as_ref(), parcel)?;
+    }
+    Ok(())
+}
+
 /// Deserialize the collection of map from parcel.
 pub fn deserialize_maps(parcel: &mut MsgParcel) -> Result<Vec<AssetMap>> {
     let len = parcel.read::<u32>().map_err(ipc_err_handle)?;
     if len > MAX_VEC_CAPACITY {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][IPC]The vector size exceeds the limit.");
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][IPC]The vector size exceeds the limit.");
     }
     let mut res_vec = Vec::with_capacity(len as usize);
     for _i in 0..len {
@@ -147,6 +203,91 @@ pub fn deserialize_maps(parcel: &mut MsgParcel) -> Result<Vec<AssetMap>> {
     Ok(res_vec)
 }
 
+/// Serialize the page of the paged query to parcel. An empty token indicates that the page is the last one.
+pub fn serialize_page(page: &AssetPage, parcel: &mut MsgParcel) -> Result<()> {
+    serialize_maps(&page.assets, parcel)?;
+    let empty = Vec::new();
+    parcel.write::<Vec<u8>>(page.next_token.as_ref().unwrap_or(&empty)).map_err(ipc_err_handle)
+}
+
+/// Deserialize the page of the paged query from parcel.
+pub fn deserialize_page(parcel: &mut MsgParcel) -> Result<AssetPage> {
+    let assets = deserialize_maps(parcel)?;
+    let token = parcel.read::<Vec<u8>>().map_err(ipc_err_handle)?;
+    Ok(AssetPage { assets, next_token: if token.is_empty() { None } else { Some(token) } })
+}
+
+fn read_capability_item_len(parcel: &mut MsgParcel) -> Result<u32> {
+    let len = parcel.read::<u32>().map_err(ipc_err_handle)?;
+    if len > MAX_CAPABILITY_ITEM_CAPACITY {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][IPC]The capability item size exceeds the limit.");
+    }
+    Ok(len)
+}
+
+// Unlike Tag::try_from, the tags added by a newer service are not logged as errors.
+fn find_known_tag(tag: u32) -> Option<Tag> {
+    Tag::ALL.iter().copied().find(|known| *known as u32 == tag)
+}
+
+/// Serialize the capabilities of the service to parcel.
+pub fn serialize_capabilities(capabilities: &Capabilities, parcel: &mut MsgParcel) -> Result<()> {
+    parcel.write::<u32>(&capabilities.protocol_version).map_err(ipc_err_handle)?;
+    parcel.write::<u32>(&(capabilities.supported_tags.len() as u32)).map_err(ipc_err_handle)?;
+    for &tag in capabilities.supported_tags.iter() {
+        parcel.write::<u32>(&(tag as u32)).map_err(ipc_err_handle)?;
+    }
+    parcel.write::<u32>(&(capabilities.enum_values.len() as u32)).map_err(ipc_err_handle)?;
+    for (tag, values) in capabilities.enum_values.iter() {
+        parcel.write::<u32>(&(*tag as u32)).map_err(ipc_err_handle)?;
+        parcel.write::<u32>(&(values.len() as u32)).map_err(ipc_err_handle)?;
+        for value in values.iter() {
+            parcel.write::<u32>(value).map_err(ipc_err_handle)?;
+        }
+    }
+    parcel.write::<u32>(&capabilities.max_secret_size).map_err(ipc_err_handle)?;
+    parcel.write::<u32>(&capabilities.max_label_size).map_err(ipc_err_handle)?;
+    parcel.write::<u32>(&capabilities.max_return_limit).map_err(ipc_err_handle)?;
+    parcel.write::<u32>(&capabilities.features).map_err(ipc_err_handle)
+}
+
+/// Deserialize the capabilities of the service from parcel. The tags unknown to the client are skipped.
+pub fn deserialize_capabilities(parcel: &mut MsgParcel) -> Result<Capabilities> {
+    let protocol_version = parcel.read::<u32>().map_err(ipc_err_handle)?;
+    let len = read_capability_item_len(parcel)?;
+    let mut supported_tags = Vec::with_capacity(len as usize);
+    for _i in 0..len {
+        let tag = parcel.read::<u32>().map_err(ipc_err_handle)?;
+        if let Some(tag) = find_known_tag(tag) {
+            supported_tags.push(tag);
+        }
+    }
+    let len = read_capability_item_len(parcel)?;
+    let mut enum_values = Vec::with_capacity(len as usize);
+    for _i in 0..len {
+        let tag = parcel.read::<u32>().map_err(ipc_err_handle)?;
+        let values_len = read_capability_item_len(parcel)?;
+        let mut values = Vec::with_capacity(values_len as usize);
+        for _j in 0..values_len {
+            values.push(parcel.read::<u32>().map_err(ipc_err_handle)?);
+        }
+        if let Some(tag) = find_known_tag(tag) {
+            enum_values.push((tag, values));
+        }
+    }
+    Ok(Capabilities {
+        protocol_version,
+        supported_tags,
+        enum_values,
+        max_secret_size: parcel.read::<u32>().map_err(ipc_err_handle)?,
+        max_label_size: parcel.read::<u32>().map_err(ipc_err_handle)?,
+        max_return_limit: parcel.read::<u32>().map_err(ipc_err_handle)?,
+        features: parcel.read::<u32>().map_err(ipc_err_handle)?,
+    })
+}
+
 /// Serialize the sync result to parcel.
 pub fn serialize_sync_result(sync_result: &SyncResult, parcel: &mut MsgParcel) -> Result<()> {
     parcel.write::<i32>(&sync_result.result_code).map_err(ipc_err_handle)?;
@@ -163,32 +304,143 @@ pub fn deserialize_sync_result(parcel: &mut MsgParcel) -> Result<SyncResult> {
     })
 }
 
-/// Serialize the batch result (Vec<(u32, i32)>) to parcel.
-pub fn serialize_batch_result(result: &Vec<(u32, u32)>, parcel: &mut MsgParcel) -> Result<()> {
-    if result.len() as u32 > MAX_ATTR_ARRAY_CAPACITY {
+/// Serialize the result of each item in a batch to parcel.
+pub fn serialize_batch_result(results: &[BatchItemResult], parcel: &mut MsgParcel) -> Result<()> {
+    if results.len() as u32 > MAX_BATCH_CHUNK_SIZE {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][IPC]The result size exceeds the limit." );
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][IPC]The result size exceeds the limit.");
     }
-    parcel.write::<u32>(&(result.len() as u32)).map_err(ipc_err_handle)?;
-    for (index, error_code) in result.iter() {
-        parcel.write::<u32>(index).map_err(ipc_err_handle)?;
-        parcel.write::<u32>(error_code).map_err(ipc_err_handle)?;
+    parcel.write::<u32>(&(results.len() as u32)).map_err(ipc_err_handle)?;
+    for item in results.iter() {
+        parcel.write::<u32>(&item.index).map_err(ipc_err_handle)?;
+        parcel.write::<u32>(&item.code.map_or(IPC_SUCCESS, |code| code as u32)).map_err(ipc_err_handle)?;
+        parcel.write::<String>(&item.message).map_err(ipc_err_handle)?;
+        parcel.write::<Vec<u8>>(&item.alias).map_err(ipc_err_handle)?;
+        parcel.write::<bool>(&item.id.is_some()).map_err(ipc_err_handle)?;
+        parcel.write::<u32>(&item.id.unwrap_or_default()).map_err(ipc_err_handle)?;
     }
     Ok(())
 }
 
-/// Deserialize the batch result (Vec<(u32, i32)>) from parcel.
-pub fn deserialize_batch_result(parcel: &mut MsgParcel) -> Result<Vec<(u32, u32)>> {
+fn read_batch_result_len(parcel: &mut MsgParcel) -> Result<u32> {
     let len = parcel.read::<u32>().map_err(ipc_err_handle)?;
-    if len > MAX_ATTR_ARRAY_CAPACITY {
+    if len > MAX_BATCH_CHUNK_SIZE {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][IPC]The result size exceeds the limit." );
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][IPC]The result size exceeds the limit.");
+    }
+    Ok(len)
+}
+
+fn read_err_code(parcel: &mut MsgParcel) -> Result<Option<ErrCode>> {
+    match parcel.read::<u32>().map_err(ipc_err_handle)? {
+        IPC_SUCCESS => Ok(None),
+        code => Ok(Some(ErrCode::try_from(code)?)),
+    }
+}
+
+/// Deserialize the result of each item in a batch from parcel.
+pub fn deserialize_batch_result(parcel: &mut MsgParcel) -> Result<Vec<BatchItemResult>> {
+    let len = read_batch_result_len(parcel)?;
+    let mut results = Vec::with_capacity(len as usize);
+    for _i in 0..len {
+        let index = parcel.read::<u32>().map_err(ipc_err_handle)?;
+        let code = read_err_code(parcel)?;
+        let message = parcel.read::<String>().map_err(ipc_err_handle)?;
+        let alias = parcel.read::<Vec<u8>>().map_err(ipc_err_handle)?;
+        let has_id = parcel.read::<bool>().map_err(ipc_err_handle)?;
+        let id = parcel.read::<u32>().map_err(ipc_err_handle)?;
+        results.push(BatchItemResult { index, code, message, alias, id: if has_id { Some(id) } else { None } });
     }
+    Ok(results)
+}
+
+/// Serialize the result of each item in a batch query to parcel, the attributes follow the results in the same order.
+pub fn serialize_batch_query_result(
+    items: &[(BatchItemResult, AssetWithSecret)],
+    parcel: &mut MsgParcel,
+) -> Result<()> {
+    let results: Vec<BatchItemResult> = items.iter().map(|(result, _)| result.clone()).collect();
+    serialize_batch_result(&results, parcel)?;
+    for (_, asset) in items.iter() {
+        serialize_map_with_secret(&asset.attributes, asset.secret.as_ref(), parcel)?;
+    }
+    Ok(())
+}
+
+/// Deserialize the result of each item in a batch query from parcel.
+pub fn deserialize_batch_query_result(parcel: &mut MsgParcel) -> Result<Vec<BatchQueryItem>> {
+    let results = deserialize_batch_result(parcel)?;
+    let mut items = Vec::with_capacity(results.len());
+    for result in results {
+        items.push(BatchQueryItem { result, attributes: deserialize_map(parcel)? });
+    }
+    Ok(items)
+}
+
+/// Serialize the operations of a transaction to parcel, each of which is the kind followed by its maps.
+pub fn serialize_operations(operations: &[TransactionOperation], parcel: &mut MsgParcel) -> Result<()> {
+    if operations.len() as u32 > MAX_BATCH_CHUNK_SIZE {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][IPC]The operation size exceeds the limit.");
+    }
+    parcel.write::<u32>(&(operations.len() as u32)).map_err(ipc_err_handle)?;
+    for operation in operations.iter() {
+        match operation {
+            TransactionOperation::Add(attributes) => {
+                parcel.write::<u32>(&TRANSACTION_ADD).map_err(ipc_err_handle)?;
+                serialize_map(attributes, parcel)?;
+            },
+            TransactionOperation::Update(query, attributes_to_update) => {
+                parcel.write::<u32>(&TRANSACTION_UPDATE).map_err(ipc_err_handle)?;
+                serialize_map(query, parcel)?;
+                serialize_map(attributes_to_update, parcel)?;
+            },
+            TransactionOperation::Remove(query) => {
+                parcel.write::<u32>(&TRANSACTION_REMOVE).map_err(ipc_err_handle)?;
+                serialize_map(query, parcel)?;
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Deserialize the operations of a transaction from parcel.
+pub fn deserialize_operations(parcel: &mut MsgParcel) -> Result<Vec<TransactionOperation>> {
+    let len = parcel.read::<u32>().map_err(ipc_err_handle)?;
+    if len > MAX_BATCH_CHUNK_SIZE {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][IPC]The operation size exceeds the limit.");
+    }
+    let mut operations = Vec::with_capacity(len as usize);
+    for _i in 0..len {
+        let operation = match parcel.read::<u32>().map_err(ipc_err_handle)? {
+            TRANSACTION_ADD => TransactionOperation::Add(deserialize_map(parcel)?),
+            TRANSACTION_UPDATE => TransactionOperation::Update(deserialize_map(parcel)?, deserialize_map(parcel)?),
+            TRANSACTION_REMOVE => TransactionOperation::Remove(deserialize_map(parcel)?),
+            kind => {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::InvalidArgument, reason = ErrReason::InvalidEnumVariant,
+                    "[FATAL][IPC]The operation kind[{}] is invalid.", kind);
+            },
+        };
+        operations.push(operation);
+    }
+    Ok(operations)
+}
+
+/// Deserialize the batch result replied by the service before [`BATCH_ITEM_RESULT_VERSION`], which only contains
+/// the error code and the index of the failed items.
+pub fn deserialize_legacy_batch_result(parcel: &mut MsgParcel) -> Result<Vec<(ErrCode, u32)>> {
+    let len = read_batch_result_len(parcel)?;
     let mut res_vec = Vec::with_capacity(len as usize);
     for _i in 0..len {
+        let code = read_err_code(parcel)?.unwrap_or(ErrCode::SystemError);
         let index = parcel.read::<u32>().map_err(ipc_err_handle)?;
-        let error_code = parcel.read::<u32>().map_err(ipc_err_handle)?;
-        res_vec.push((index, error_code));
+        res_vec.push((code, index));
     }
     Ok(res_vec)
 }
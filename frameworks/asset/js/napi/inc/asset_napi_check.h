@@ -59,6 +59,17 @@ const std::vector<uint32_t> NORMAL_LOCAL_LABEL_TAGS = {
     SEC_ASSET_TAG_DATA_LABEL_NORMAL_LOCAL_4
 };
 
+const std::vector<uint32_t> CUSTOM_LABEL_TAGS = {
+    SEC_ASSET_TAG_DATA_LABEL_CUSTOM_CRITICAL,
+    SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL,
+    SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL_LOCAL
+};
+
+const std::vector<uint32_t> NORMAL_CUSTOM_LABEL_TAGS = {
+    SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL,
+    SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL_LOCAL
+};
+
 const std::vector<uint32_t> ACCESS_CONTROL_TAGS = {
     SEC_ASSET_TAG_ALIAS,
     SEC_ASSET_TAG_ACCESSIBILITY,
@@ -96,7 +107,9 @@ const std::vector<uint32_t> ASSET_RETURN_ORDER_BY_TAGS = {
     SEC_ASSET_TAG_DATA_LABEL_NORMAL_LOCAL_1,
     SEC_ASSET_TAG_DATA_LABEL_NORMAL_LOCAL_2,
     SEC_ASSET_TAG_DATA_LABEL_NORMAL_LOCAL_3,
-    SEC_ASSET_TAG_DATA_LABEL_NORMAL_LOCAL_4
+    SEC_ASSET_TAG_DATA_LABEL_NORMAL_LOCAL_4,
+    SEC_ASSET_TAG_CREATE_TIME,
+    SEC_ASSET_TAG_UPDATE_TIME
 };
 
 const std::vector<uint32_t> ASSET_SYNC_TAGS = {
@@ -111,6 +124,10 @@ const std::vector<uint32_t> ASSET_ACCESSIBILITY_VEC = {
 
 const std::vector<uint32_t> ASSET_AUTH_TYPE_VEC = {
     SEC_ASSET_AUTH_TYPE_NONE,
+    SEC_ASSET_AUTH_TYPE_PIN,
+    SEC_ASSET_AUTH_TYPE_FINGERPRINT,
+    SEC_ASSET_AUTH_TYPE_FACE,
+    SEC_ASSET_AUTH_TYPE_BIOMETRIC,
     SEC_ASSET_AUTH_TYPE_ANY
 };
 
@@ -121,7 +138,13 @@ const std::vector<uint32_t> ASSET_CONFLICT_RESOLUTION_VEC = {
 
 const std::vector<uint32_t> ASSET_RETURN_TYPE_VEC = {
     SEC_ASSET_RETURN_ALL,
-    SEC_ASSET_RETURN_ATTRIBUTES
+    SEC_ASSET_RETURN_ATTRIBUTES,
+    SEC_ASSET_RETURN_KEY_STATUS
+};
+
+const std::vector<uint32_t> ASSET_RETURN_ORDER_VEC = {
+    SEC_ASSET_RETURN_ORDER_ASCENDING,
+    SEC_ASSET_RETURN_ORDER_DESCENDING
 };
 
 const std::vector<uint32_t> ASSET_WRAP_TYPE_VEC = {
@@ -162,6 +185,19 @@ const std::unordered_map<uint32_t, const char *> TAG_MAP = {
     { SEC_ASSET_TAG_REQUIRE_ATTR_ENCRYPTED, "REQUIRE_ATTR_ENCRYPTED" },
     { SEC_ASSET_TAG_GROUP_ID, "GROUP_ID" },
     { SEC_ASSET_TAG_WRAP_TYPE, "WRAP_TYPE" },
+    { SEC_ASSET_TAG_EXPIRE_TIME, "EXPIRE_TIME" },
+    { SEC_ASSET_TAG_MAX_ACCESS_COUNT, "MAX_ACCESS_COUNT" },
+    { SEC_ASSET_TAG_DATA_LABEL_CUSTOM_CRITICAL, "DATA_LABEL_CUSTOM_CRITICAL" },
+    { SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL, "DATA_LABEL_CUSTOM_NORMAL" },
+    { SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL_LOCAL, "DATA_LABEL_CUSTOM_NORMAL_LOCAL" },
+    { SEC_ASSET_TAG_INVALIDATE_ON_BIOMETRIC_CHANGE, "INVALIDATE_ON_BIOMETRIC_CHANGE" },
+    { SEC_ASSET_TAG_KEY_STATUS, "KEY_STATUS" },
+    { SEC_ASSET_TAG_REVISION, "REVISION" },
+    { SEC_ASSET_TAG_CREATE_TIME, "CREATE_TIME" },
+    { SEC_ASSET_TAG_QUERY_FILTER, "QUERY_FILTER" },
+    { SEC_ASSET_TAG_RETURN_ORDER, "RETURN_ORDER" },
+    { SEC_ASSET_TAG_RETURN_ORDERED_BY_KEYS, "RETURN_ORDERED_BY_KEYS" },
+    { SEC_ASSET_TAG_REMAINING_ACCESS_COUNT, "REMAINING_ACCESS_COUNT" },
     { SEC_ASSET_TAG_USER_ID, "USER_ID" },
 };
 
@@ -49,6 +49,10 @@ const std::unordered_map<int32_t, const char *> ERR_MSGS = {
     { SEC_ASSET_UNSUPPORTED, "The capability is not supported." },
     { SEC_ASSET_PARAM_VERIFICATION_FAILED, "Parameter verification failed." },
     { SEC_ASSET_INCONSISTENT_ATTRIBUTE, "The array has inconsistent group id or REQUIRE_ATTR_ENCRYPTED." },
+    { SEC_ASSET_ACCESS_COUNT_EXHAUSTED, "The access count of the asset is exhausted." },
+    { SEC_ASSET_KEY_INVALIDATED, "The key protecting the asset has been permanently invalidated." },
+    { SEC_ASSET_REVISION_CONFLICT, "The asset has been modified since the expected revision." },
+    { SEC_ASSET_CANCELED, "The request has been cancelled." },
 };
 
 inline const char *GetErrorMessage(int32_t errCode)
@@ -76,6 +76,19 @@ napi_value DeclareTag(const napi_env env)
     AddUint32Property(env, tag, "REQUIRE_ATTR_ENCRYPTED", SEC_ASSET_TAG_REQUIRE_ATTR_ENCRYPTED);
     AddUint32Property(env, tag, "GROUP_ID", SEC_ASSET_TAG_GROUP_ID);
     AddUint32Property(env, tag, "WRAP_TYPE", SEC_ASSET_TAG_WRAP_TYPE);
+    AddUint32Property(env, tag, "EXPIRE_TIME", SEC_ASSET_TAG_EXPIRE_TIME);
+    AddUint32Property(env, tag, "MAX_ACCESS_COUNT", SEC_ASSET_TAG_MAX_ACCESS_COUNT);
+    AddUint32Property(env, tag, "DATA_LABEL_CUSTOM_CRITICAL", SEC_ASSET_TAG_DATA_LABEL_CUSTOM_CRITICAL);
+    AddUint32Property(env, tag, "DATA_LABEL_CUSTOM_NORMAL", SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL);
+    AddUint32Property(env, tag, "DATA_LABEL_CUSTOM_NORMAL_LOCAL", SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL_LOCAL);
+    AddUint32Property(env, tag, "INVALIDATE_ON_BIOMETRIC_CHANGE", SEC_ASSET_TAG_INVALIDATE_ON_BIOMETRIC_CHANGE);
+    AddUint32Property(env, tag, "KEY_STATUS", SEC_ASSET_TAG_KEY_STATUS);
+    AddUint32Property(env, tag, "REVISION", SEC_ASSET_TAG_REVISION);
+    AddUint32Property(env, tag, "CREATE_TIME", SEC_ASSET_TAG_CREATE_TIME);
+    AddUint32Property(env, tag, "QUERY_FILTER", SEC_ASSET_TAG_QUERY_FILTER);
+    AddUint32Property(env, tag, "RETURN_ORDER", SEC_ASSET_TAG_RETURN_ORDER);
+    AddUint32Property(env, tag, "RETURN_ORDERED_BY_KEYS", SEC_ASSET_TAG_RETURN_ORDERED_BY_KEYS);
+    AddUint32Property(env, tag, "REMAINING_ACCESS_COUNT", SEC_ASSET_TAG_REMAINING_ACCESS_COUNT);
     return tag;
 }
 
@@ -115,6 +128,10 @@ napi_value DeclareErrorCode(const napi_env env)
     AddUint32Property(env, errorCode, "UNSUPPORTED", SEC_ASSET_UNSUPPORTED);
     AddUint32Property(env, errorCode, "PARAM_VERIFICATION_FAILED", SEC_ASSET_PARAM_VERIFICATION_FAILED);
     AddUint32Property(env, errorCode, "INCONSISTENT_ATTRIBUTE", SEC_ASSET_INCONSISTENT_ATTRIBUTE);
+    AddUint32Property(env, errorCode, "ACCESS_COUNT_EXHAUSTED", SEC_ASSET_ACCESS_COUNT_EXHAUSTED);
+    AddUint32Property(env, errorCode, "KEY_INVALIDATED", SEC_ASSET_KEY_INVALIDATED);
+    AddUint32Property(env, errorCode, "REVISION_CONFLICT", SEC_ASSET_REVISION_CONFLICT);
+    AddUint32Property(env, errorCode, "CANCELED", SEC_ASSET_CANCELED);
     return errorCode;
 }
 
@@ -133,6 +150,10 @@ napi_value DeclareAuthType(const napi_env env)
     napi_value authType = nullptr;
     NAPI_CALL(env, napi_create_object(env, &authType));
     AddUint32Property(env, authType, "NONE", SEC_ASSET_AUTH_TYPE_NONE);
+    AddUint32Property(env, authType, "PIN", SEC_ASSET_AUTH_TYPE_PIN);
+    AddUint32Property(env, authType, "FINGERPRINT", SEC_ASSET_AUTH_TYPE_FINGERPRINT);
+    AddUint32Property(env, authType, "FACE", SEC_ASSET_AUTH_TYPE_FACE);
+    AddUint32Property(env, authType, "BIOMETRIC", SEC_ASSET_AUTH_TYPE_BIOMETRIC);
     AddUint32Property(env, authType, "ANY", SEC_ASSET_AUTH_TYPE_ANY);
     return authType;
 }
@@ -172,9 +193,29 @@ napi_value DeclareReturnType(const napi_env env)
     NAPI_CALL(env, napi_create_object(env, &returnType));
     AddUint32Property(env, returnType, "ALL", SEC_ASSET_RETURN_ALL);
     AddUint32Property(env, returnType, "ATTRIBUTES", SEC_ASSET_RETURN_ATTRIBUTES);
+    AddUint32Property(env, returnType, "KEY_STATUS", SEC_ASSET_RETURN_KEY_STATUS);
     return returnType;
 }
 
+napi_value DeclareReturnOrder(const napi_env env)
+{
+    napi_value returnOrder = nullptr;
+    NAPI_CALL(env, napi_create_object(env, &returnOrder));
+    AddUint32Property(env, returnOrder, "ASCENDING", SEC_ASSET_RETURN_ORDER_ASCENDING);
+    AddUint32Property(env, returnOrder, "DESCENDING", SEC_ASSET_RETURN_ORDER_DESCENDING);
+    return returnOrder;
+}
+
+napi_value DeclareKeyStatus(const napi_env env)
+{
+    napi_value keyStatus = nullptr;
+    NAPI_CALL(env, napi_create_object(env, &keyStatus));
+    AddUint32Property(env, keyStatus, "VALID", SEC_ASSET_KEY_STATUS_VALID);
+    AddUint32Property(env, keyStatus, "INVALIDATED", SEC_ASSET_KEY_STATUS_INVALIDATED);
+    AddUint32Property(env, keyStatus, "MISSING", SEC_ASSET_KEY_STATUS_MISSING);
+    return keyStatus;
+}
+
 napi_value DeclareOperationType(const napi_env env)
 {
     napi_value operationType = nullptr;
@@ -221,8 +262,10 @@ napi_value Register(const napi_env env, napi_value exports)
         DECLARE_NAPI_PROPERTY("SyncType", DeclareSyncType(env)),
         DECLARE_NAPI_PROPERTY("ConflictResolution", DeclareConflictResolution(env)),
         DECLARE_NAPI_PROPERTY("ReturnType", DeclareReturnType(env)),
+        DECLARE_NAPI_PROPERTY("ReturnOrder", DeclareReturnOrder(env)),
         DECLARE_NAPI_PROPERTY("OperationType", DeclareOperationType(env)),
         DECLARE_NAPI_PROPERTY("WrapType", DeclareWrapType(env)),
+        DECLARE_NAPI_PROPERTY("KeyStatus", DeclareKeyStatus(env)),
     };
 
     NAPI_CALL(env, napi_define_properties(env, exports, sizeof(desc) / sizeof(desc[0]), desc));
@@ -37,7 +37,10 @@ const std::vector<uint32_t> REQUIRED_TAGS = {
 
 const std::vector<uint32_t> OPTIONAL_TAGS = {
     SEC_ASSET_TAG_SECRET,
-    SEC_ASSET_TAG_CONFLICT_RESOLUTION
+    SEC_ASSET_TAG_CONFLICT_RESOLUTION,
+    SEC_ASSET_TAG_EXPIRE_TIME,
+    SEC_ASSET_TAG_MAX_ACCESS_COUNT,
+    SEC_ASSET_TAG_INVALIDATE_ON_BIOMETRIC_CHANGE
 };
 
 napi_status CheckAddArgsCommon(const napi_env env, const std::vector<AssetAttr> &attrs,
@@ -55,6 +58,7 @@ napi_status CheckAddArgs(const napi_env env, const std::vector<AssetAttr> &attrs
     validTags.insert(validTags.end(), CRITICAL_LABEL_TAGS.begin(), CRITICAL_LABEL_TAGS.end());
     validTags.insert(validTags.end(), NORMAL_LABEL_TAGS.begin(), NORMAL_LABEL_TAGS.end());
     validTags.insert(validTags.end(), NORMAL_LOCAL_LABEL_TAGS.begin(), NORMAL_LOCAL_LABEL_TAGS.end());
+    validTags.insert(validTags.end(), CUSTOM_LABEL_TAGS.begin(), CUSTOM_LABEL_TAGS.end());
     validTags.insert(validTags.end(), ACCESS_CONTROL_TAGS.begin(), ACCESS_CONTROL_TAGS.end());
     validTags.insert(validTags.end(), ASSET_SYNC_TAGS.begin(), ASSET_SYNC_TAGS.end());
     validTags.insert(validTags.end(), OPTIONAL_TAGS.begin(), OPTIONAL_TAGS.end());
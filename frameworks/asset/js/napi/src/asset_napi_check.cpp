@@ -47,6 +47,8 @@ namespace {
 #define SYNC_TYPE_MAX_BITS 3
 #define ROOT_USER_UPPERBOUND 99
 #define MAX_TIME_SIZE 1024
+#define MAX_QUERY_FILTER_SIZE 0x8000
+#define MAX_ORDER_KEYS_SIZE 32
 #define SYSTEM_USER_ID_MAX 99
 #define BINARY_BASE 2
 #define MIN_GROUP_ID_SIZE 6
@@ -137,10 +139,16 @@ const std::unordered_map<uint32_t, CheckContinuousRange> CHECK_CONTINOUS_RANGE_F
     { SEC_ASSET_TAG_DATA_LABEL_NORMAL_LOCAL_2, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_LABEL_SIZE } },
     { SEC_ASSET_TAG_DATA_LABEL_NORMAL_LOCAL_3, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_LABEL_SIZE } },
     { SEC_ASSET_TAG_DATA_LABEL_NORMAL_LOCAL_4, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_LABEL_SIZE } },
+    { SEC_ASSET_TAG_DATA_LABEL_CUSTOM_CRITICAL, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_LABEL_SIZE } },
+    { SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_LABEL_SIZE } },
+    { SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL_LOCAL, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_LABEL_SIZE } },
     { SEC_ASSET_TAG_RETURN_LIMIT, { &CheckNumberRange, MIN_NUMBER_VALUE, MAX_RETURN_LIMIT } },
     { SEC_ASSET_TAG_GROUP_ID, { &CheckArraySize, MIN_GROUP_ID_SIZE, MAX_GROUP_ID_SIZE } },
     { SEC_ASSET_TAG_USER_ID, { &CheckNumberRange, ROOT_USER_UPPERBOUND, INT32_MAX } },
-    { SEC_ASSET_TAG_UPDATE_TIME, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_TIME_SIZE } }
+    { SEC_ASSET_TAG_UPDATE_TIME, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_TIME_SIZE } },
+    { SEC_ASSET_TAG_CREATE_TIME, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_TIME_SIZE } },
+    { SEC_ASSET_TAG_QUERY_FILTER, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_QUERY_FILTER_SIZE } },
+    { SEC_ASSET_TAG_RETURN_ORDERED_BY_KEYS, { &CheckArraySize, MIN_ARRAY_SIZE, MAX_ORDER_KEYS_SIZE } }
 };
 
 struct CheckDiscreteRange {
@@ -154,6 +162,7 @@ const std::unordered_map<uint32_t, CheckDiscreteRange> CHECK_DISCRETE_RANGE_FUNC
     { SEC_ASSET_TAG_CONFLICT_RESOLUTION, { &CheckEnumVariant, ASSET_CONFLICT_RESOLUTION_VEC } },
     { SEC_ASSET_TAG_RETURN_TYPE, { &CheckEnumVariant, ASSET_RETURN_TYPE_VEC } },
     { SEC_ASSET_TAG_RETURN_ORDERED_BY, { &CheckTagRange, ASSET_RETURN_ORDER_BY_TAGS } },
+    { SEC_ASSET_TAG_RETURN_ORDER, { &CheckEnumVariant, ASSET_RETURN_ORDER_VEC } },
     { SEC_ASSET_TAG_WRAP_TYPE, { &CheckEnumVariant, ASSET_WRAP_TYPE_VEC } }
 };
 
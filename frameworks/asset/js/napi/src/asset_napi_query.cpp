@@ -36,6 +36,9 @@ const std::vector<uint32_t> OPTIONAL_TAGS = {
     SEC_ASSET_TAG_RETURN_TYPE,
     SEC_ASSET_TAG_AUTH_TOKEN,
     SEC_ASSET_TAG_AUTH_CHALLENGE,
+    SEC_ASSET_TAG_QUERY_FILTER,
+    SEC_ASSET_TAG_RETURN_ORDER,
+    SEC_ASSET_TAG_RETURN_ORDERED_BY_KEYS,
 };
 
 napi_status CheckQueryArgs(const napi_env env, const std::vector<AssetAttr> &attrs)
@@ -44,6 +47,7 @@ napi_status CheckQueryArgs(const napi_env env, const std::vector<AssetAttr> &att
     validTags.insert(validTags.end(), CRITICAL_LABEL_TAGS.begin(), CRITICAL_LABEL_TAGS.end());
     validTags.insert(validTags.end(), NORMAL_LABEL_TAGS.begin(), NORMAL_LABEL_TAGS.end());
     validTags.insert(validTags.end(), NORMAL_LOCAL_LABEL_TAGS.begin(), NORMAL_LOCAL_LABEL_TAGS.end());
+    validTags.insert(validTags.end(), CUSTOM_LABEL_TAGS.begin(), CUSTOM_LABEL_TAGS.end());
     validTags.insert(validTags.end(), ACCESS_CONTROL_TAGS.begin(), ACCESS_CONTROL_TAGS.end());
     validTags.insert(validTags.end(), ASSET_SYNC_TAGS.begin(), ASSET_SYNC_TAGS.end());
     validTags.insert(validTags.end(), OPTIONAL_TAGS.begin(), OPTIONAL_TAGS.end());
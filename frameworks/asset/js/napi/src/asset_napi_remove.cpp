@@ -42,6 +42,7 @@ napi_status CheckRemoveArgs(const napi_env env, const std::vector<AssetAttr> &at
     std::vector<uint32_t> validTags;
     validTags.insert(validTags.end(), NORMAL_LABEL_TAGS.begin(), NORMAL_LABEL_TAGS.end());
     validTags.insert(validTags.end(), NORMAL_LOCAL_LABEL_TAGS.begin(), NORMAL_LOCAL_LABEL_TAGS.end());
+    validTags.insert(validTags.end(), CUSTOM_LABEL_TAGS.begin(), CUSTOM_LABEL_TAGS.end());
     validTags.insert(validTags.end(), ACCESS_CONTROL_TAGS.begin(), ACCESS_CONTROL_TAGS.end());
     validTags.insert(validTags.end(), ASSET_SYNC_TAGS.begin(), ASSET_SYNC_TAGS.end());
     return CheckRemoveArgsCommon(env, attrs, validTags);
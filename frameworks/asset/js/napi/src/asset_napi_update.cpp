@@ -35,6 +35,10 @@ const std::vector<uint32_t> QUERY_REQUIRED_TAGS = {
     SEC_ASSET_TAG_ALIAS
 };
 
+const std::vector<uint32_t> QUERY_OPTIONAL_TAGS = {
+    SEC_ASSET_TAG_REVISION
+};
+
 const std::vector<uint32_t> UPDATE_OPTIONAL_TAGS = {
     SEC_ASSET_TAG_SECRET
 };
@@ -69,11 +73,14 @@ napi_status CheckUpdateArgs(const napi_env env, const std::vector<AssetAttr> &at
     queryValidTags.insert(queryValidTags.end(), CRITICAL_LABEL_TAGS.begin(), CRITICAL_LABEL_TAGS.end());
     queryValidTags.insert(queryValidTags.end(), NORMAL_LABEL_TAGS.begin(), NORMAL_LABEL_TAGS.end());
     queryValidTags.insert(queryValidTags.end(), NORMAL_LOCAL_LABEL_TAGS.begin(), NORMAL_LOCAL_LABEL_TAGS.end());
+    queryValidTags.insert(queryValidTags.end(), CUSTOM_LABEL_TAGS.begin(), CUSTOM_LABEL_TAGS.end());
     queryValidTags.insert(queryValidTags.end(), ACCESS_CONTROL_TAGS.begin(), ACCESS_CONTROL_TAGS.end());
+    queryValidTags.insert(queryValidTags.end(), QUERY_OPTIONAL_TAGS.begin(), QUERY_OPTIONAL_TAGS.end());
 
     std::vector<uint32_t> updateValidTags;
     updateValidTags.insert(updateValidTags.end(), NORMAL_LABEL_TAGS.begin(), NORMAL_LABEL_TAGS.end());
     updateValidTags.insert(updateValidTags.end(), NORMAL_LOCAL_LABEL_TAGS.begin(), NORMAL_LOCAL_LABEL_TAGS.end());
+    updateValidTags.insert(updateValidTags.end(), NORMAL_CUSTOM_LABEL_TAGS.begin(), NORMAL_CUSTOM_LABEL_TAGS.end());
     updateValidTags.insert(updateValidTags.end(), ASSET_SYNC_TAGS.begin(), ASSET_SYNC_TAGS.end());
     updateValidTags.insert(updateValidTags.end(), UPDATE_OPTIONAL_TAGS.begin(), UPDATE_OPTIONAL_TAGS.end());
 
@@ -89,6 +96,7 @@ napi_status CheckBatchUpdateArgs(const napi_env env, const std::vector<AssetAttr
     queryValidTags.insert(queryValidTags.end(), NORMAL_LOCAL_LABEL_TAGS.begin(), NORMAL_LOCAL_LABEL_TAGS.end());
     queryValidTags.insert(queryValidTags.end(),
         ACCESS_CONTROL_TAGS_FOR_BATCH.begin(), ACCESS_CONTROL_TAGS_FOR_BATCH.end());
+    queryValidTags.insert(queryValidTags.end(), QUERY_OPTIONAL_TAGS.begin(), QUERY_OPTIONAL_TAGS.end());
 
     std::vector<uint32_t> updateValidTags;
     updateValidTags.insert(updateValidTags.end(), NORMAL_LABEL_TAGS.begin(), NORMAL_LABEL_TAGS.end());
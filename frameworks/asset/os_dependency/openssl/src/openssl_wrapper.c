@@ -15,6 +15,8 @@
 
 #include "openssl_wrapper.h"
 
+#include <openssl/evp.h>
+#include <openssl/hmac.h>
 #include <openssl/rand.h>
 #include <openssl/sha.h>
 #include <stdlib.h>
@@ -35,6 +37,21 @@ void Sha256(const uint8_t *input, uint32_t intputLen, uint8_t *output)
     (void)SHA256((const unsigned char *)input, intputLen, (unsigned char *)output);
 }
 
+// The caller should ensure the memory safety, that the output should point at 32 bytes of valid memory.
+int32_t HmacSha256(const uint8_t *key, uint32_t keyLen, const uint8_t *input, uint32_t inputLen, uint8_t *output)
+{
+    if (key == NULL || keyLen == 0 || input == NULL || inputLen == 0 || output == NULL) {
+        LOGE("invalid input for hmac sha256");
+        return -1;
+    }
+    unsigned int outputLen = 0;
+    if (HMAC(EVP_sha256(), key, (int)keyLen, input, inputLen, output, &outputLen) == NULL) {
+        LOGE("Compute hmac sha256 failed!");
+        return -1;
+    }
+    return 0;
+}
+
 int32_t GenerateRandom(uint8_t *random, uint32_t randomLen)
 {
     if (random == NULL) {
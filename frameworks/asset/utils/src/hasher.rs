@@ -15,6 +15,8 @@
 
 //! This module implements the SHA256 hash algorithm.
 
+use asset_definition::{macros_lib, ErrCode, Result};
+
 const LOWER_BYTES_MASK: u32 = 0xff;
 const BITS_PER_U8: usize = 8;
 const U8_PER_U32: usize = 4;
@@ -136,6 +138,7 @@ fn into_vec_u8(hash: &[u32; 8]) -> Vec<u8> {
 
 extern "C" {
     fn Sha256(input: *const u8, input_len: u32, output: *mut u8);
+    fn HmacSha256(key: *const u8, key_len: u32, input: *const u8, input_len: u32, output: *mut u8) -> i32;
 }
 
 const SHA256_OUTPUT_LEN: usize = 32;
@@ -161,3 +164,16 @@ pub fn sha256(standard: bool, message: &[u8]) -> Vec<u8> {
 
     sha256_old(message)
 }
+
+/// the function to execute hmac sha256 by openssl.
+pub fn hmac_sha256(key: &[u8], message: &[u8]) -> Result<Vec<u8>> {
+    let mut res = vec![0; SHA256_OUTPUT_LEN];
+    let ret = unsafe {
+        HmacSha256(key.as_ptr(), key.len() as u32, message.as_ptr(), message.len() as u32, res.as_mut_ptr())
+    };
+    if ret != 0 {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::CryptoError, "[FATAL]Compute hmac sha256 failed, ret = {}.", ret);
+    }
+    Ok(res)
+}
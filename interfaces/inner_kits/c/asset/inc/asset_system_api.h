@@ -30,6 +30,7 @@
 #include "asset_system_type.h"
 
 #ifdef __cplusplus
+#include <string>
 #include <vector>
 extern "C" {
 #endif
@@ -111,6 +112,28 @@ int32_t AssetPostQuery(const AssetAttr *handle, uint32_t handleCnt);
  */
 int32_t AssetQuerySyncResult(const AssetAttr *query, uint32_t queryCnt, AssetSyncResult *syncResult);
 
+/**
+ * @brief Counts the assets matching the search criteria, the attributes of which are not returned.
+ *
+ * @param query Pointer to the search criteria.
+ * @param queryCnt Number of the search criteria.
+ * @param count Pointer to the number of the assets obtained.
+ * @return Returns <b>SEC_ASSET_SUCCESS</b> if the operation is successful; returns an error code otherwise.
+ * @since 26
+ */
+int32_t AssetCount(const AssetAttr *query, uint32_t queryCnt, uint32_t *count);
+
+/**
+ * @brief Checks whether any asset matches the search criteria, the attributes of which are not returned.
+ *
+ * @param query Pointer to the search criteria.
+ * @param queryCnt Number of the search criteria.
+ * @param exists Pointer to the result obtained, which is true if any asset matches.
+ * @return Returns <b>SEC_ASSET_SUCCESS</b> if the operation is successful; returns an error code otherwise.
+ * @since 26
+ */
+int32_t AssetExists(const AssetAttr *query, uint32_t queryCnt, bool *exists);
+
  /**
  * @brief Parses the query result to obtain the specified attribute value.
  *
@@ -171,6 +194,75 @@ int32_t AssetBatchRemove(std::vector<std::vector<AssetAttr>> &attrsArray);
 int32_t AssetBatchUpdate(std::vector<std::vector<AssetAttr>> &attrsArray,
     std::vector<std::vector<AssetAttr>> &attrsToUpdateArray,
     std::vector<std::pair<uint32_t, uint32_t>> &errInfoArray);
+
+/**
+ * @brief Represents the result of an item in a batch operation.
+ *
+ * @since 26
+ */
+struct AssetBatchItemResult {
+    /**
+     * Index of the item in the batch.
+     */
+    uint32_t index;
+    /**
+     * Result code of the item, which is <b>SEC_ASSET_SUCCESS</b> if the item succeeds.
+     */
+    int32_t errorCode;
+    /**
+     * Message describing why the item fails.
+     */
+    std::string message;
+    /**
+     * Alias of the asset that the item operates on.
+     */
+    std::vector<uint8_t> alias;
+    /**
+     * Whether the id of the row is present.
+     */
+    bool hasId;
+    /**
+     * Id of the row added or updated by the item.
+     */
+    uint32_t id;
+};
+
+/**
+ * @brief Adds a batch of assets, and returns the result of each asset.
+ *
+ * A batch larger than 100 assets is sent in chunks, each of which is committed on its own.
+ *
+ * @param attrsArray Array of the attributes of the asset to add.
+ * @param results Array of the result of each asset, in the order of the assets.
+ * @return Returns <b>SEC_ASSET_SUCCESS</b> if the operation is successful; returns an error code otherwise.
+ * @since 26
+ */
+int32_t AssetBatchAddWithResults(std::vector<std::vector<AssetAttr>> &attrsArray,
+    std::vector<AssetBatchItemResult> &results);
+
+/**
+ * @brief Removes a batch of assets, and returns the result of each asset.
+ *
+ * @param attrsArray Array of the attributes of the asset to remove.
+ * @param results Array of the result of each asset, in the order of the assets.
+ * @return Returns <b>SEC_ASSET_SUCCESS</b> if the operation is successful; returns an error code otherwise.
+ * @since 26
+ */
+int32_t AssetBatchRemoveWithResults(std::vector<std::vector<AssetAttr>> &attrsArray,
+    std::vector<AssetBatchItemResult> &results);
+
+/**
+ * @brief Updates a batch of assets, and returns the result of each asset.
+ *
+ * @param attrsArray Array of the the conditions for updating the assets.
+ * @param attrsToUpdateArray Array of the attributes of the asset to update.
+ * @param results Array of the result of each asset, in the order of the assets.
+ * @return Returns <b>SEC_ASSET_SUCCESS</b> if the operation is successful; returns an error code otherwise.
+ * @since 26
+ */
+int32_t AssetBatchUpdateWithResults(std::vector<std::vector<AssetAttr>> &attrsArray,
+    std::vector<std::vector<AssetAttr>> &attrsToUpdateArray,
+    std::vector<AssetBatchItemResult> &results);
 }
 #endif
 
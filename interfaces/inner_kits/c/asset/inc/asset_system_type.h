@@ -190,6 +190,75 @@ typedef enum {
      * A tag whose value is a 32-bit unsigned integer indicating the type of Asset encapsulation.
      */
     SEC_ASSET_TAG_WRAP_TYPE = SEC_ASSET_TYPE_NUMBER | 0x49,
+    /**
+     * A tag whose value is a 32-bit unsigned integer indicating the time (in seconds since the Unix epoch)
+     * after which an Asset expires.
+     */
+    SEC_ASSET_TAG_EXPIRE_TIME = SEC_ASSET_TYPE_NUMBER | 0x4A,
+    /**
+     * A tag whose value is a 32-bit unsigned integer indicating the maximum number of times the secret of an
+     * Asset can be read, after which the Asset is removed.
+     */
+    SEC_ASSET_TAG_MAX_ACCESS_COUNT = SEC_ASSET_TYPE_NUMBER | 0x4B,
+    /**
+     * A tag whose value is a byte array indicating the user-defined custom labels identified by name,
+     * which cannot be updated. Each label is encoded as the length (4 bytes, little-endian) and content of its name,
+     * followed by those of its value.
+     */
+    SEC_ASSET_TAG_DATA_LABEL_CUSTOM_CRITICAL = SEC_ASSET_TYPE_BYTES | 0x4C,
+    /**
+     * A tag whose value is a byte array indicating the user-defined custom labels identified by name,
+     * which can be updated. The labels are encoded in the same way as SEC_ASSET_TAG_DATA_LABEL_CUSTOM_CRITICAL.
+     */
+    SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL = SEC_ASSET_TYPE_BYTES | 0x4D,
+    /**
+     * A tag whose value is a byte array indicating the user-defined custom local labels identified by name,
+     * which can be updated and will not be synchronized.
+     * The labels are encoded in the same way as SEC_ASSET_TAG_DATA_LABEL_CUSTOM_CRITICAL.
+     */
+    SEC_ASSET_TAG_DATA_LABEL_CUSTOM_NORMAL_LOCAL = SEC_ASSET_TYPE_BYTES | 0x4E,
+    /**
+     * A tag whose value is a bool indicating whether the key protecting the Asset is invalidated
+     * when the biometric enrollment (fingerprint or face) changes.
+     */
+    SEC_ASSET_TAG_INVALIDATE_ON_BIOMETRIC_CHANGE = SEC_ASSET_TYPE_BOOL | 0x4F,
+    /**
+     * A tag whose value is a 32-bit unsigned integer indicating the status of the key protecting the Asset.
+     */
+    SEC_ASSET_TAG_KEY_STATUS = SEC_ASSET_TYPE_NUMBER | 0x50,
+    /**
+     * A tag whose value is a 32-bit unsigned integer indicating the revision of the Asset,
+     * which increases each time the Asset is updated.
+     */
+    SEC_ASSET_TAG_REVISION = SEC_ASSET_TYPE_NUMBER | 0x52,
+    /**
+     * A tag whose value is a byte array indicating the create time of an Asset.
+     */
+    SEC_ASSET_TAG_CREATE_TIME = SEC_ASSET_TYPE_BYTES | 0x53,
+    /**
+     * A tag whose value is a byte array of the encoded predicates that the queried Assets must match,
+     * such as the prefix of the alias, the range of the update time or the list of aliases.
+     * Each predicate is encoded as its kind (0: prefix, 1: range, 2: in-list), its tag and the count of its operands
+     * (4 bytes each, little-endian), followed by the length (4 bytes, little-endian) and content of each operand.
+     * The bound of a range is encoded as 8 bytes (little-endian), or as empty content if it is not bounded.
+     */
+    SEC_ASSET_TAG_QUERY_FILTER = SEC_ASSET_TYPE_BYTES | 0x54,
+    /**
+     * A tag whose value is a 32-bit unsigned integer indicating the direction in which the query results are sorted
+     * by SEC_ASSET_TAG_RETURN_ORDERED_BY.
+     */
+    SEC_ASSET_TAG_RETURN_ORDER = SEC_ASSET_TYPE_NUMBER | 0x55,
+    /**
+     * A tag whose value is a byte array of the sort keys by which the query results are sorted, each consisting of
+     * the tag (4 bytes, little-endian) followed by its direction (4 bytes, little-endian). The earlier key takes
+     * precedence.
+     */
+    SEC_ASSET_TAG_RETURN_ORDERED_BY_KEYS = SEC_ASSET_TYPE_BYTES | 0x56,
+    /**
+     * A tag whose value is a 32-bit unsigned integer indicating the remaining number of times the secret of an
+     * Asset can be read, which is returned by the query only.
+     */
+    SEC_ASSET_TAG_REMAINING_ACCESS_COUNT = SEC_ASSET_TYPE_NUMBER | 0x57,
     /**
      * Tag used to store specific user id. The value is of the uint32 type.
      */
@@ -292,6 +361,22 @@ typedef enum {
      * Each value of Tag.GROUP_ID and Tag.REQUIRE_ATTR_ENCRYPTED in the array is not consistent.
      */
     SEC_ASSET_INCONSISTENT_ATTRIBUTE = 24000019,
+    /**
+     * The error code indicates that the Asset has been removed because its access count is exhausted.
+     */
+    SEC_ASSET_ACCESS_COUNT_EXHAUSTED = 24000020,
+    /**
+     * The error code indicates that the key protecting the Asset has been permanently invalidated.
+     */
+    SEC_ASSET_KEY_INVALIDATED = 24000021,
+    /**
+     * The error code indicates that the Asset has been modified since the expected revision.
+     */
+    SEC_ASSET_REVISION_CONFLICT = 24000022,
+    /**
+     * The error code indicates that the request has been cancelled before it is sent to the service.
+     */
+    SEC_ASSET_CANCELED = 24000023,
     /**
      * The HW Account error.
      */
@@ -356,6 +441,23 @@ typedef enum {
      * No user authentication is required before the asset is accessed.
      */
     SEC_ASSET_AUTH_TYPE_NONE = 0x00,
+    /**
+     * The asset can be accessed only if PIN authentication is successful.
+     */
+    SEC_ASSET_AUTH_TYPE_PIN = 0x01,
+    /**
+     * The asset can be accessed only if fingerprint authentication is successful.
+     */
+    SEC_ASSET_AUTH_TYPE_FINGERPRINT = 0x02,
+    /**
+     * The asset can be accessed only if facial authentication is successful.
+     */
+    SEC_ASSET_AUTH_TYPE_FACE = 0x04,
+    /**
+     * The asset can be accessed if any biometric authentication (such as facial or fingerprint authentication) is
+     * successful.
+     */
+    SEC_ASSET_AUTH_TYPE_BIOMETRIC = SEC_ASSET_AUTH_TYPE_FINGERPRINT | SEC_ASSET_AUTH_TYPE_FACE,
     /**
      * The asset can be accessed if any user authentication (such as PIN, facial, or fingerprint authentication) is
      * successful.
@@ -426,8 +528,44 @@ typedef enum {
      * The query result contains only the asset attributes.
      */
     SEC_ASSET_RETURN_ATTRIBUTES = 1,
+    /**
+     * The query result contains the asset attributes and the status of the key protecting the asset.
+     */
+    SEC_ASSET_RETURN_KEY_STATUS = 2,
 } AssetReturnType;
 
+/**
+ * @brief Enumerates the statuses of the key protecting the asset.
+ */
+typedef enum {
+    /**
+     * The key is available for encryption and decryption.
+     */
+    SEC_ASSET_KEY_STATUS_VALID = 0,
+    /**
+     * The key has been permanently invalidated, for example, after the biometric enrollment changed.
+     */
+    SEC_ASSET_KEY_STATUS_INVALIDATED = 1,
+    /**
+     * The key does not exist.
+     */
+    SEC_ASSET_KEY_STATUS_MISSING = 2,
+} AssetKeyStatus;
+
+/**
+ * @brief Enumerates the directions in which the query results are sorted.
+ */
+typedef enum {
+    /**
+     * Sort the query results in ascending order.
+     */
+    SEC_ASSET_RETURN_ORDER_ASCENDING = 0,
+    /**
+     * Sort the query results in descending order.
+     */
+    SEC_ASSET_RETURN_ORDER_DESCENDING = 1,
+} AssetReturnOrder;
+
 /**
  * @brief Enumerates the types of the asset query result.
  */
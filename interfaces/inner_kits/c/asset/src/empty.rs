@@ -87,6 +87,26 @@ pub unsafe extern "C" fn query_sync_result(
     empty_mode()
 }
 
+/// Function called from C programming language to Rust programming language for counting Assets.
+///
+/// # Safety
+///
+/// The caller must ensure that the count pointer is valid.
+#[no_mangle]
+pub unsafe extern "C" fn count_asset(_query: *const AssetAttr, _query_cnt: u32, _count: *mut u32) -> i32 {
+    empty_mode()
+}
+
+/// Function called from C programming language to Rust programming language for checking the existence of Assets.
+///
+/// # Safety
+///
+/// The caller must ensure that the exists pointer is valid.
+#[no_mangle]
+pub unsafe extern "C" fn exists_asset(_query: *const AssetAttr, _query_cnt: u32, _exists: *mut bool) -> i32 {
+    empty_mode()
+}
+
 /// Function called from C programming language to Rust programming language for batch inserting Assets.
 #[no_mangle]
 pub extern "C" fn asset_batch_add(
@@ -113,8 +133,42 @@ pub extern "C" fn asset_batch_remove(_c_array: &C2DArray) -> i32 {
     empty_mode()
 }
 
+/// Function called from C programming language to Rust programming language for batch inserting Assets,
+/// the result of each item is returned.
+#[no_mangle]
+pub extern "C" fn asset_batch_add_with_results(
+    _c_array: &C2DArray,
+    _c_results: &mut MutBatchResultVec,
+) -> i32 {
+    empty_mode()
+}
+
+/// Function called from C programming language to Rust programming language for batch updating Assets,
+/// the result of each item is returned.
+#[no_mangle]
+pub extern "C" fn asset_batch_update_with_results(
+    _c_array: &C2DArray,
+    _c_array_to_update: &C2DArray,
+    _c_results: &mut MutBatchResultVec,
+) -> i32 {
+    empty_mode()
+}
+
+/// Function called from C programming language to Rust programming language for batch removing Assets,
+/// the result of each item is returned.
+#[no_mangle]
+pub extern "C" fn asset_batch_remove_with_results(_c_array: &C2DArray, _c_results: &mut MutBatchResultVec) -> i32 {
+    empty_mode()
+}
+
 /// Function used to free memory allocated from Rust side.
 #[no_mangle]
 pub extern "C" fn asset_free_mut_pair_vec(_vec: &MutPairVec) -> i32 {
     empty_mode()
 }
+
+/// Function used to free the batch results allocated from Rust side.
+#[no_mangle]
+pub extern "C" fn asset_free_batch_result_vec(_vec: &MutBatchResultVec) -> i32 {
+    empty_mode()
+}
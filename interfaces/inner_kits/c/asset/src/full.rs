@@ -26,7 +26,7 @@ use std::{
 
 use asset_log::loge;
 use asset_sdk::{
-    macros_lib, AssetError, AssetMap, Conversion, DataType, ErrCode, SyncResult, Tag, Value,
+    macros_lib, AssetError, AssetMap, BatchItemResult, Conversion, DataType, ErrCode, SyncResult, Tag, Value,
 };
 use asset_sdk::full::Manager;
 use crate::*;
@@ -133,37 +133,97 @@ pub extern "C" fn add_asset(attributes: *const AssetAttr, attr_cnt: u32) -> i32
     ret
 }
 
-/// Function called from C programming language to Rust programming language for batch inserting Assets.
-#[no_mangle]
-pub extern "C" fn asset_batch_add(
-    c_array: &C2DArray,
-    err_info: &mut MutPairVec,
-) -> i32 {
-    let array = match into_vec2d(c_array) {
-        Some(array) => array,
-        None => return ErrCode::InvalidArgument as i32,
-    };
-    let attributes_array = match into_batch_map(&array) {
-        Some(array) => array,
-        None => return ErrCode::InvalidArgument as i32,
-    };
+fn into_mut_bytes(bytes: Vec<u8>) -> MutBytes {
+    let len = bytes.len();
+    let data = Box::into_raw(bytes.into_boxed_slice()) as *mut u8;
+    MutBytes { data, len }
+}
 
-    let manager = match Manager::build() {
-        Ok(manager) => manager,
-        Err(e) => return map_err(e.code),
-    };
+fn free_mut_bytes(bytes: &MutBytes) {
+    if !bytes.data.is_null() && bytes.len > 0 {
+        unsafe {
+            let _ = Box::from_raw(slice::from_raw_parts_mut(bytes.data, bytes.len));
+        }
+    }
+}
 
-    let mut vec = match manager.lock().unwrap().batch_add(&attributes_array) {
-        Ok(info) => info,
-        Err(e) => return e.code as i32,
-    };
+fn into_err_info(results: &[BatchItemResult], err_info: &mut MutPairVec) {
+    let mut vec = BatchItemResult::into_err_info(results).into_boxed_slice().into_vec();
     err_info.data = vec.as_mut_ptr();
     err_info.len = vec.len();
     std::mem::forget(vec);
+}
 
-    RESULT_CODE_SUCCESS
+fn into_c_batch_results(results: Vec<BatchItemResult>, c_results: &mut MutBatchResultVec) {
+    let items: Vec<CBatchItemResult> = results.into_iter().map(|item| CBatchItemResult {
+        index: item.index,
+        error_code: item.code.map_or(RESULT_CODE_SUCCESS, |code| code as i32),
+        message: into_mut_bytes(item.message.into_bytes()),
+        alias: into_mut_bytes(item.alias),
+        has_id: item.id.is_some(),
+        id: item.id.unwrap_or_default(),
+    }).collect();
+    c_results.len = items.len();
+    c_results.data = Box::into_raw(items.into_boxed_slice()) as *mut CBatchItemResult;
+}
+
+fn batch_add_inner(c_array: &C2DArray) -> Result<Vec<BatchItemResult>, i32> {
+    let array = into_vec2d(c_array).ok_or(ErrCode::InvalidArgument as i32)?;
+    let attributes_array = into_batch_map(&array).ok_or(ErrCode::InvalidArgument as i32)?;
+    let manager = Manager::build().map_err(|e| map_err(e.code))?;
+    let results = manager.lock().unwrap().batch_add_with_results(&attributes_array).map_err(|e| e.code as i32);
+    results
+}
+
+fn batch_update_inner(c_array: &C2DArray, c_array_to_update: &C2DArray) -> Result<Vec<BatchItemResult>, i32> {
+    let array = into_vec2d(c_array).ok_or(ErrCode::InvalidArgument as i32)?;
+    let attributes_array = into_batch_map(&array).ok_or(ErrCode::InvalidArgument as i32)?;
+    let array_to_update = into_vec2d(c_array_to_update).ok_or(ErrCode::InvalidArgument as i32)?;
+    let attributes_to_update_array = into_batch_map(&array_to_update).ok_or(ErrCode::InvalidArgument as i32)?;
+    let manager = Manager::build().map_err(|e| map_err(e.code))?;
+    let results = manager.lock().unwrap().batch_update_with_results(&attributes_array, &attributes_to_update_array)
+        .map_err(|e| e.code as i32);
+    results
+}
+
+fn batch_remove_inner(c_array: &C2DArray) -> Result<Vec<BatchItemResult>, i32> {
+    let array = into_vec2d(c_array).ok_or(ErrCode::InvalidArgument as i32)?;
+    let attributes_array = into_batch_map(&array).ok_or(ErrCode::InvalidArgument as i32)?;
+    let manager = Manager::build().map_err(|e| map_err(e.code))?;
+    let results = manager.lock().unwrap().batch_remove_with_results(&attributes_array).map_err(|e| e.code as i32);
+    results
+}
+
+/// Function called from C programming language to Rust programming language for batch inserting Assets.
+#[no_mangle]
+pub extern "C" fn asset_batch_add(
+    c_array: &C2DArray,
+    err_info: &mut MutPairVec,
+) -> i32 {
+    match batch_add_inner(c_array) {
+        Ok(results) => {
+            into_err_info(&results, err_info);
+            RESULT_CODE_SUCCESS
+        },
+        Err(code) => code,
+    }
 }
 
+/// Function called from C programming language to Rust programming language for batch inserting Assets,
+/// the result of each item is returned.
+#[no_mangle]
+pub extern "C" fn asset_batch_add_with_results(
+    c_array: &C2DArray,
+    c_results: &mut MutBatchResultVec,
+) -> i32 {
+    match batch_add_inner(c_array) {
+        Ok(results) => {
+            into_c_batch_results(results, c_results);
+            RESULT_CODE_SUCCESS
+        },
+        Err(code) => code,
+    }
+}
 
 /// Function called from C programming language to Rust programming language for batch updating Assets.
 #[no_mangle]
@@ -172,40 +232,30 @@ pub extern "C" fn asset_batch_update(
     c_array_to_update: &C2DArray,
     err_info: &mut MutPairVec,
 ) -> i32 {
-    let array = match into_vec2d(c_array) {
-        Some(array) => array,
-        None => return ErrCode::InvalidArgument as i32,
-    };
-    let attributes_array = match into_batch_map(&array) {
-        Some(array) => array,
-        None => return ErrCode::InvalidArgument as i32,
-    };
-
-    let array_to_update = match into_vec2d(c_array_to_update) {
-        Some(array) => array,
-        None => return ErrCode::InvalidArgument as i32,
-    };
-
-    let attributes_to_update_array = match into_batch_map(&array_to_update) {
-        Some(array) => array,
-        None => return ErrCode::InvalidArgument as i32,
-    };
-
-    let manager = match Manager::build() {
-        Ok(manager) => manager,
-        Err(e) => return map_err(e.code),
-    };
-
-    let mut vec = match manager.lock().unwrap().batch_update(&attributes_array, &attributes_to_update_array) {
-        Ok(info) => info,
-        Err(e) => return e.code as i32,
-    };
-
-    err_info.data = vec.as_mut_ptr();
-    err_info.len = vec.len();
-    std::mem::forget(vec);
+    match batch_update_inner(c_array, c_array_to_update) {
+        Ok(results) => {
+            into_err_info(&results, err_info);
+            RESULT_CODE_SUCCESS
+        },
+        Err(code) => code,
+    }
+}
 
-    RESULT_CODE_SUCCESS
+/// Function called from C programming language to Rust programming language for batch updating Assets,
+/// the result of each item is returned.
+#[no_mangle]
+pub extern "C" fn asset_batch_update_with_results(
+    c_array: &C2DArray,
+    c_array_to_update: &C2DArray,
+    c_results: &mut MutBatchResultVec,
+) -> i32 {
+    match batch_update_inner(c_array, c_array_to_update) {
+        Ok(results) => {
+            into_c_batch_results(results, c_results);
+            RESULT_CODE_SUCCESS
+        },
+        Err(code) => code,
+    }
 }
 
 /// Function used to free memory allocated from Rust side.
@@ -219,6 +269,21 @@ pub extern "C" fn asset_free_mut_pair_vec(vec: &MutPairVec) -> i32 {
     RESULT_CODE_SUCCESS
 }
 
+/// Function used to free the batch results allocated from Rust side.
+#[no_mangle]
+pub extern "C" fn asset_free_batch_result_vec(vec: &MutBatchResultVec) -> i32 {
+    if !vec.data.is_null() && vec.len > 0 {
+        unsafe {
+            let items = Box::from_raw(slice::from_raw_parts_mut(vec.data, vec.len));
+            for item in items.iter() {
+                free_mut_bytes(&item.message);
+                free_mut_bytes(&item.alias);
+            }
+        }
+    }
+    RESULT_CODE_SUCCESS
+}
+
 /// Function called from C programming language to Rust programming language for removing Asset.
 #[no_mangle]
 pub extern "C" fn remove_asset(query: *const AssetAttr, query_cnt: u32) -> i32 {
@@ -239,26 +304,23 @@ pub extern "C" fn remove_asset(query: *const AssetAttr, query_cnt: u32) -> i32 {
 /// Function called from C programming language to Rust programming language for batch removing Assets.
 #[no_mangle]
 pub extern "C" fn asset_batch_remove(c_array: &C2DArray) -> i32 {
-    let array = match into_vec2d(c_array) {
-        Some(array) => array,
-        None => return ErrCode::InvalidArgument as i32,
-    };
-    let attributes_array = match into_batch_map(&array) {
-        Some(array) => array,
-        None => return ErrCode::InvalidArgument as i32,
-    };
-
-    let manager = match Manager::build() {
-        Ok(manager) => manager,
-        Err(e) => return map_err(e.code),
-    };
+    match batch_remove_inner(c_array) {
+        Ok(_) => RESULT_CODE_SUCCESS,
+        Err(code) => code,
+    }
+}
 
-    let ret = if let Err(e) = manager.lock().unwrap().batch_remove(&attributes_array) {
-        e.code as i32
-    } else {
-        RESULT_CODE_SUCCESS
-    };
-    ret
+/// Function called from C programming language to Rust programming language for batch removing Assets,
+/// the result of each item is returned.
+#[no_mangle]
+pub extern "C" fn asset_batch_remove_with_results(c_array: &C2DArray, c_results: &mut MutBatchResultVec) -> i32 {
+    match batch_remove_inner(c_array) {
+        Ok(results) => {
+            into_c_batch_results(results, c_results);
+            RESULT_CODE_SUCCESS
+        },
+        Err(code) => code,
+    }
 }
 
 /// Function called from C programming language to Rust programming language for updating Asset.
@@ -421,6 +483,70 @@ pub unsafe extern "C" fn query_sync_result(
     ret
 }
 
+/// Function called from C programming language to Rust programming language for counting Assets.
+///
+/// # Safety
+///
+/// The caller must ensure that the count pointer is valid.
+#[no_mangle]
+pub unsafe extern "C" fn count_asset(query: *const AssetAttr, query_cnt: u32, count: *mut u32) -> i32 {
+    let map = match into_map(query, query_cnt) {
+        Some(map) => map,
+        None => return ErrCode::ParamVerificationFailed as i32,
+    };
+
+    if count.is_null() {
+        loge!("[FATAL][RUST SDK]count is null");
+        return ErrCode::ParamVerificationFailed as i32;
+    }
+
+    let manager = match Manager::build() {
+        Ok(manager) => manager,
+        Err(e) => return map_err(e.code),
+    };
+
+    let ret = match manager.lock().unwrap().count(&map) {
+        Err(e) => map_err(e.code),
+        Ok(res) => {
+            *count = res;
+            RESULT_CODE_SUCCESS
+        },
+    };
+    ret
+}
+
+/// Function called from C programming language to Rust programming language for checking the existence of Assets.
+///
+/// # Safety
+///
+/// The caller must ensure that the exists pointer is valid.
+#[no_mangle]
+pub unsafe extern "C" fn exists_asset(query: *const AssetAttr, query_cnt: u32, exists: *mut bool) -> i32 {
+    let map = match into_map(query, query_cnt) {
+        Some(map) => map,
+        None => return ErrCode::ParamVerificationFailed as i32,
+    };
+
+    if exists.is_null() {
+        loge!("[FATAL][RUST SDK]exists is null");
+        return ErrCode::ParamVerificationFailed as i32;
+    }
+
+    let manager = match Manager::build() {
+        Ok(manager) => manager,
+        Err(e) => return map_err(e.code),
+    };
+
+    let ret = match manager.lock().unwrap().exists(&map) {
+        Err(e) => map_err(e.code),
+        Ok(res) => {
+            *exists = res;
+            RESULT_CODE_SUCCESS
+        },
+    };
+    ret
+}
+
 fn map_err(err_code: ErrCode) -> i32 {
     if err_code == ErrCode::InvalidArgument {
         ErrCode::ParamVerificationFailed as i32
@@ -65,6 +65,44 @@ pub struct MutPairVec {
     pub len: usize,
 }
 
+/// Byte array allocated from Rust side.
+#[repr(C)]
+#[derive(Debug)]
+pub struct MutBytes {
+    /// Data of the byte array.
+    pub data: *mut u8,
+    /// Size of the byte array.
+    pub len: usize,
+}
+
+/// Result of an item in a batch with a c representation.
+#[repr(C)]
+#[derive(Debug)]
+pub struct CBatchItemResult {
+    /// Index of the item in the batch.
+    pub index: u32,
+    /// Result code of the item, which is 0 if the item succeeds.
+    pub error_code: i32,
+    /// Message describing why the item fails.
+    pub message: MutBytes,
+    /// Alias of the Asset that the item operates on.
+    pub alias: MutBytes,
+    /// Whether the Id of the row is present.
+    pub has_id: bool,
+    /// Id of the row added or updated by the item.
+    pub id: u32,
+}
+
+/// Vec of CBatchItemResult.
+#[repr(C)]
+#[derive(Debug)]
+pub struct MutBatchResultVec {
+    /// Data of MutBatchResultVec.
+    pub data: *mut CBatchItemResult,
+    /// Size of vector.
+    pub len: usize,
+}
+
 /// Blob of Asset with a c representation.
 #[repr(C)]
 #[derive(Clone, Copy)]
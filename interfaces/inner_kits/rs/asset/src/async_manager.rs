@@ -0,0 +1,283 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module defines the asynchronous interface of the Asset Rust SDK.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+use asset_definition::{
+    macros_lib, AssetMap, AssetPage, BatchItemResult, BatchQueryItem, Capabilities, ErrCode, Result, SyncResult,
+    TransactionOperation,
+};
+use ylong_runtime::task::JoinHandle;
+
+use crate::{full::Manager, RemoteTransport, RetryPolicy, Transport};
+
+// The idle managers beyond the limit are dropped, the others are reused by the subsequent requests.
+const MAX_IDLE_MANAGERS: usize = 4;
+
+type TransportFactory = dyn Fn() -> Result<Box<dyn Transport>> + Send + Sync;
+
+struct ManagerPool {
+    factory: Box<TransportFactory>,
+    idle: Mutex<Vec<Manager>>,
+    retry_policy: Mutex<RetryPolicy>,
+}
+
+impl ManagerPool {
+    fn acquire(&self) -> Result<Manager> {
+        let mut manager = match self.idle.lock().unwrap().pop() {
+            Some(manager) => manager,
+            None => Manager::with_transport((self.factory)()?),
+        };
+        manager.set_retry_policy(self.retry_policy.lock().unwrap().clone());
+        Ok(manager)
+    }
+
+    fn release(&self, manager: Manager) {
+        let mut idle = self.idle.lock().unwrap();
+        if idle.len() < MAX_IDLE_MANAGERS {
+            idle.push(manager);
+        }
+    }
+}
+
+// Cancel the request which is not dispatched yet if the future awaiting its result is dropped.
+struct CancelGuard<R> {
+    handle: JoinHandle<R>,
+    cancelled: Arc<AtomicBool>,
+    finished: bool,
+}
+
+impl<R> Drop for CancelGuard<R> {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.cancelled.store(true, Ordering::Release);
+            self.handle.cancel();
+        }
+    }
+}
+
+/// This manager provides the asynchronous version of the operations of [`Manager`].
+///
+/// Each request runs on the blocking pool of ylong_runtime with a connection of its own, so the requests in flight
+/// do not wait for each other. The connections are kept for reuse after the requests complete.
+///
+/// A request is cancelled by dropping its future. The request which is not dispatched yet is skipped, while the
+/// request which is already sent to the service completes without reporting its result.
+#[derive(Clone)]
+pub struct AsyncManager {
+    pool: Arc<ManagerPool>,
+}
+
+impl AsyncManager {
+    /// Build the AsyncManager which connects to the Asset service.
+    pub fn build() -> Result<Self> {
+        let factory = || -> Result<Box<dyn Transport>> { Ok(Box::new(RemoteTransport::connect()?)) };
+        // Connect once to fail fast if the service is unavailable.
+        let manager = Manager::with_transport(factory()?);
+        let async_manager = Self::with_transport_factory(factory);
+        async_manager.pool.release(manager);
+        Ok(async_manager)
+    }
+
+    /// Create the AsyncManager whose connections are created by the specified factory.
+    pub fn with_transport_factory<F>(factory: F) -> Self
+    where
+        F: Fn() -> Result<Box<dyn Transport>> + Send + Sync + 'static,
+    {
+        let pool = ManagerPool {
+            factory: Box::new(factory),
+            idle: Mutex::new(vec![]),
+            retry_policy: Mutex::new(RetryPolicy::default()),
+        };
+        Self { pool: Arc::new(pool) }
+    }
+
+    /// Set the policy to retry the requests failed because the service is unavailable, which applies to the
+    /// requests sent afterwards.
+    pub fn set_retry_policy(&self, retry_policy: RetryPolicy) {
+        *self.pool.retry_policy.lock().unwrap() = retry_policy;
+    }
+
+    /// Add an Asset.
+    pub async fn add(&self, attributes: &AssetMap) -> Result<()> {
+        let attributes = attributes.clone();
+        self.execute(move |manager| manager.add(&attributes)).await
+    }
+
+    /// Add batch assets, the error code and the index of each failed item are returned.
+    pub async fn batch_add(&self, attributes_array: &[AssetMap]) -> Result<Vec<(u32, u32)>> {
+        let attributes_array = attributes_array.to_vec();
+        self.execute(move |manager| manager.batch_add(&attributes_array)).await
+    }
+
+    /// Add batch assets, the result of each item is returned in the order of the items.
+    pub async fn batch_add_with_results(&self, attributes_array: &[AssetMap]) -> Result<Vec<BatchItemResult>> {
+        let attributes_array = attributes_array.to_vec();
+        self.execute(move |manager| manager.batch_add_with_results(&attributes_array)).await
+    }
+
+    /// Remove one or more Assets that match a search query.
+    pub async fn remove(&self, query: &AssetMap) -> Result<()> {
+        let query = query.clone();
+        self.execute(move |manager| manager.remove(&query)).await
+    }
+
+    /// Remove batch assets, the items which are not found are ignored.
+    pub async fn batch_remove(&self, attributes_array: &[AssetMap]) -> Result<()> {
+        let attributes_array = attributes_array.to_vec();
+        self.execute(move |manager| manager.batch_remove(&attributes_array)).await
+    }
+
+    /// Remove batch assets, the result of each item is returned in the order of the items.
+    pub async fn batch_remove_with_results(&self, attributes_array: &[AssetMap]) -> Result<Vec<BatchItemResult>> {
+        let attributes_array = attributes_array.to_vec();
+        self.execute(move |manager| manager.batch_remove_with_results(&attributes_array)).await
+    }
+
+    /// Update batch assets, the error code and the index of each failed item are returned.
+    pub async fn batch_update(
+        &self,
+        attributes_array: &[AssetMap],
+        attributes_to_update_array: &[AssetMap],
+    ) -> Result<Vec<(u32, u32)>> {
+        let (attributes_array, attributes_to_update_array) =
+            (attributes_array.to_vec(), attributes_to_update_array.to_vec());
+        self.execute(move |manager| manager.batch_update(&attributes_array, &attributes_to_update_array)).await
+    }
+
+    /// Update batch assets, the result of each item is returned in the order of the items.
+    pub async fn batch_update_with_results(
+        &self,
+        attributes_array: &[AssetMap],
+        attributes_to_update_array: &[AssetMap],
+    ) -> Result<Vec<BatchItemResult>> {
+        let (attributes_array, attributes_to_update_array) =
+            (attributes_array.to_vec(), attributes_to_update_array.to_vec());
+        self.execute(move |manager| {
+            manager.batch_update_with_results(&attributes_array, &attributes_to_update_array)
+        }).await
+    }
+
+    /// Add, update and remove Assets of the same database in a single transaction.
+    pub async fn transact(&self, operations: &[TransactionOperation]) -> Result<Vec<BatchItemResult>> {
+        let operations = operations.to_vec();
+        self.execute(move |manager| manager.transact(&operations)).await
+    }
+
+    /// Update an Asset that matches a search query.
+    pub async fn update(&self, query: &AssetMap, attributes_to_update: &AssetMap) -> Result<()> {
+        let (query, attributes_to_update) = (query.clone(), attributes_to_update.clone());
+        self.execute(move |manager| manager.update(&query, &attributes_to_update)).await
+    }
+
+    /// Rename an Asset that matches a search query, the secret of which is re-encrypted with the new alias.
+    pub async fn rename(&self, query: &AssetMap, new_alias: &[u8]) -> Result<()> {
+        let (query, new_alias) = (query.clone(), new_alias.to_vec());
+        self.execute(move |manager| manager.rename(&query, &new_alias)).await
+    }
+
+    /// Move or copy an Asset between the private space of the application and its groups.
+    pub async fn transfer(&self, query: &AssetMap, target: &AssetMap, keep_source: bool) -> Result<()> {
+        let (query, target) = (query.clone(), target.clone());
+        self.execute(move |manager| manager.transfer(&query, &target, keep_source)).await
+    }
+
+    /// Preprocessing for querying one or more Assets that require user authentication.
+    pub async fn pre_query(&self, query: &AssetMap) -> Result<Vec<u8>> {
+        let query = query.clone();
+        self.execute(move |manager| manager.pre_query(&query)).await
+    }
+
+    /// Query one or more Assets that match a search query.
+    pub async fn query(&self, query: &AssetMap) -> Result<Vec<AssetMap>> {
+        let query = query.clone();
+        self.execute(move |manager| manager.query(&query)).await
+    }
+
+    /// Query the secrets of batch Assets which do not require user authentication.
+    pub async fn batch_query(&self, queries: &[AssetMap]) -> Result<Vec<BatchQueryItem>> {
+        let queries = queries.to_vec();
+        self.execute(move |manager| manager.batch_query(&queries)).await
+    }
+
+    /// Query one page of the Assets that match a search query, the secrets are not returned.
+    pub async fn query_page(&self, query: &AssetMap) -> Result<AssetPage> {
+        let query = query.clone();
+        self.execute(move |manager| manager.query_page(&query)).await
+    }
+
+    /// Post-processing for querying multiple Assets that require user authentication.
+    pub async fn post_query(&self, query: &AssetMap) -> Result<()> {
+        let query = query.clone();
+        self.execute(move |manager| manager.post_query(&query)).await
+    }
+
+    /// Query the result of synchronization.
+    pub async fn query_sync_result(&self, query: &AssetMap) -> Result<SyncResult> {
+        let query = query.clone();
+        self.execute(move |manager| manager.query_sync_result(&query)).await
+    }
+
+    /// Count the Assets that match a search query.
+    pub async fn count(&self, query: &AssetMap) -> Result<u32> {
+        let query = query.clone();
+        self.execute(move |manager| manager.count(&query)).await
+    }
+
+    /// Check whether any Asset matches a search query.
+    pub async fn exists(&self, query: &AssetMap) -> Result<bool> {
+        let query = query.clone();
+        self.execute(move |manager| manager.exists(&query)).await
+    }
+
+    /// Get the capabilities of the Asset service.
+    pub async fn get_capabilities(&self) -> Result<Capabilities> {
+        self.execute(|manager| manager.get_capabilities()).await
+    }
+
+    async fn execute<T, F>(&self, func: F) -> Result<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Manager) -> Result<T> + Send + 'static,
+    {
+        let pool = self.pool.clone();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let cancelled_in_task = cancelled.clone();
+        let handle = ylong_runtime::spawn_blocking(move || {
+            if cancelled_in_task.load(Ordering::Acquire) {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::Canceled, "[FATAL][RUST SDK]The request is cancelled before it is sent.");
+            }
+            let mut manager = pool.acquire()?;
+            let ret = func(&mut manager);
+            pool.release(manager);
+            ret
+        });
+
+        let mut guard = CancelGuard { handle, cancelled, finished: false };
+        let ret = (&mut guard.handle).await;
+        guard.finished = true;
+        match ret {
+            Ok(ret) => ret,
+            Err(e) => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::IpcError, "[FATAL][RUST SDK]Join the request failed, err: {:?}", e),
+        }
+    }
+}
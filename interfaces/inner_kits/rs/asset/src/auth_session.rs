@@ -0,0 +1,107 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module defines the session for querying the Assets that require user authentication.
+
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use asset_definition::{AssetMap, Extension, Result, Tag, Value};
+use asset_log::logw;
+
+#[cfg(feature = "AssetMockMode")]
+use crate::mock::Manager;
+#[cfg(not(feature = "AssetMockMode"))]
+use crate::full::Manager;
+
+// The same as the default validity period of the service.
+const DEFAULT_AUTH_VALIDITY_IN_SECS: u32 = 60;
+
+// The tags which locate the challenge in the service, besides the challenge itself.
+const HANDLE_TAGS: [Tag; 2] = [Tag::GroupId, Tag::UserId];
+
+/// The session for querying the Assets that require user authentication.
+///
+/// The session is started by pre_query, and the challenge is released by post_query when the session is dropped, so
+/// that the challenge slots of the caller in the service are not leaked.
+pub struct AuthSession {
+    manager: Arc<Mutex<Manager>>,
+    handle: AssetMap,
+    challenge: Vec<u8>,
+    expires_at: Instant,
+}
+
+impl AuthSession {
+    /// Start a session for the Assets that match a search query.
+    ///
+    /// The session is valid in the period specified by [`Tag::AuthValidityPeriod`] of the query, 60 seconds by
+    /// default.
+    pub fn begin(manager: Arc<Mutex<Manager>>, query: &AssetMap) -> Result<Self> {
+        let validity = match query.get(&Tag::AuthValidityPeriod) {
+            Some(Value::Number(secs)) => *secs,
+            _ => DEFAULT_AUTH_VALIDITY_IN_SECS,
+        };
+        // The time is taken before the request so that the remaining validity is never longer than the actual one.
+        let expires_at = Instant::now() + Duration::from_secs(validity as u64);
+        let challenge = manager.lock().unwrap().pre_query(query)?;
+
+        let mut handle = AssetMap::new();
+        for tag in HANDLE_TAGS {
+            if let Some(value) = query.get(&tag) {
+                handle.insert(tag, value.clone());
+            }
+        }
+        handle.insert_attr(Tag::AuthChallenge, challenge.clone());
+        Ok(Self { manager, handle, challenge, expires_at })
+    }
+
+    /// Get the challenge used for user authentication.
+    pub fn challenge(&self) -> &[u8] {
+        &self.challenge
+    }
+
+    /// Get the remaining validity time of the session, which is zero if the session has expired.
+    pub fn remaining_validity(&self) -> Duration {
+        self.expires_at.saturating_duration_since(Instant::now())
+    }
+
+    /// Check whether the session has expired.
+    pub fn is_expired(&self) -> bool {
+        self.remaining_validity().is_zero()
+    }
+
+    /// Query the Assets that match a search query with the token of user authentication, which can be called
+    /// repeatedly until the session expires.
+    pub fn query(&self, query: &AssetMap, auth_token: &[u8]) -> Result<Vec<AssetMap>> {
+        let mut query = query.clone();
+        query.insert_attr(Tag::AuthChallenge, self.challenge.clone());
+        query.insert_attr(Tag::AuthToken, auth_token.to_vec());
+        self.manager.lock().unwrap().query(&query)
+    }
+}
+
+impl Drop for AuthSession {
+    fn drop(&mut self) {
+        let Ok(mut manager) = self.manager.lock() else {
+            logw!("[RUST SDK]The manager is poisoned, the challenge is released when it expires.");
+            return;
+        };
+        if let Err(e) = manager.post_query(&self.handle) {
+            logw!("[RUST SDK]Release the challenge failed, err: {}", e.code);
+        }
+    }
+}
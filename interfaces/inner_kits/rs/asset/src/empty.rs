@@ -21,6 +21,8 @@ pub use asset_definition::*;
 
 use ipc::{remote::RemoteObj};
 
+use crate::QueryPages;
+
 /// This manager provides the capabilities for life cycle management of sensitive user data (Asset) such as passwords
 /// and tokens, including adding, removing, updating, and querying.
 #[allow(dead_code)]
@@ -32,37 +34,71 @@ impl Manager {
     /// Build and initialize the Manager.
     pub fn build() -> Result<Arc<Mutex<Manager>>> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
     }
 
     /// Add an Asset.
     pub fn add(&mut self, _attributes: &AssetMap) -> Result<()> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
     }
 
     /// Remove one or more Assets that match a search query.
     pub fn remove(&mut self, _query: &AssetMap) -> Result<()> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
     }
 
     /// Update an Asset that matches a search query.
     pub fn update(&mut self, _query: &AssetMap, _attributes_to_update: &AssetMap) -> Result<()> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Rename an Asset that matches a search query.
+    pub fn rename(&mut self, _query: &AssetMap, _new_alias: &[u8]) -> Result<()> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Move or copy an Asset between the private space of the application and its groups.
+    pub fn transfer(&mut self, _query: &AssetMap, _target: &AssetMap, _keep_source: bool) -> Result<()> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
     }
 
     /// Add batch assets.
     pub fn batch_add(&mut self, _attributes_array: &[AssetMap]) -> Result<Vec<(u32, u32)>> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Add batch assets, the result of each item is returned.
+    pub fn batch_add_with_results(&mut self, _attributes_array: &[AssetMap]) -> Result<Vec<BatchItemResult>> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
     }
 
     /// Remove batch assets.
     pub fn batch_remove(&mut self, _attributes_array: &[AssetMap]) -> Result<()> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Remove batch assets, the result of each item is returned.
+    pub fn batch_remove_with_results(&mut self, _attributes_array: &[AssetMap]) -> Result<Vec<BatchItemResult>> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
     }
 
     /// Update batch assets.
@@ -72,30 +108,93 @@ impl Manager {
         _attributes_to_update_array: &[AssetMap]
     ) -> Result<Vec<(u32, u32)>> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Update batch assets, the result of each item is returned.
+    pub fn batch_update_with_results(
+        &mut self,
+        _attributes_array: &[AssetMap],
+        _attributes_to_update_array: &[AssetMap]
+    ) -> Result<Vec<BatchItemResult>> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Add, update and remove Assets of the same database in a single transaction.
+    pub fn transact(&mut self, _operations: &[TransactionOperation]) -> Result<Vec<BatchItemResult>> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
     }
 
     /// Preprocessing for querying one or more Assets that require user authentication.
     pub fn pre_query(&mut self, _query: &AssetMap) -> Result<Vec<u8>> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
     }
 
     /// Query one or more Assets that match a search query.
     pub fn query(&mut self, _query: &AssetMap) -> Result<Vec<AssetMap>> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Query the secrets of batch Assets which do not require user authentication.
+    pub fn batch_query(&mut self, _queries: &[AssetMap]) -> Result<Vec<BatchQueryItem>> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Query one page of the Assets that match a search query, the secrets are not returned.
+    pub fn query_page(&mut self, _query: &AssetMap) -> Result<AssetPage> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Query the Assets that match a search query page by page, the pages are fetched lazily by the iterator.
+    pub fn query_pages(&mut self, query: &AssetMap) -> QueryPages<'_> {
+        QueryPages::new(self, query)
     }
 
     /// Post-processing for querying multiple Assets that require user authentication.
     pub fn post_query(&mut self, _query: &AssetMap) -> Result<()> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Get the capabilities of the Asset service, which are fetched once and cached.
+    pub fn get_capabilities(&mut self) -> Result<Capabilities> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
     }
 
     /// Query the result of synchronization.
     pub fn query_sync_result(&mut self, _query: &AssetMap) -> Result<SyncResult> {
         macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Count the Assets that match a search query.
+    pub fn count(&mut self, _query: &AssetMap) -> Result<u32> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
+    }
+
+    /// Check whether any Asset matches a search query.
+    pub fn exists(&mut self, _query: &AssetMap) -> Result<bool> {
+        macros_lib::log_throw_error!( macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][RUST SDK]Asset service is not supported in empty mode" )
     }
 }
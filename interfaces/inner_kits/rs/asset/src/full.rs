@@ -16,32 +16,32 @@
 //! This module defines the interface of the Asset Rust SDK.
 
 use std::{
+    ops::Range,
     sync::{Arc, Mutex},
-    time::Instant,
+    thread,
 };
 
 use std::{ffi::CString, os::raw::c_char, sync::OnceLock};
 pub use asset_definition::*;
 
 use asset_log::logw;
-use ipc::{parcel::{MsgParcel, MsgOption}, remote::RemoteObj};
-use samgr::manage::SystemAbilityManager;
+use ipc::parcel::MsgParcel;
 
 pub use asset_ipc::{
-    deserialize_batch_result, deserialize_map, deserialize_maps, deserialize_sync_result, ipc_err_handle,
-    serialize_map, serialize_maps, IpcCode, IPC_SUCCESS, SA_ID, SA_NAME,
+    deserialize_batch_query_result, deserialize_batch_result, deserialize_capabilities,
+    deserialize_legacy_batch_result, deserialize_map, deserialize_maps, deserialize_page, deserialize_sync_result,
+    ipc_err_handle, serialize_map, serialize_maps, serialize_operations, IpcCode, BATCH_ITEM_RESULT_VERSION,
+    IPC_SUCCESS, MAX_BATCH_CHUNK_SIZE, SA_ID, SA_NAME,
 };
 
+use crate::{retry::is_idempotent, QueryPages, RemoteTransport, RetryPolicy, Transport};
+
 extern "C" {
-    fn GetTimeOut(timeout: *mut i32) -> i32;
     fn IsBeforeImageCreationPoint() -> bool;
     fn IsAbilityCreated() -> bool;
     fn ReportSnapshotFailure(load_interface_name: *const c_char, unload_interface_name: *const c_char);
 }
 
-const LOAD_TIMEOUT_IN_SECONDS: i32 = 4;
-const SUCCESS: i32 = 0;
-const MAX_ARRAY_CAPACITY: usize = 100;
 static ASSET_PLUGIN_LOCK: Mutex<()> = Mutex::new(());
 
 struct ImageInfo {
@@ -91,27 +91,139 @@ impl ImageInfo {
     }
 }
 
-fn load_asset_service() -> Result<RemoteObj> {
-    let mut timeout: i32 = 0;
-    let ret = unsafe { GetTimeOut(&mut timeout as *mut i32) };
-    if ret != SUCCESS {
-        timeout = LOAD_TIMEOUT_IN_SECONDS;
-    }
-    let start_time = Instant::now();
-    match SystemAbilityManager::load_system_ability(SA_ID, timeout) {
-        Some(remote) => Ok(remote),
-        None => {
-            logw!("load_asset_service time:{}s", start_time.elapsed().as_secs_f64());
-            macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::ServiceUnavailable, "[FATAL][RUST SDK]get remote service failed")
-        },
+fn get_feature(ipc_code: IpcCode) -> Option<Feature> {
+    match ipc_code {
+        IpcCode::BatchAdd | IpcCode::BatchRemove | IpcCode::BatchUpdate => Some(Feature::Batch),
+        IpcCode::QuerySyncResult => Some(Feature::Sync),
+        IpcCode::QueryPage => Some(Feature::QueryPage),
+        IpcCode::BatchQuery => Some(Feature::BatchQuery),
+        IpcCode::Transact => Some(Feature::Transaction),
+        IpcCode::Rename => Some(Feature::Rename),
+        IpcCode::Transfer => Some(Feature::Transfer),
+        IpcCode::Count | IpcCode::Exists => Some(Feature::Count),
+        _ => None,
+    }
+}
+
+fn get_max_size(capabilities: &Capabilities, tag: Tag) -> Option<u32> {
+    match tag {
+        Tag::Secret => Some(capabilities.max_secret_size),
+        Tag::DataLabelCritical1 | Tag::DataLabelCritical2 | Tag::DataLabelCritical3 | Tag::DataLabelCritical4
+        | Tag::DataLabelNormal1 | Tag::DataLabelNormal2 | Tag::DataLabelNormal3 | Tag::DataLabelNormal4
+        | Tag::DataLabelNormalLocal1 | Tag::DataLabelNormalLocal2 | Tag::DataLabelNormalLocal3
+        | Tag::DataLabelNormalLocal4 | Tag::DataLabelCustomCritical | Tag::DataLabelCustomNormal
+        | Tag::DataLabelCustomNormalLocal => Some(capabilities.max_label_size),
+        _ => None,
+    }
+}
+
+fn is_known_value(tag: Tag, value: u32) -> bool {
+    match ENUM_TAG_VALUES.iter().find(|(enum_tag, _)| *enum_tag == tag) {
+        Some((_, values)) => values.contains(&value),
+        None => true,
+    }
+}
+
+fn check_attributes(capabilities: &Capabilities, attributes: &AssetMap) -> Result<()> {
+    for (&tag, value) in attributes.iter() {
+        if !capabilities.supports_tag(tag) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::Unsupported, reason = ErrReason::TagUnsupported,
+                "[FATAL][RUST SDK]The tag [{}] is not supported by the service.", tag);
+        }
+        match value {
+            // The value unknown to the SDK is left to the service, which reports it as an invalid argument.
+            Value::Number(n) if is_known_value(tag, *n) && !capabilities.supports_value(tag, *n) => {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::Unsupported, reason = ErrReason::ValueUnsupported,
+                    "[FATAL][RUST SDK]The value[{}] of tag [{}] is not supported by the service.", *n, tag);
+            },
+            Value::Number(n) if tag == Tag::ReturnLimit && *n > capabilities.max_return_limit => {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::InvalidArgument, reason = ErrReason::NumberOutOfRange,
+                    "[FATAL][RUST SDK]The value[{}] of tag [{}] exceeds the limit of the service.", *n, tag);
+            },
+            Value::Number(n) if tag == Tag::WrapType && *n != WrapType::Never as u32
+                && !capabilities.supports_feature(Feature::Wrap) => {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+                    "[FATAL][RUST SDK]The feature [{}] is not supported by the service.",
+                    Feature::Wrap);
+            },
+            _ => {},
+        }
+        if let (Value::Bytes(bytes), Some(max_size)) = (value, get_max_size(capabilities, tag)) {
+            if bytes.len() > max_size as usize {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+                    "[FATAL][RUST SDK]The array length[{}] of tag [{}] exceeds the limit of the service.",
+                    bytes.len(), tag);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn get_alias(attributes: &AssetMap) -> Vec<u8> {
+    match attributes.get(&Tag::Alias) {
+        Some(Value::Bytes(alias)) => alias.clone(),
+        _ => vec![],
     }
 }
 
+// The service of an earlier version only replies the failed items, so the other items are regarded as succeeded.
+fn into_batch_results(attributes_array: &[AssetMap], failures: &[(ErrCode, u32)]) -> Vec<BatchItemResult> {
+    let mut results = Vec::with_capacity(attributes_array.len());
+    for (index, attributes) in attributes_array.iter().enumerate() {
+        let (index, alias) = (index as u32, get_alias(attributes));
+        match failures.iter().find(|(_, failed_index)| *failed_index == index) {
+            Some((code, _)) => results.push(BatchItemResult::failure(index, alias, *code, String::new())),
+            None => results.push(BatchItemResult::success(index, alias, None)),
+        }
+    }
+    results
+}
+
+// Send the batch chunk by chunk, and convert the index of each item from the chunk to the whole batch.
+fn process_in_chunks<F>(attributes_array: &[AssetMap], mut process: F) -> Result<Vec<BatchItemResult>>
+where
+    F: FnMut(Range<usize>) -> Result<Vec<BatchItemResult>>,
+{
+    if attributes_array.is_empty() {
+        return process(0..0);
+    }
+
+    let chunk_size = MAX_BATCH_CHUNK_SIZE as usize;
+    let mut results = Vec::with_capacity(attributes_array.len());
+    for start in (0..attributes_array.len()).step_by(chunk_size) {
+        let end = (start + chunk_size).min(attributes_array.len());
+        match process(start..end) {
+            Ok(chunk_results) => {
+                for mut item in chunk_results {
+                    item.index += start as u32;
+                    results.push(item);
+                }
+            },
+            Err(e) if start == 0 => return Err(e),
+            Err(e) => {
+                // The previous chunks have been committed, so the error is reported on the items left.
+                for (index, attributes) in attributes_array.iter().enumerate().skip(start) {
+                    results.push(BatchItemResult::failure(index as u32, get_alias(attributes), e.code, e.msg.clone()));
+                }
+                break;
+            },
+        }
+    }
+    Ok(results)
+}
+
 /// This manager provides the capabilities for life cycle management of sensitive user data (Asset) such as passwords
 /// and tokens, including adding, removing, updating, and querying.
 pub struct Manager {
-    remote: RemoteObj,
+    transport: Box<dyn Transport>,
+    retry_policy: RetryPolicy,
+    // None if not fetched yet, Some(None) if the service is too old to report its capabilities.
+    capabilities: Option<Option<Capabilities>>,
 }
 
 impl Manager {
@@ -126,14 +238,27 @@ impl Manager {
             }
 
             logw!("Create instance for Manager.");
-            let remote = load_asset_service()?;
-            let manager = Arc::new(Mutex::new(Manager { remote }));
+            let transport = RemoteTransport::connect()?;
+            let manager = Arc::new(Mutex::new(Manager::with_transport(Box::new(transport))));
             INSTANCE = Some(manager.clone());
 
             Ok(manager.clone())
         }
     }
 
+    /// Create a Manager which sends the requests through the specified transport.
+    ///
+    /// Unlike [`Manager::build`], the Manager created is not shared, e.g. it can be used to talk to an in-process
+    /// service in the integration tests.
+    pub fn with_transport(transport: Box<dyn Transport>) -> Self {
+        Manager { transport, retry_policy: RetryPolicy::default(), capabilities: None }
+    }
+
+    /// Set the policy to retry the requests failed because the service is unavailable.
+    pub fn set_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
     /// Add an Asset.
     pub fn add(&mut self, attributes: &AssetMap) -> Result<()> {
         self.snapshot_check_before_image(attributes, IpcCode::Add);
@@ -143,11 +268,22 @@ impl Manager {
         Ok(())
     }
 
-    /// Add batch assets.
+    /// Add batch assets, the error code and the index of each failed item are returned.
     pub fn batch_add(&mut self, attributes_array: &Vec<AssetMap>) -> Result<Vec<(u32, u32)>> {
+        let results = self.batch_add_with_results(attributes_array)?;
+        Ok(BatchItemResult::into_err_info(&results))
+    }
+
+    /// Add batch assets, the result of each item is returned in the order of the items.
+    ///
+    /// A batch larger than [`MAX_BATCH_CHUNK_SIZE`] is sent in chunks, each of which is committed on its own. If a
+    /// chunk fails as a whole after the previous chunks are committed, the error is reported on the items left.
+    pub fn batch_add_with_results(&mut self, attributes_array: &[AssetMap]) -> Result<Vec<BatchItemResult>> {
         self.snapshot_check_batch_before_image(attributes_array, IpcCode::BatchAdd);
         self.snapshot_check_groupid();
-        let ret = self.process_one_array_request_with_ret(attributes_array, IpcCode::BatchAdd)?;
+        let ret = process_in_chunks(attributes_array, |range| {
+            self.process_one_array_request_with_ret(&attributes_array[range], IpcCode::BatchAdd)
+        })?;
         self.snapshot_check_after_image(IpcCode::BatchAdd);
         Ok(ret)
     }
@@ -161,30 +297,86 @@ impl Manager {
         Ok(())
     }
 
-    /// Remove batch assets.
+    /// Remove batch assets, the items which are not found are ignored.
     pub fn batch_remove(&mut self, attributes_array: &Vec<AssetMap>) -> Result<()> {
+        self.batch_remove_with_results(attributes_array).map(|_| ())
+    }
+
+    /// Remove batch assets, the result of each item is returned in the order of the items.
+    ///
+    /// The batch is sent in chunks in the same way as [`Manager::batch_add_with_results`].
+    pub fn batch_remove_with_results(&mut self, attributes_array: &[AssetMap]) -> Result<Vec<BatchItemResult>> {
         self.snapshot_check_batch_before_image(attributes_array, IpcCode::BatchRemove);
         self.snapshot_check_groupid();
-        self.process_one_array_request(attributes_array, IpcCode::BatchRemove)?;
+        let ret = process_in_chunks(attributes_array, |range| {
+            self.process_one_array_request_with_ret(&attributes_array[range], IpcCode::BatchRemove)
+        })?;
         self.snapshot_check_after_image(IpcCode::BatchRemove);
-        Ok(())
+        Ok(ret)
     }
 
-    /// Update batch assets.
+    /// Update batch assets, the error code and the index of each failed item are returned.
     pub fn batch_update(
         &mut self,
         attributes_array: &Vec<AssetMap>,
         attributes_to_update_array: &Vec<AssetMap>
     ) -> Result<Vec<(u32, u32)>> {
+        let results = self.batch_update_with_results(attributes_array, attributes_to_update_array)?;
+        Ok(BatchItemResult::into_err_info(&results))
+    }
+
+    /// Update batch assets, the result of each item is returned in the order of the items.
+    ///
+    /// The batch is sent in chunks in the same way as [`Manager::batch_add_with_results`].
+    pub fn batch_update_with_results(
+        &mut self,
+        attributes_array: &[AssetMap],
+        attributes_to_update_array: &[AssetMap]
+    ) -> Result<Vec<BatchItemResult>> {
+        if attributes_array.len() != attributes_to_update_array.len() {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+                "[FATAL][RUST SDK]The array sizes [{}] and [{}] are different.",
+                attributes_array.len(), attributes_to_update_array.len());
+        }
         self.snapshot_check_batch_before_image(attributes_array, IpcCode::BatchUpdate);
         self.snapshot_check_groupid();
-        let ret = self.process_two_array_request_with_ret(
-            attributes_array, attributes_to_update_array, IpcCode::BatchUpdate
-        )?;
+        let ret = process_in_chunks(attributes_array, |range| {
+            self.process_two_array_request_with_ret(
+                &attributes_array[range.clone()], &attributes_to_update_array[range], IpcCode::BatchUpdate
+            )
+        })?;
         self.snapshot_check_after_image(IpcCode::BatchUpdate);
         Ok(ret)
     }
 
+    /// Add, update and remove Assets of the same database in a single transaction, the operations are executed in
+    /// order. Either all the operations take effect and a successful result is returned for each of them, or none of
+    /// them takes effect and only the result of the operation failed is returned.
+    ///
+    /// The transaction is not split into chunks, so it contains at most [`MAX_BATCH_CHUNK_SIZE`] operations.
+    pub fn transact(&mut self, operations: &[TransactionOperation]) -> Result<Vec<BatchItemResult>> {
+        if operations.is_empty() || operations.len() > MAX_BATCH_CHUNK_SIZE as usize {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+                "[FATAL][RUST SDK]The operation count[{}] is out of range (0, {}].",
+                operations.len(), MAX_BATCH_CHUNK_SIZE);
+        }
+        self.snapshot_check_before_image(operations[0].attributes(), IpcCode::Transact);
+        self.snapshot_check_groupid();
+        let maps = operations.iter().flat_map(|operation| match operation {
+            TransactionOperation::Update(query, attributes_to_update) => vec![query, attributes_to_update],
+            _ => vec![operation.attributes()],
+        });
+        self.check_capabilities(IpcCode::Transact, maps)?;
+        // The transaction may have been committed when the reply is lost, so it is never retried.
+        let mut reply =
+            self.send_with_retry(IpcCode::Transact, false, |parcel| serialize_operations(operations, parcel))?;
+        let ret = deserialize_batch_result(&mut reply)?;
+        self.snapshot_check_after_image(IpcCode::Transact);
+        Ok(ret)
+    }
+
     /// Update an Asset that matches a search query.
     pub fn update(&mut self, query: &AssetMap, attributes_to_update: &AssetMap) -> Result<()> {
         self.snapshot_check_before_image(query, IpcCode::Update);
@@ -194,6 +386,36 @@ impl Manager {
         Ok(())
     }
 
+    /// Rename an Asset that matches a search query, the secret of which is re-encrypted with the new alias by the
+    /// service. The query of the Asset requiring user authentication carries the challenge and the auth token.
+    pub fn rename(&mut self, query: &AssetMap, new_alias: &[u8]) -> Result<()> {
+        let mut attributes_to_update = AssetMap::new();
+        attributes_to_update.insert_attr(Tag::Alias, new_alias.to_vec());
+        self.snapshot_check_before_image(query, IpcCode::Rename);
+        self.snapshot_check_groupid();
+        self.process_two_agr_request(query, &attributes_to_update, IpcCode::Rename)?;
+        self.snapshot_check_after_image(IpcCode::Rename);
+        Ok(())
+    }
+
+    /// Move an Asset that matches a search query between the private space of the application and its groups, the
+    /// secret of which is re-encrypted by the service with the key of the target space. The target space is specified
+    /// by [`Tag::GroupId`] of the target attributes, or the private space if absent. The Asset is copied if
+    /// `keep_source` is true.
+    pub fn transfer(&mut self, query: &AssetMap, target: &AssetMap, keep_source: bool) -> Result<()> {
+        self.snapshot_check_before_image(query, IpcCode::Transfer);
+        self.snapshot_check_groupid();
+        self.check_capabilities(IpcCode::Transfer, [query, target])?;
+        // The Asset may have been transferred when the reply is lost, so it is never retried.
+        self.send_with_retry(IpcCode::Transfer, false, |parcel| {
+            serialize_map(query, parcel)?;
+            serialize_map(target, parcel)?;
+            parcel.write::<bool>(&keep_source).map_err(ipc_err_handle)
+        })?;
+        self.snapshot_check_after_image(IpcCode::Transfer);
+        Ok(())
+    }
+
     /// Preprocessing for querying one or more Assets that require user authentication.
     pub fn pre_query(&mut self, query: &AssetMap) -> Result<Vec<u8>> {
         self.snapshot_check_before_image(query, IpcCode::PreQuery);
@@ -212,6 +434,41 @@ impl Manager {
         Ok(res)
     }
 
+    /// Query one page of the Assets that match a search query, the secrets are not returned.
+    pub fn query_page(&mut self, query: &AssetMap) -> Result<AssetPage> {
+        self.snapshot_check_before_image(query, IpcCode::QueryPage);
+        self.snapshot_check_groupid();
+        let mut reply = self.process_one_agr_request(query, IpcCode::QueryPage)?;
+        let res = deserialize_page(&mut reply)?;
+        Ok(res)
+    }
+
+    /// Query the secrets of batch Assets which do not require user authentication. Each query specifies an Asset by
+    /// its alias, or the Assets matching its labels if the alias is absent.
+    ///
+    /// The Assets matched are returned in the order of the queries, and the index of each item is the index of the
+    /// query. A query failed is reported by an item without attributes rather than failing the whole batch.
+    pub fn batch_query(&mut self, queries: &Vec<AssetMap>) -> Result<Vec<BatchQueryItem>> {
+        self.snapshot_check_batch_before_image(queries, IpcCode::BatchQuery);
+        self.snapshot_check_groupid();
+        let chunk_size = MAX_BATCH_CHUNK_SIZE as usize;
+        let mut items = Vec::with_capacity(queries.len());
+        for start in (0..queries.len()).step_by(chunk_size) {
+            let end = (start + chunk_size).min(queries.len());
+            for mut item in self.process_batch_query_request(&queries[start..end])? {
+                item.result.index += start as u32;
+                items.push(item);
+            }
+        }
+        self.snapshot_check_after_image(IpcCode::BatchQuery);
+        Ok(items)
+    }
+
+    /// Query the Assets that match a search query page by page, the pages are fetched lazily by the iterator.
+    pub fn query_pages(&mut self, query: &AssetMap) -> QueryPages<'_> {
+        QueryPages::new(self, query)
+    }
+
     /// Post-processing for querying multiple Assets that require user authentication.
     pub fn post_query(&mut self, query: &AssetMap) -> Result<()> {
         self.process_one_agr_request(query, IpcCode::PostQuery)?;
@@ -234,29 +491,85 @@ impl Manager {
         }
     }
 
+    /// Count the Assets that match a search query, the attributes of which are not returned.
+    pub fn count(&mut self, query: &AssetMap) -> Result<u32> {
+        self.snapshot_check_before_image(query, IpcCode::Count);
+        self.snapshot_check_groupid();
+        let mut reply = self.process_one_agr_request(query, IpcCode::Count)?;
+        reply.read::<u32>().map_err(ipc_err_handle)
+    }
+
+    /// Check whether any Asset matches a search query, the attributes of which are not returned.
+    pub fn exists(&mut self, query: &AssetMap) -> Result<bool> {
+        self.snapshot_check_before_image(query, IpcCode::Exists);
+        self.snapshot_check_groupid();
+        let mut reply = self.process_one_agr_request(query, IpcCode::Exists)?;
+        reply.read::<bool>().map_err(ipc_err_handle)
+    }
+
+    /// Get the capabilities of the Asset service, which are fetched once and cached.
+    pub fn get_capabilities(&mut self) -> Result<Capabilities> {
+        match self.cached_capabilities()? {
+            Some(capabilities) => Ok(capabilities.clone()),
+            None => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+                "[FATAL][RUST SDK]The service does not report its capabilities."),
+        }
+    }
+
     fn rebuild(&mut self) -> Result<()> {
-        self.remote = load_asset_service()?;
+        self.transport.reconnect()?;
+        // The service may be upgraded after restarting.
+        self.capabilities = None;
         Ok(())
     }
 
-    fn process_one_agr_request(&mut self, attributes: &AssetMap, ipc_code: IpcCode) -> Result<MsgParcel> {
-        let mut parcel = MsgParcel::new();
-        parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
-        serialize_map(attributes, &mut parcel)?;
-        match self.send_request(parcel, ipc_code) {
-            Ok(msg) => Ok(msg),
-            Err(e) => match e.code {
-                ErrCode::ServiceUnavailable => {
-                    logw!("ServiceUnavailable, rebuild Manager");
-                    self.rebuild()?;
-                    let mut parcel = MsgParcel::new();
-                    parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
-                    serialize_map(attributes, &mut parcel)?;
-                    self.send_request(parcel, ipc_code)
-                },
-                _ => Err(e),
+    fn cached_capabilities(&mut self) -> Result<Option<&Capabilities>> {
+        if self.capabilities.is_none() {
+            self.capabilities = Some(self.fetch_capabilities()?);
+        }
+        Ok(self.capabilities.as_ref().and_then(|capabilities| capabilities.as_ref()))
+    }
+
+    fn fetch_capabilities(&mut self) -> Result<Option<Capabilities>> {
+        match self.send_with_retry(IpcCode::GetCapabilities, true, |_| Ok(())) {
+            Ok(mut reply) => Ok(Some(deserialize_capabilities(&mut reply)?)),
+            Err(e) if e.code == ErrCode::IpcError => {
+                // The service of an earlier version rejects the unknown IPC code.
+                logw!("[RUST SDK]The service does not report its capabilities.");
+                Ok(None)
             },
+            Err(e) => Err(e),
+        }
+    }
+
+    // Fail fast if the request uses something that the service is known to be unable to do.
+    fn check_capabilities<'a>(
+        &mut self,
+        ipc_code: IpcCode,
+        maps: impl IntoIterator<Item = &'a AssetMap>,
+    ) -> Result<()> {
+        let Some(capabilities) = self.cached_capabilities()? else {
+            return Ok(());
+        };
+        if let Some(feature) = get_feature(ipc_code) {
+            if !capabilities.supports_feature(feature) {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+                    "[FATAL][RUST SDK]The feature [{}] is not supported by the service.",
+                    feature);
+            }
+        }
+        for map in maps {
+            check_attributes(capabilities, map)?;
         }
+        Ok(())
+    }
+
+    fn process_one_agr_request(&mut self, attributes: &AssetMap, ipc_code: IpcCode) -> Result<MsgParcel> {
+        self.check_capabilities(ipc_code, [attributes])?;
+        let idempotent = is_idempotent(ipc_code, [attributes].into_iter());
+        self.send_with_retry(ipc_code, idempotent, |parcel| serialize_map(attributes, parcel))
     }
 
     fn process_two_agr_request(
@@ -265,132 +578,110 @@ impl Manager {
         attributes_to_update: &AssetMap,
         ipc_code: IpcCode,
     ) -> Result<MsgParcel> {
-        let mut parcel = MsgParcel::new();
-        parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
-        serialize_map(query, &mut parcel)?;
-        serialize_map(attributes_to_update, &mut parcel)?;
-        match self.send_request(parcel, ipc_code) {
-            Ok(msg) => Ok(msg),
-            Err(e) => match e.code {
-                ErrCode::ServiceUnavailable => {
-                    self.rebuild()?;
-                    let mut parcel = MsgParcel::new();
-                    parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
-                    serialize_map(query, &mut parcel)?;
-                    serialize_map(attributes_to_update, &mut parcel)?;
-                    self.send_request(parcel, ipc_code)
-                },
-                _ => Err(e),
-            },
-        }
+        self.check_capabilities(ipc_code, [query, attributes_to_update])?;
+        let idempotent = is_idempotent(ipc_code, [query].into_iter());
+        self.send_with_retry(ipc_code, idempotent, |parcel| {
+            serialize_map(query, parcel)?;
+            serialize_map(attributes_to_update, parcel)
+        })
     }
 
-    fn process_one_array_request_with_ret(
+    fn read_batch_result(
         &mut self,
-        attributes_array: &Vec<AssetMap>,
+        reply: &mut MsgParcel,
+        attributes_array: &[AssetMap],
         ipc_code: IpcCode,
-    ) -> Result<Vec<(u32, u32)>> {
-        let mut parcel = MsgParcel::new();
-        parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
-        if attributes_array.len() > MAX_ARRAY_CAPACITY {
-            return macros_lib::throw_error!( macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL][IPC]The array size {} exceeds the limit", attributes_array.len() );
+    ) -> Result<Vec<BatchItemResult>> {
+        if matches!(self.cached_capabilities()?,
+            Some(capabilities) if capabilities.protocol_version >= BATCH_ITEM_RESULT_VERSION) {
+            return deserialize_batch_result(reply);
         }
-        serialize_maps(attributes_array, &mut parcel)?;
-        match self.send_request(parcel, ipc_code) {
-            Ok(mut reply) => {
-                let res = deserialize_batch_result(&mut reply)?;
-                Ok(res)
-            },
-            Err(e) => match e.code {
-                ErrCode::ServiceUnavailable => {
-                    logw!("ServiceUnavailable, rebuild Manager");
-                    self.rebuild()?;
-                    let mut parcel = MsgParcel::new();
-                    parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
-                    serialize_maps(attributes_array, &mut parcel)?;
-                    let mut reply = self.send_request(parcel, ipc_code)?;
-                    let res = deserialize_batch_result(&mut reply)?;
-                    Ok(res)
-                },
-                _ => Err(e),
-            },
+        match ipc_code {
+            // The service of an earlier version replies nothing but the result code for BatchRemove.
+            IpcCode::BatchRemove => Ok(into_batch_results(attributes_array, &[])),
+            _ => Ok(into_batch_results(attributes_array, &deserialize_legacy_batch_result(reply)?)),
         }
     }
 
-    fn process_two_array_request_with_ret(
+    fn process_one_array_request_with_ret(
         &mut self,
-        attributes_array: &Vec<AssetMap>,
-        attributes_to_update_array: &Vec<AssetMap>,
+        attributes_array: &[AssetMap],
         ipc_code: IpcCode,
-    ) -> Result<Vec<(u32, u32)>> {
-        let mut parcel = MsgParcel::new();
-        parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
-        if attributes_array.len() > MAX_ARRAY_CAPACITY {
-            return macros_lib::throw_error!( macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL][IPC]The array size {} exceeds the limit", attributes_array.len() );
-        }
-        serialize_maps(attributes_array, &mut parcel)?;
-        serialize_maps(attributes_to_update_array, &mut parcel)?;
-        match self.send_request(parcel, ipc_code) {
-            Ok(mut reply) => {
-                let res = deserialize_batch_result(&mut reply)?;
-                Ok(res)
-            },
-            Err(e) => match e.code {
-                ErrCode::ServiceUnavailable => {
-                    logw!("ServiceUnavailable, rebuild Manager");
-                    self.rebuild()?;
-                    let mut parcel = MsgParcel::new();
-                    parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
-                    serialize_maps(attributes_array, &mut parcel)?;
-                    serialize_maps(attributes_to_update_array, &mut parcel)?;
-                    let mut reply = self.send_request(parcel, ipc_code)?;
-                    let res = deserialize_batch_result(&mut reply)?;
-                    Ok(res)
-                },
-                _ => Err(e),
-            },
-        }
+    ) -> Result<Vec<BatchItemResult>> {
+        self.check_capabilities(ipc_code, attributes_array.iter())?;
+        let idempotent = is_idempotent(ipc_code, attributes_array.iter());
+        let mut reply = self.send_with_retry(ipc_code, idempotent, |parcel| serialize_maps(attributes_array, parcel))?;
+        self.read_batch_result(&mut reply, attributes_array, ipc_code)
     }
 
-    fn process_one_array_request(
+    fn process_two_array_request_with_ret(
         &mut self,
-        attributes_array: &Vec<AssetMap>,
+        attributes_array: &[AssetMap],
+        attributes_to_update_array: &[AssetMap],
         ipc_code: IpcCode,
-    ) -> Result<MsgParcel> {
-        let mut parcel = MsgParcel::new();
-        parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
-        if attributes_array.len() > MAX_ARRAY_CAPACITY {
-            return macros_lib::throw_error!( macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL][IPC]The array size {} exceeds the limit", attributes_array.len() );
-        }
-        serialize_maps(attributes_array, &mut parcel)?;
-        match self.send_request(parcel, ipc_code) {
-            Ok(msg) => Ok(msg),
-            Err(e) => match e.code {
-                ErrCode::ServiceUnavailable => {
-                    logw!("ServiceUnavailable, rebuild Manager");
-                    self.rebuild()?;
-                    let mut parcel = MsgParcel::new();
-                    parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
-                    serialize_maps(attributes_array, &mut parcel)?;
-                    self.send_request(parcel, ipc_code)
-                },
-                _ => Err(e),
-            },
+    ) -> Result<Vec<BatchItemResult>> {
+        self.check_capabilities(ipc_code, attributes_array.iter().chain(attributes_to_update_array.iter()))?;
+        let idempotent = is_idempotent(ipc_code, attributes_array.iter());
+        let mut reply = self.send_with_retry(ipc_code, idempotent, |parcel| {
+            serialize_maps(attributes_array, parcel)?;
+            serialize_maps(attributes_to_update_array, parcel)
+        })?;
+        self.read_batch_result(&mut reply, attributes_array, ipc_code)
+    }
+
+    fn process_batch_query_request(&mut self, queries: &[AssetMap]) -> Result<Vec<BatchQueryItem>> {
+        self.check_capabilities(IpcCode::BatchQuery, queries.iter())?;
+        let idempotent = is_idempotent(IpcCode::BatchQuery, queries.iter());
+        let mut reply =
+            self.send_with_retry(IpcCode::BatchQuery, idempotent, |parcel| serialize_maps(queries, parcel))?;
+        deserialize_batch_query_result(&mut reply)
+    }
+
+    // The parcel is rebuilt for each attempt, and the connection is rebuilt if the service is unavailable. Only the
+    // idempotent requests are retried by the policy.
+    fn send_with_retry<F>(&mut self, ipc_code: IpcCode, idempotent: bool, write_args: F) -> Result<MsgParcel>
+    where
+        F: Fn(&mut MsgParcel) -> Result<()>,
+    {
+        let mut attempt = 1;
+        loop {
+            let mut parcel = MsgParcel::new();
+            parcel.write_interface_token(self.descriptor()).map_err(ipc_err_handle)?;
+            write_args(&mut parcel)?;
+            let e = match self.send_request(parcel, ipc_code) {
+                Ok(reply) => return Ok(reply),
+                Err(e) => e,
+            };
+            let delay = match idempotent {
+                true => self.retry_policy.next_delay(ipc_code, attempt, &e),
+                false => None,
+            };
+            let Some(delay) = delay else {
+                return Err(e);
+            };
+            logw!("[RUST SDK]Attempt[{}] of [{}] failed with [{}], retry after {}ms.",
+                attempt, ipc_code, e.code, delay.as_millis());
+            thread::sleep(delay);
+            if e.code == ErrCode::ServiceUnavailable {
+                self.rebuild()?;
+            }
+            attempt += 1;
         }
     }
 
-    fn send_request(&self, mut parcel: MsgParcel, ipc_code: IpcCode) -> Result<MsgParcel> {
-        let mut option = MsgOption::new();
-        option.set_image();
-        let mut reply = self.remote.send_request_ext(ipc_code as u32, &mut parcel, option).map_err(ipc_err_handle)?;
+    fn send_request(&mut self, mut parcel: MsgParcel, ipc_code: IpcCode) -> Result<MsgParcel> {
+        let mut reply = self.transport.send_request(ipc_code, &mut parcel)?;
         match reply.read::<u32>().map_err(ipc_err_handle)? {
             IPC_SUCCESS => Ok(reply),
             e => {
                 let msg = reply.read::<String>().map_err(ipc_err_handle)?;
-                macros_lib::throw_error!(macros_lib::hisysevent::function!(), ErrCode::try_from(e)?, "{}", msg)
+                // The reason is absent in the reply of an earlier service version.
+                let reason = match reply.read::<u32>() {
+                    Ok(reason) => ErrReason::try_from(reason).unwrap_or_default(),
+                    Err(_) => ErrReason::Unspecified,
+                };
+                macros_lib::throw_error!(macros_lib::hisysevent::function!(), ErrCode::try_from(e)?,
+                    reason = reason, "{}", msg)
             },
         }
     }
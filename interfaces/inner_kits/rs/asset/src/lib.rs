@@ -15,12 +15,43 @@
 
 //! This module defines the interface of the Asset Rust SDK.
 
-#[cfg(not(feature = "AssetEmptyMode"))]
+#[cfg(not(any(feature = "AssetEmptyMode", feature = "AssetMockMode")))]
 pub mod full;
 
+#[cfg(not(any(feature = "AssetEmptyMode", feature = "AssetMockMode")))]
+mod async_manager;
+
+#[cfg(not(feature = "AssetEmptyMode"))]
+mod auth_session;
+
 #[cfg(feature = "AssetEmptyMode")]
 pub mod empty;
 
+#[cfg(feature = "AssetMockMode")]
+pub mod mock;
+
+mod query_pages;
+
+#[cfg(not(any(feature = "AssetEmptyMode", feature = "AssetMockMode")))]
+mod retry;
+
+#[cfg(not(any(feature = "AssetEmptyMode", feature = "AssetMockMode")))]
+mod transport;
+
+pub use query_pages::QueryPages;
+
+#[cfg(not(any(feature = "AssetEmptyMode", feature = "AssetMockMode")))]
+pub use retry::{RetryEvent, RetryListener, RetryPolicy};
+
+#[cfg(not(any(feature = "AssetEmptyMode", feature = "AssetMockMode")))]
+pub use transport::{RemoteTransport, Transport};
+
+#[cfg(not(any(feature = "AssetEmptyMode", feature = "AssetMockMode")))]
+pub use async_manager::AsyncManager;
+
+#[cfg(not(feature = "AssetEmptyMode"))]
+pub use auth_session::AuthSession;
+
 pub use asset_definition::*;
 
-pub use asset_ipc::{deserialize_map, deserialize_maps};
+pub use asset_ipc::{deserialize_map, deserialize_maps, MAX_BATCH_CHUNK_SIZE};
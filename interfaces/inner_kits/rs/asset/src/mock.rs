@@ -0,0 +1,1022 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module defines the interface of the Asset Rust SDK backed by an in-memory store, which simulates the Asset
+//! service so that the applications can be unit-tested without the service.
+
+use std::{
+    cmp::Ordering,
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    sync::{Arc, Mutex, OnceLock},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+pub use asset_definition::*;
+
+use asset_ipc::{MAX_BATCH_CHUNK_SIZE, PROTOCOL_VERSION};
+
+use crate::QueryPages;
+
+const CRITICAL_LABEL_ATTRS: [Tag; 4] =
+    [Tag::DataLabelCritical1, Tag::DataLabelCritical2, Tag::DataLabelCritical3, Tag::DataLabelCritical4];
+const NORMAL_LABEL_ATTRS: [Tag; 4] =
+    [Tag::DataLabelNormal1, Tag::DataLabelNormal2, Tag::DataLabelNormal3, Tag::DataLabelNormal4];
+const NORMAL_LOCAL_LABEL_ATTRS: [Tag; 4] =
+    [Tag::DataLabelNormalLocal1, Tag::DataLabelNormalLocal2, Tag::DataLabelNormalLocal3, Tag::DataLabelNormalLocal4];
+const CUSTOM_LABEL_ATTRS: [Tag; 3] =
+    [Tag::DataLabelCustomCritical, Tag::DataLabelCustomNormal, Tag::DataLabelCustomNormalLocal];
+const NORMAL_CUSTOM_LABEL_ATTRS: [Tag; 2] = [Tag::DataLabelCustomNormal, Tag::DataLabelCustomNormalLocal];
+const ACCESS_CONTROL_ATTRS: [Tag; 10] = [
+    Tag::Alias,
+    Tag::Accessibility,
+    Tag::AuthType,
+    Tag::IsPersistent,
+    Tag::SyncType,
+    Tag::RequirePasswordSet,
+    Tag::RequireAttrEncrypted,
+    Tag::GroupId,
+    Tag::WrapType,
+    Tag::UserId,
+];
+const ASSET_SYNC_ATTRS: [Tag; 1] = [Tag::OperationType];
+const ADD_REQUIRED_ATTRS: [Tag; 2] = [Tag::Secret, Tag::Alias];
+const ADD_OPTIONAL_ATTRS: [Tag; 6] = [
+    Tag::Secret,
+    Tag::ConflictResolution,
+    Tag::WrapType,
+    Tag::ExpireTime,
+    Tag::MaxAccessCount,
+    Tag::InvalidateOnBiometricChange,
+];
+const QUERY_OPTIONAL_ATTRS: [Tag; 9] = [
+    Tag::ReturnLimit,
+    Tag::ReturnOffset,
+    Tag::ReturnOrderedBy,
+    Tag::ReturnOrderedByKeys,
+    Tag::ReturnOrder,
+    Tag::ReturnType,
+    Tag::AuthToken,
+    Tag::AuthChallenge,
+    Tag::QueryFilter,
+];
+const PRE_QUERY_OPTIONAL_ATTRS: [Tag; 1] = [Tag::AuthValidityPeriod];
+const POST_QUERY_ATTRS: [Tag; 3] = [Tag::AuthChallenge, Tag::GroupId, Tag::UserId];
+const PAGE_QUERY_OPTIONAL_ATTRS: [Tag; 4] = [Tag::ReturnLimit, Tag::ReturnType, Tag::PageToken, Tag::QueryFilter];
+const BATCH_QUERY_ATTRS: [Tag; 3] = [Tag::Alias, Tag::GroupId, Tag::RequireAttrEncrypted];
+const FILTER_ONLY_ATTRS: [Tag; 3] = [Tag::ReturnLimit, Tag::ReturnOffset, Tag::ReturnOrderedBy];
+
+// The tags which are used to locate an Asset, in addition to its alias.
+const SCOPE_ATTRS: [Tag; 2] = [Tag::GroupId, Tag::UserId];
+// The tags which are not attributes of the Asset, and are not stored.
+const OPTION_ATTRS: [Tag; 12] = [
+    Tag::ConflictResolution,
+    Tag::ReturnLimit,
+    Tag::ReturnOffset,
+    Tag::ReturnOrderedBy,
+    Tag::ReturnOrderedByKeys,
+    Tag::ReturnOrder,
+    Tag::ReturnType,
+    Tag::AuthToken,
+    Tag::AuthChallenge,
+    Tag::AuthValidityPeriod,
+    Tag::PageToken,
+    Tag::QueryFilter,
+];
+// The tags which are stored but not returned, the same as the service.
+const HIDDEN_ATTRS: [Tag; 4] = [Tag::GroupId, Tag::UserId, Tag::RequireAttrEncrypted, Tag::OperationType];
+
+const MAX_SECRET_SIZE: usize = 1024;
+const MAX_ALIAS_SIZE: usize = 256;
+const MAX_LABEL_SIZE: usize = 2048;
+const MAX_AUTH_TOKEN_SIZE: usize = 1024;
+const MIN_GROUP_ID_LEN: usize = 6;
+const MAX_GROUP_ID_LEN: usize = 127;
+const MAX_RETURN_LIMIT: u32 = 0x10000;
+const MAX_AUTH_VALID_PERIOD: u32 = 600;
+const DEFAULT_AUTH_VALIDITY_IN_SECS: u32 = 60;
+const CHALLENGE_SIZE: usize = 32;
+const CRYPTO_CAPACITY: usize = 16;
+const DEFAULT_PAGE_SIZE: u32 = 100;
+const MAX_PAGE_SIZE: u32 = 1000;
+const MAX_IN_LIST_SIZE: usize = 100;
+const MAX_ORDER_KEYS: usize = 4;
+
+fn check_required_tags(attrs: &AssetMap, required_tags: &[Tag]) -> Result<()> {
+    for tag in required_tags {
+        if !attrs.contains_key(tag) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::MissingTag,
+                "[FATAL][MOCK]The required tag [{}] is missing.", tag);
+        }
+    }
+    Ok(())
+}
+
+fn check_tag_validity(attrs: &AssetMap, valid_tags: &[&[Tag]]) -> Result<()> {
+    for tag in attrs.keys() {
+        if !valid_tags.iter().any(|tags| tags.contains(tag)) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::IllegalTag, "[FATAL][MOCK]The tag [{}] is illegal.", tag);
+        }
+    }
+    Ok(())
+}
+
+fn check_array_size(tag: &Tag, bytes: &[u8], min: usize, max: usize) -> Result<()> {
+    if bytes.len() > max || bytes.len() < min {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL][MOCK]The array length[{}] of Tag[{}], exceeds the valid range.", bytes.len(), tag);
+    }
+    Ok(())
+}
+
+fn check_number_range(tag: &Tag, n: u32, max: u32) -> Result<()> {
+    if n == 0 || n > max {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::NumberOutOfRange,
+            "[FATAL][MOCK]The value[{}] of Tag[{}] is not in the valid number range.", n, tag);
+    }
+    Ok(())
+}
+
+fn check_value_validity(attrs: &AssetMap) -> Result<()> {
+    for (tag, value) in attrs {
+        if tag.data_type() != value.data_type() {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch,
+                "[FATAL][MOCK]The data type[{}] of the tag[{}] does not match that of the value.",
+                value.data_type(), tag);
+        }
+        match (tag, value) {
+            (Tag::Secret, Value::Bytes(bytes)) => check_array_size(tag, bytes, 1, MAX_SECRET_SIZE)?,
+            (Tag::Alias, Value::Bytes(bytes)) => check_array_size(tag, bytes, 1, MAX_ALIAS_SIZE)?,
+            (Tag::AuthChallenge, Value::Bytes(bytes)) => check_array_size(tag, bytes, CHALLENGE_SIZE, CHALLENGE_SIZE)?,
+            (Tag::AuthToken, Value::Bytes(bytes)) => check_array_size(tag, bytes, 1, MAX_AUTH_TOKEN_SIZE)?,
+            (Tag::GroupId, Value::Bytes(bytes)) => {
+                check_array_size(tag, bytes, MIN_GROUP_ID_LEN + 1, MAX_GROUP_ID_LEN)?
+            },
+            (Tag::ReturnLimit, Value::Number(n)) => check_number_range(tag, *n, MAX_RETURN_LIMIT)?,
+            (Tag::AuthValidityPeriod, Value::Number(n)) => check_number_range(tag, *n, MAX_AUTH_VALID_PERIOD)?,
+            (_, Value::Bytes(bytes)) if is_label(tag) => check_array_size(tag, bytes, 1, MAX_LABEL_SIZE)?,
+            (Tag::QueryFilter, Value::Bytes(bytes)) => check_query_filter(bytes)?,
+            (Tag::ReturnOrderedByKeys, Value::Bytes(bytes)) => check_order_keys(bytes)?,
+            (_, Value::Number(n)) => {
+                if let Some((_, values)) = ENUM_TAG_VALUES.iter().find(|(enum_tag, _)| enum_tag == tag) {
+                    if !values.contains(n) {
+                        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                            ErrCode::InvalidArgument, reason = ErrReason::InvalidEnumVariant,
+                            "[FATAL][MOCK]The value[{}] of Tag[{}] is not a legal enumeration variant", n, tag);
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+    if attrs.contains_key(&Tag::GroupId) && matches!(attrs.get(&Tag::IsPersistent), Some(Value::Bool(true))) {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
+            "[FATAL][MOCK]The value of the tag [{}] cannot be set to true when the tag [{}] is specified.",
+            Tag::IsPersistent, Tag::GroupId);
+    }
+    Ok(())
+}
+
+fn check_query_filter(bytes: &[u8]) -> Result<()> {
+    let predicates = Predicate::decode_list(bytes)?;
+    let is_valid = !predicates.is_empty() && predicates.iter().all(|predicate| match predicate {
+        Predicate::Prefix(tag, prefix) => {
+            (*tag == Tag::Alias || (is_label(tag) && !CUSTOM_LABEL_ATTRS.contains(tag))) && !prefix.is_empty()
+        },
+        Predicate::Range(tag, from, to) => {
+            [Tag::CreateTime, Tag::UpdateTime].contains(tag) && (from.is_some() || to.is_some())
+                && !matches!((from, to), (Some(from), Some(to)) if from > to)
+        },
+        Predicate::In(tag, values) => *tag == Tag::Alias && !values.is_empty() && values.len() <= MAX_IN_LIST_SIZE,
+    });
+    if !is_valid {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+            "[FATAL][MOCK]The predicates of Tag[{}] are invalid.", Tag::QueryFilter);
+    }
+    Ok(())
+}
+
+fn check_order_keys(bytes: &[u8]) -> Result<()> {
+    let keys: Vec<Tag> = OrderKey::decode_list(bytes)?.into_iter().map(|(key, _)| key).collect();
+    let is_valid = !keys.is_empty() && keys.len() <= MAX_ORDER_KEYS && keys.iter().enumerate().all(|(i, key)| {
+        (is_label(key) && !CUSTOM_LABEL_ATTRS.contains(key) || [Tag::CreateTime, Tag::UpdateTime].contains(key))
+            && !keys[..i].contains(key)
+    });
+    if !is_valid {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::TagOutOfRange,
+            "[FATAL][MOCK]The sort keys of Tag[{}] are invalid.", Tag::ReturnOrderedByKeys);
+    }
+    Ok(())
+}
+
+fn is_label(tag: &Tag) -> bool {
+    CRITICAL_LABEL_ATTRS.contains(tag) || NORMAL_LABEL_ATTRS.contains(tag) || NORMAL_LOCAL_LABEL_ATTRS.contains(tag)
+        || CUSTOM_LABEL_ATTRS.contains(tag)
+}
+
+fn get_alias(attributes: &AssetMap) -> Vec<u8> {
+    match attributes.get(&Tag::Alias) {
+        Some(Value::Bytes(alias)) => alias.clone(),
+        _ => vec![],
+    }
+}
+
+fn get_number(attributes: &AssetMap, tag: Tag) -> Option<u32> {
+    match attributes.get(&tag) {
+        Some(Value::Number(n)) => Some(*n),
+        _ => None,
+    }
+}
+
+fn time_since_epoch() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+fn random_bytes(len: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(len);
+    while bytes.len() < len {
+        // The hasher is seeded randomly each time, which is enough for the fake challenges.
+        bytes.extend(RandomState::new().build_hasher().finish().to_le_bytes());
+    }
+    bytes.truncate(len);
+    bytes
+}
+
+fn is_challenge_of(query: &AssetMap, challenge: &MockChallenge) -> bool {
+    matches!(query.get(&Tag::AuthChallenge), Some(Value::Bytes(bytes)) if *bytes == challenge.challenge)
+}
+
+fn is_auth_required(attributes: &AssetMap) -> bool {
+    matches!(get_number(attributes, Tag::AuthType), Some(n) if n != AuthType::None as u32)
+}
+
+// The alias and the scope which locate an Asset in the store.
+fn location_of(attributes: &AssetMap) -> AssetMap {
+    let mut location: AssetMap =
+        SCOPE_ATTRS.iter().filter_map(|tag| attributes.get(tag).map(|value| (*tag, value.clone()))).collect();
+    if let Some(alias) = attributes.get(&Tag::Alias) {
+        location.insert(Tag::Alias, alias.clone());
+    }
+    location
+}
+
+fn compare_by_tag(a: &MockAsset, b: &MockAsset, tag: Tag) -> Ordering {
+    match (a.attributes.get(&tag), b.attributes.get(&tag)) {
+        (Some(Value::Bytes(a)), Some(Value::Bytes(b))) => a.cmp(b),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        _ => Ordering::Equal,
+    }
+}
+
+#[derive(Clone)]
+struct MockAsset {
+    id: u32,
+    attributes: AssetMap,
+}
+
+impl MockAsset {
+    fn is_expired(&self) -> bool {
+        let now = time_since_epoch().as_secs();
+        matches!(get_number(&self.attributes, Tag::ExpireTime), Some(secs) if u64::from(secs) <= now)
+    }
+
+    // The Assets in a group are isolated from the private ones of the caller, and from those in other groups.
+    fn is_matched(&self, query: &AssetMap) -> bool {
+        SCOPE_ATTRS.iter().all(|tag| self.attributes.get(tag) == query.get(tag))
+            && query.iter().filter(|(tag, _)| !SCOPE_ATTRS.contains(tag) && !OPTION_ATTRS.contains(tag))
+                .all(|(tag, value)| self.attributes.get(tag) == Some(value))
+            && match query.get(&Tag::QueryFilter) {
+                Some(Value::Bytes(bytes)) => Predicate::decode_list(bytes)
+                    .is_ok_and(|predicates| predicates.iter().all(|predicate| self.is_predicate_matched(predicate))),
+                _ => true,
+            }
+    }
+
+    fn is_predicate_matched(&self, predicate: &Predicate) -> bool {
+        let Some(Value::Bytes(value)) = self.attributes.get(&predicate.tag()) else {
+            return false;
+        };
+        match predicate {
+            Predicate::Prefix(_, prefix) => value.starts_with(prefix),
+            Predicate::Range(_, from, to) => {
+                let time = std::str::from_utf8(value).ok().and_then(|time| time.parse::<u64>().ok());
+                matches!(time, Some(time) if !matches!(from, Some(from) if time < *from)
+                    && !matches!(to, Some(to) if time > *to))
+            },
+            Predicate::In(_, values) => values.contains(&value.as_slice()),
+        }
+    }
+
+    fn to_asset_map(&self, with_secret: bool) -> AssetMap {
+        let mut map = self.attributes.clone();
+        for tag in HIDDEN_ATTRS {
+            map.remove(&tag);
+        }
+        if !with_secret {
+            map.remove(&Tag::Secret);
+        }
+        map
+    }
+}
+
+struct MockChallenge {
+    challenge: Vec<u8>,
+    scope: AssetMap,
+    expires_at: Instant,
+}
+
+/// This manager provides the same capabilities as the one talking to the Asset service, with the Assets kept in
+/// memory.
+///
+/// The challenges of user authentication are faked, and any non-empty token is accepted as the authentication
+/// result. The secrets are not encrypted.
+pub struct Manager {
+    assets: Vec<MockAsset>,
+    next_id: u32,
+    challenges: Vec<MockChallenge>,
+    // The locations of the Assets removed because their access count is exhausted.
+    exhausted: Vec<AssetMap>,
+}
+
+impl Default for Manager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Manager {
+    /// Build and initialize the Manager, the store of which is shared in the process.
+    pub fn build() -> Result<Arc<Mutex<Manager>>> {
+        static INSTANCE: OnceLock<Arc<Mutex<Manager>>> = OnceLock::new();
+        Ok(INSTANCE.get_or_init(|| Arc::new(Mutex::new(Manager::new()))).clone())
+    }
+
+    /// Create a Manager with an empty store of its own, which isolates the test cases from each other.
+    pub fn new() -> Self {
+        Self { assets: vec![], next_id: 1, challenges: vec![], exhausted: vec![] }
+    }
+
+    /// Add an Asset.
+    pub fn add(&mut self, attributes: &AssetMap) -> Result<()> {
+        self.add_inner(attributes).map(|_| ())
+    }
+
+    /// Add batch assets, the error code and the index of each failed item are returned.
+    pub fn batch_add(&mut self, attributes_array: &[AssetMap]) -> Result<Vec<(u32, u32)>> {
+        let results = self.batch_add_with_results(attributes_array)?;
+        Ok(BatchItemResult::into_err_info(&results))
+    }
+
+    /// Add batch assets, the result of each item is returned in the order of the items.
+    pub fn batch_add_with_results(&mut self, attributes_array: &[AssetMap]) -> Result<Vec<BatchItemResult>> {
+        check_batch_consistency(attributes_array)?;
+        let mut results = Vec::with_capacity(attributes_array.len());
+        for (index, attributes) in attributes_array.iter().enumerate() {
+            let (index, alias) = (index as u32, get_alias(attributes));
+            match self.add_inner(attributes) {
+                Ok(id) => results.push(BatchItemResult::success(index, alias, Some(id))),
+                Err(e) => results.push(BatchItemResult::failure(index, alias, e.code, e.msg)),
+            }
+        }
+        Ok(results)
+    }
+
+    /// Remove one or more Assets that match a search query.
+    pub fn remove(&mut self, query: &AssetMap) -> Result<()> {
+        check_tag_validity(query, &[&CRITICAL_LABEL_ATTRS, &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS,
+            &CUSTOM_LABEL_ATTRS, &ACCESS_CONTROL_ATTRS, &ASSET_SYNC_ATTRS])?;
+        check_value_validity(query)?;
+        // The alias is available again, so the record of its exhausted access count is no longer valid.
+        if query.contains_key(&Tag::Alias) {
+            let location = location_of(query);
+            self.exhausted.retain(|exhausted| *exhausted != location);
+        }
+        let count = self.assets.len();
+        self.assets.retain(|asset| !asset.is_matched(query));
+        if self.assets.len() == count {
+            return macros_lib::throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+                "[FATAL][MOCK]The data to be deleted does not exist.");
+        }
+        Ok(())
+    }
+
+    /// Remove batch assets, the items which are not found are ignored.
+    pub fn batch_remove(&mut self, attributes_array: &[AssetMap]) -> Result<()> {
+        self.batch_remove_with_results(attributes_array).map(|_| ())
+    }
+
+    /// Remove batch assets, the result of each item is returned in the order of the items.
+    pub fn batch_remove_with_results(&mut self, attributes_array: &[AssetMap]) -> Result<Vec<BatchItemResult>> {
+        let mut results = Vec::with_capacity(attributes_array.len());
+        for (index, query) in attributes_array.iter().enumerate() {
+            let (index, alias) = (index as u32, get_alias(query));
+            match self.remove(query) {
+                Ok(()) => results.push(BatchItemResult::success(index, alias, None)),
+                Err(e) if e.code == ErrCode::NotFound => results.push(BatchItemResult::failure(
+                    index, alias, e.code, "The data to remove is not found.".to_string())),
+                Err(e) => results.push(BatchItemResult::failure(index, alias, e.code, e.msg)),
+            }
+        }
+        Ok(results)
+    }
+
+    /// Update batch assets, the error code and the index of each failed item are returned.
+    pub fn batch_update(
+        &mut self,
+        attributes_array: &[AssetMap],
+        attributes_to_update_array: &[AssetMap]
+    ) -> Result<Vec<(u32, u32)>> {
+        let results = self.batch_update_with_results(attributes_array, attributes_to_update_array)?;
+        Ok(BatchItemResult::into_err_info(&results))
+    }
+
+    /// Update batch assets, the result of each item is returned in the order of the items.
+    pub fn batch_update_with_results(
+        &mut self,
+        attributes_array: &[AssetMap],
+        attributes_to_update_array: &[AssetMap]
+    ) -> Result<Vec<BatchItemResult>> {
+        if attributes_array.is_empty() || attributes_array.len() != attributes_to_update_array.len() {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+                "[FATAL][MOCK]The array sizes [{}] and [{}] are invalid.",
+                attributes_array.len(), attributes_to_update_array.len());
+        }
+        check_batch_consistency(attributes_array)?;
+        let mut results = Vec::with_capacity(attributes_array.len());
+        for (index, (query, update)) in attributes_array.iter().zip(attributes_to_update_array).enumerate() {
+            let (index, alias) = (index as u32, get_alias(query));
+            match self.update_inner(query, update) {
+                Ok(id) => results.push(BatchItemResult::success(index, alias, Some(id))),
+                Err(e) if e.code == ErrCode::NotFound => results.push(BatchItemResult::failure(
+                    index, alias, e.code, "The data to update is not found.".to_string())),
+                Err(e) => results.push(BatchItemResult::failure(index, alias, e.code, e.msg)),
+            }
+        }
+        Ok(results)
+    }
+
+    /// Update an Asset that matches a search query.
+    pub fn update(&mut self, query: &AssetMap, attributes_to_update: &AssetMap) -> Result<()> {
+        self.update_inner(query, attributes_to_update).map(|_| ())
+    }
+
+    /// Rename an Asset that matches a search query, the Asset requiring user authentication is renamed only if the
+    /// query carries a valid challenge and token.
+    pub fn rename(&mut self, query: &AssetMap, new_alias: &[u8]) -> Result<()> {
+        check_required_tags(query, &[Tag::Alias])?;
+        check_tag_validity(query, &[&CRITICAL_LABEL_ATTRS, &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS,
+            &CUSTOM_LABEL_ATTRS, &ACCESS_CONTROL_ATTRS, &[Tag::AuthChallenge, Tag::AuthToken]])?;
+        check_value_validity(query)?;
+        check_array_size(&Tag::Alias, new_alias, 1, MAX_ALIAS_SIZE)?;
+
+        let Some(pos) = self.assets.iter().position(|asset| !asset.is_expired() && asset.is_matched(query)) else {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL][MOCK]The asset to rename is not found.");
+        };
+        let mut condition: AssetMap = SCOPE_ATTRS.iter()
+            .filter_map(|tag| self.assets[pos].attributes.get(tag).map(|value| (*tag, value.clone())))
+            .collect();
+        condition.insert_attr(Tag::Alias, new_alias.to_vec());
+        if self.assets.iter().any(|asset| asset.is_matched(&condition)) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::Duplicated, "[FATAL][MOCK]The new alias already exists.");
+        }
+        if is_auth_required(&self.assets[pos].attributes) {
+            self.check_auth(query)?;
+        }
+
+        let attributes = &mut self.assets[pos].attributes;
+        let revision = get_number(attributes, Tag::Revision).unwrap_or(0);
+        attributes.insert_attr(Tag::Alias, new_alias.to_vec());
+        attributes.insert(Tag::UpdateTime, Value::Bytes(time_since_epoch().as_millis().to_string().into_bytes()));
+        attributes.insert(Tag::Revision, Value::Number(revision.wrapping_add(1)));
+        Ok(())
+    }
+
+    /// Move an Asset that matches a search query between the private space and the groups, or copy it if
+    /// `keep_source` is true. The target space is specified by the group id of the target attributes.
+    pub fn transfer(&mut self, query: &AssetMap, target: &AssetMap, keep_source: bool) -> Result<()> {
+        check_required_tags(query, &[Tag::Alias])?;
+        check_tag_validity(query, &[&CRITICAL_LABEL_ATTRS, &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS,
+            &CUSTOM_LABEL_ATTRS, &ACCESS_CONTROL_ATTRS, &[Tag::AuthChallenge, Tag::AuthToken]])?;
+        check_value_validity(query)?;
+        check_tag_validity(target, &[&[Tag::GroupId, Tag::ConflictResolution]])?;
+        check_value_validity(target)?;
+        if query.get(&Tag::GroupId) == target.get(&Tag::GroupId) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
+                "[FATAL][MOCK]The source and the target of the transfer are the same space.");
+        }
+
+        let Some(pos) = self.assets.iter().position(|asset| !asset.is_expired() && asset.is_matched(query)) else {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+                "[FATAL][MOCK]The asset to transfer is not found.");
+        };
+        if keep_source && self.assets[pos].attributes.contains_key(&Tag::MaxAccessCount) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::Unsupported, reason = ErrReason::LimitedAccessUnsupported,
+                "[FATAL][MOCK]The Asset with limited access count cannot be copied.");
+        }
+        if is_auth_required(&self.assets[pos].attributes) {
+            self.check_auth(query)?;
+        }
+
+        let mut stored = self.assets[pos].attributes.clone();
+        stored.remove(&Tag::GroupId);
+        if let Some(group_id) = target.get(&Tag::GroupId) {
+            stored.insert(Tag::GroupId, group_id.clone());
+        }
+        stored.insert(Tag::UpdateTime, Value::Bytes(time_since_epoch().as_millis().to_string().into_bytes()));
+        stored.insert(Tag::Revision, Value::Number(0));
+        let mut condition: AssetMap =
+            SCOPE_ATTRS.iter().filter_map(|tag| stored.get(tag).map(|value| (*tag, value.clone()))).collect();
+        condition.insert(Tag::Alias, stored[&Tag::Alias].clone());
+        let existing = self.assets.iter().position(|asset| asset.is_matched(&condition));
+        let is_overwrite = get_number(target, Tag::ConflictResolution) == Some(ConflictResolution::Overwrite as u32);
+        if existing.is_some() && !is_overwrite {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::Duplicated, "[FATAL][MOCK]The specified alias already exists in the target space.");
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let moved = MockAsset { id, attributes: stored };
+        match existing {
+            Some(existing) => self.assets[existing] = moved,
+            None => self.assets.push(moved),
+        }
+        if !keep_source {
+            self.assets.remove(pos);
+        }
+        Ok(())
+    }
+
+    /// Add, update and remove Assets of the same database in a single transaction, the store is restored if any of
+    /// the operations fails.
+    pub fn transact(&mut self, operations: &[TransactionOperation]) -> Result<Vec<BatchItemResult>> {
+        if operations.len() > MAX_BATCH_CHUNK_SIZE as usize {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+                "[FATAL][MOCK]The operation count[{}] exceeds the chunk size[{}].",
+                operations.len(), MAX_BATCH_CHUNK_SIZE);
+        }
+        let attributes_array: Vec<AssetMap> =
+            operations.iter().map(|operation| operation.attributes().clone()).collect();
+        check_batch_consistency(&attributes_array)?;
+
+        let (assets, next_id) = (self.assets.clone(), self.next_id);
+        let mut results = Vec::with_capacity(operations.len());
+        for (index, operation) in operations.iter().enumerate() {
+            let (index, alias) = (index as u32, get_alias(operation.attributes()));
+            let result = match operation {
+                TransactionOperation::Add(attributes) => self.add_inner(attributes).map(|_| ()),
+                TransactionOperation::Update(query, update) => self.update_inner(query, update).map(|_| ()),
+                TransactionOperation::Remove(query) => self.remove(query),
+            };
+            if let Err(e) = result {
+                (self.assets, self.next_id) = (assets, next_id);
+                return Ok(vec![BatchItemResult::failure(index, alias, e.code, e.msg)]);
+            }
+            results.push(BatchItemResult::success(index, alias, None));
+        }
+        Ok(results)
+    }
+
+    /// Preprocessing for querying one or more Assets that require user authentication.
+    pub fn pre_query(&mut self, query: &AssetMap) -> Result<Vec<u8>> {
+        check_tag_validity(query, &[&CRITICAL_LABEL_ATTRS, &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS,
+            &CUSTOM_LABEL_ATTRS, &ACCESS_CONTROL_ATTRS, &PRE_QUERY_OPTIONAL_ATTRS])?;
+        check_value_validity(query)?;
+        if get_number(query, Tag::AuthType) == Some(AuthType::None as u32) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::InvalidEnumVariant,
+                "[FATAL][MOCK]The auth type of pre_query cannot be None.");
+        }
+        if !self.visible_assets(query).any(|asset| is_auth_required(&asset.attributes)) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+                "[FATAL][MOCK]No data that meets the query conditions is found.");
+        }
+
+        self.challenges.retain(|challenge| challenge.expires_at > Instant::now());
+        if self.challenges.len() >= CRYPTO_CAPACITY {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::LimitExceeded, reason = ErrReason::ChallengeLimitExceeded,
+                "[FATAL][MOCK]The number of challenges exceeds the limit.");
+        }
+        let validity = get_number(query, Tag::AuthValidityPeriod).unwrap_or(DEFAULT_AUTH_VALIDITY_IN_SECS);
+        let challenge = random_bytes(CHALLENGE_SIZE);
+        let scope = SCOPE_ATTRS.iter().filter_map(|tag| query.get(tag).map(|value| (*tag, value.clone()))).collect();
+        self.challenges.push(MockChallenge {
+            challenge: challenge.clone(),
+            scope,
+            expires_at: Instant::now() + Duration::from_secs(validity as u64),
+        });
+        Ok(challenge)
+    }
+
+    /// Query one or more Assets that match a search query.
+    pub fn query(&mut self, query: &AssetMap) -> Result<Vec<AssetMap>> {
+        check_tag_validity(query, &[&CRITICAL_LABEL_ATTRS, &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS,
+            &CUSTOM_LABEL_ATTRS, &ACCESS_CONTROL_ATTRS, &ASSET_SYNC_ATTRS, &QUERY_OPTIONAL_ATTRS])?;
+        check_value_validity(query)?;
+        if query.contains_key(&Tag::ReturnOrderedBy) && query.contains_key(&Tag::ReturnOrderedByKeys) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
+                "[FATAL][MOCK]The tags [{}] and [{}] cannot be specified at the same time.",
+                Tag::ReturnOrderedBy, Tag::ReturnOrderedByKeys);
+        }
+        if query.contains_key(&Tag::ReturnOrder) && query.contains_key(&Tag::ReturnOrderedByKeys) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
+                "[FATAL][MOCK]The tag [{}] cannot be specified with [{}], which carries the direction of each key.",
+                Tag::ReturnOrder, Tag::ReturnOrderedByKeys);
+        }
+        if get_number(query, Tag::ReturnType) != Some(ReturnType::All as u32) {
+            return self.query_attrs(query);
+        }
+        if !query.contains_key(&Tag::Alias) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+                "[FATAL][MOCK]Batch secret query is not supported.");
+        }
+
+        let mut results: Vec<(u32, bool, AssetMap)> = self.visible_assets(query)
+            .map(|asset| (asset.id, is_auth_required(&asset.attributes), asset.to_asset_map(true)))
+            .collect();
+        match results.len() {
+            0 if self.is_access_exhausted(query) => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::AccessCountExhausted, "[FATAL][MOCK]The access count of the data to be queried is exhausted."),
+            0 => macros_lib::throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+                "[FATAL][MOCK]The data to be queried does not exist."),
+            1 => {
+                let (id, auth_required, mut result) = results.remove(0);
+                if auth_required {
+                    self.check_auth(query)?;
+                }
+                self.consume_access(id, &mut result);
+                Ok(vec![result])
+            },
+            n => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::DatabaseError, "[FATAL][MOCK]The store contains {} records with the specified alias.", n),
+        }
+    }
+
+    /// Query the secrets of batch Assets which do not require user authentication. Each query specifies an Asset by
+    /// its alias, or the Assets matching its labels if the alias is absent.
+    pub fn batch_query(&mut self, queries: &[AssetMap]) -> Result<Vec<BatchQueryItem>> {
+        if queries.is_empty() {
+            return Ok(vec![]);
+        }
+        check_batch_consistency(queries)?;
+        for query in queries {
+            check_tag_validity(query, &[&CRITICAL_LABEL_ATTRS, &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS,
+                &CUSTOM_LABEL_ATTRS, &BATCH_QUERY_ATTRS, &FILTER_ONLY_ATTRS])?;
+            check_value_validity(query)?;
+            if query.contains_key(&Tag::Alias) && FILTER_ONLY_ATTRS.iter().any(|tag| query.contains_key(tag)) {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::InvalidArgument, reason = ErrReason::IllegalTag,
+                    "[FATAL][MOCK]The tags [{}], [{}] and [{}] are only valid for the query without alias.",
+                    Tag::ReturnLimit, Tag::ReturnOffset, Tag::ReturnOrderedBy);
+            }
+        }
+
+        // The results are collected in advance, since reading the secret consumes the access count.
+        let matched: Vec<Vec<(u32, bool, AssetMap)>> = queries.iter()
+            .map(|query| self.matched_assets(query)
+                .into_iter()
+                .map(|asset| (asset.id, is_auth_required(&asset.attributes), asset.to_asset_map(true)))
+                .collect())
+            .collect();
+        let mut items = Vec::with_capacity(queries.len());
+        for (index, (query, assets)) in queries.iter().zip(matched).enumerate() {
+            // One item is reserved for each of the later queries, so that the reply never exceeds the chunk size.
+            let budget = (MAX_BATCH_CHUNK_SIZE as usize).saturating_sub(items.len() + queries.len() - index - 1);
+            let index = index as u32;
+            if assets.len() > budget {
+                let msg = format!("The [{}] Assets matched exceed the chunk size[{}] left for the query.",
+                    assets.len(), budget);
+                let result = BatchItemResult::failure(index, get_alias(query), ErrCode::LimitExceeded, msg);
+                items.push(BatchQueryItem { result, attributes: AssetMap::new() });
+                continue;
+            }
+            if assets.is_empty() && query.contains_key(&Tag::Alias) {
+                let (code, msg) = match self.is_access_exhausted(query) {
+                    true => (ErrCode::AccessCountExhausted, "The access count of the data to be queried is exhausted."),
+                    false => (ErrCode::NotFound, "The data to be queried does not exist."),
+                };
+                let result = BatchItemResult::failure(index, get_alias(query), code, msg.to_string());
+                items.push(BatchQueryItem { result, attributes: AssetMap::new() });
+            }
+            for (id, auth_required, mut attributes) in assets {
+                let alias = get_alias(&attributes);
+                let item = match auth_required {
+                    true => BatchQueryItem {
+                        result: BatchItemResult::failure(index, alias, ErrCode::Unsupported,
+                            "The Asset requiring user authentication cannot be queried in batch.".to_string()),
+                        attributes: AssetMap::new(),
+                    },
+                    false => {
+                        self.consume_access(id, &mut attributes);
+                        BatchQueryItem { result: BatchItemResult::success(index, alias, None), attributes }
+                    },
+                };
+                items.push(item);
+            }
+        }
+        Ok(items)
+    }
+
+    /// Query one page of the Assets that match a search query, the secrets are not returned.
+    pub fn query_page(&mut self, query: &AssetMap) -> Result<AssetPage> {
+        check_tag_validity(query, &[&CRITICAL_LABEL_ATTRS, &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS,
+            &CUSTOM_LABEL_ATTRS, &ACCESS_CONTROL_ATTRS, &ASSET_SYNC_ATTRS, &PAGE_QUERY_OPTIONAL_ATTRS])?;
+        check_value_validity(query)?;
+        if get_number(query, Tag::ReturnType) == Some(ReturnType::All as u32) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+                "[FATAL][MOCK]The secrets are not returned by the paged query.");
+        }
+        let page_size = get_number(query, Tag::ReturnLimit).unwrap_or(DEFAULT_PAGE_SIZE);
+        if page_size == 0 || page_size > MAX_PAGE_SIZE {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::NumberOutOfRange,
+                "[FATAL][MOCK]The page size[{}] is out of range (0, {}].", page_size, MAX_PAGE_SIZE);
+        }
+        // The token of the mock is simply the id of the last Asset in the previous page.
+        let last_id = match query.get(&Tag::PageToken) {
+            Some(Value::Bytes(token)) => match <[u8; 4]>::try_from(token.as_slice()) {
+                Ok(bytes) => u32::from_le_bytes(bytes),
+                Err(_) => return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+                    "[FATAL][MOCK]The page token is malformed."),
+            },
+            _ => 0,
+        };
+
+        let mut assets: Vec<&MockAsset> = self.visible_assets(query).filter(|asset| asset.id > last_id).collect();
+        let has_more = assets.len() > page_size as usize;
+        assets.truncate(page_size as usize);
+        let next_token = match assets.last() {
+            Some(asset) if has_more => Some(asset.id.to_le_bytes().to_vec()),
+            _ => None,
+        };
+        Ok(AssetPage { assets: assets.iter().map(|asset| asset.to_asset_map(false)).collect(), next_token })
+    }
+
+    /// Query the Assets that match a search query page by page, the pages are fetched lazily by the iterator.
+    pub fn query_pages(&mut self, query: &AssetMap) -> QueryPages<'_> {
+        QueryPages::new(self, query)
+    }
+
+    /// Post-processing for querying multiple Assets that require user authentication.
+    pub fn post_query(&mut self, query: &AssetMap) -> Result<()> {
+        check_required_tags(query, &[Tag::AuthChallenge])?;
+        check_tag_validity(query, &[&POST_QUERY_ATTRS])?;
+        check_value_validity(query)?;
+        self.challenges.retain(|challenge| !is_challenge_of(query, challenge));
+        Ok(())
+    }
+
+    /// Get the capabilities of the Asset service, which are the same as those of the latest service.
+    pub fn get_capabilities(&mut self) -> Result<Capabilities> {
+        Ok(Capabilities {
+            protocol_version: PROTOCOL_VERSION,
+            supported_tags: Tag::ALL.to_vec(),
+            enum_values: ENUM_TAG_VALUES.iter().map(|(tag, values)| (*tag, values.to_vec())).collect(),
+            max_secret_size: MAX_SECRET_SIZE as u32,
+            max_label_size: MAX_LABEL_SIZE as u32,
+            max_return_limit: MAX_RETURN_LIMIT,
+            features: Feature::Batch as u32 | Feature::Wrap as u32 | Feature::QueryPage as u32
+                | Feature::BatchQuery as u32 | Feature::Transaction as u32 | Feature::Rename as u32
+                | Feature::Transfer as u32 | Feature::Count as u32,
+        })
+    }
+
+    /// Query the result of synchronization, which is not supported by the mock.
+    pub fn query_sync_result(&mut self, _query: &AssetMap) -> Result<SyncResult> {
+        macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+            "[FATAL][MOCK]The synchronization is not supported by the mock.")
+    }
+
+    /// Count the Assets that match a search query.
+    pub fn count(&mut self, query: &AssetMap) -> Result<u32> {
+        check_tag_validity(query, &[&CRITICAL_LABEL_ATTRS, &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS,
+            &CUSTOM_LABEL_ATTRS, &ACCESS_CONTROL_ATTRS, &ASSET_SYNC_ATTRS, &[Tag::QueryFilter]])?;
+        check_value_validity(query)?;
+        Ok(self.visible_assets(query).count() as u32)
+    }
+
+    /// Check whether any Asset matches a search query.
+    pub fn exists(&mut self, query: &AssetMap) -> Result<bool> {
+        Ok(self.count(query)? > 0)
+    }
+
+    fn visible_assets<'a>(&'a self, query: &'a AssetMap) -> impl Iterator<Item = &'a MockAsset> {
+        self.assets.iter().filter(move |asset| !asset.is_expired() && asset.is_matched(query))
+    }
+
+    fn add_inner(&mut self, attributes: &AssetMap) -> Result<u32> {
+        check_required_tags(attributes, &ADD_REQUIRED_ATTRS)?;
+        check_tag_validity(attributes, &[&CRITICAL_LABEL_ATTRS, &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS,
+            &CUSTOM_LABEL_ATTRS, &ACCESS_CONTROL_ATTRS, &ASSET_SYNC_ATTRS, &ADD_OPTIONAL_ATTRS])?;
+        check_value_validity(attributes)?;
+
+        let mut stored = attributes.clone();
+        for tag in OPTION_ATTRS {
+            stored.remove(&tag);
+        }
+        stored.entry(Tag::Accessibility).or_insert(Value::Number(Accessibility::default() as u32));
+        stored.entry(Tag::AuthType).or_insert(Value::Number(AuthType::default() as u32));
+        stored.entry(Tag::SyncType).or_insert(Value::Number(SyncType::default() as u32));
+        stored.entry(Tag::RequirePasswordSet).or_insert(Value::Bool(false));
+        stored.entry(Tag::IsPersistent).or_insert(Value::Bool(false));
+        stored.entry(Tag::WrapType).or_insert(Value::Number(WrapType::default() as u32));
+        let now = Value::Bytes(time_since_epoch().as_millis().to_string().into_bytes());
+        stored.insert(Tag::CreateTime, now.clone());
+        stored.insert(Tag::UpdateTime, now);
+        stored.insert(Tag::Revision, Value::Number(0));
+        // The maximum access count is kept as configured, while the remaining one is consumed by each read.
+        if let Some(max_access_count) = attributes.get(&Tag::MaxAccessCount) {
+            stored.insert(Tag::RemainingAccessCount, max_access_count.clone());
+        }
+
+        let condition = location_of(attributes);
+        let id = match self.assets.iter().position(|asset| asset.is_matched(&condition)) {
+            Some(pos) if get_number(attributes, Tag::ConflictResolution)
+                == Some(ConflictResolution::Overwrite as u32) => {
+                let revision = get_number(&self.assets[pos].attributes, Tag::Revision).unwrap_or(0);
+                stored.insert(Tag::Revision, Value::Number(revision.wrapping_add(1)));
+                self.assets[pos].attributes = stored;
+                self.assets[pos].id
+            },
+            Some(_) => return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::Duplicated, "[FATAL][MOCK]The specified alias already exists."),
+            None => {
+                let id = self.next_id;
+                self.next_id += 1;
+                self.assets.push(MockAsset { id, attributes: stored });
+                id
+            },
+        };
+        // The alias is available again, so the record of its exhausted access count is no longer valid.
+        self.exhausted.retain(|exhausted| *exhausted != condition);
+        Ok(id)
+    }
+
+    fn is_access_exhausted(&self, query: &AssetMap) -> bool {
+        query.contains_key(&Tag::Alias) && self.exhausted.contains(&location_of(query))
+    }
+
+    // Consume one access of the Asset whose secret is read, and set the remaining count in the result. The Asset is
+    // removed once its access count is exhausted, the same as the service.
+    fn consume_access(&mut self, id: u32, result: &mut AssetMap) {
+        let Some(pos) = self.assets.iter().position(|asset| asset.id == id) else {
+            return;
+        };
+        let Some(remaining) = get_number(&self.assets[pos].attributes, Tag::RemainingAccessCount) else {
+            return;
+        };
+        match remaining.saturating_sub(1) {
+            0 => {
+                let asset = self.assets.remove(pos);
+                self.exhausted.push(location_of(&asset.attributes));
+                result.remove(&Tag::RemainingAccessCount);
+            },
+            remaining => {
+                self.assets[pos].attributes.insert(Tag::RemainingAccessCount, Value::Number(remaining));
+                result.insert(Tag::RemainingAccessCount, Value::Number(remaining));
+            },
+        }
+    }
+
+    fn update_inner(&mut self, query: &AssetMap, update: &AssetMap) -> Result<u32> {
+        check_required_tags(query, &[Tag::Alias])?;
+        check_tag_validity(query, &[&CRITICAL_LABEL_ATTRS, &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS,
+            &CUSTOM_LABEL_ATTRS, &ACCESS_CONTROL_ATTRS, &[Tag::Revision]])?;
+        check_value_validity(query)?;
+        if update.is_empty() {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::MissingTag,
+                "[FATAL][MOCK]The attributes to update is empty.");
+        }
+        // The critical labels and the access control attributes cannot be updated.
+        check_tag_validity(update, &[&NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS, &NORMAL_CUSTOM_LABEL_ATTRS,
+            &ASSET_SYNC_ATTRS, &[Tag::Secret]])?;
+        check_value_validity(update)?;
+
+        // The expected revision is checked against the Asset found, rather than used to find the Asset.
+        let mut condition = query.clone();
+        let expected_revision = get_number(&condition, Tag::Revision);
+        condition.remove(&Tag::Revision);
+        let Some(asset) = self.assets.iter_mut().find(|asset| !asset.is_expired() && asset.is_matched(&condition))
+        else {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL][MOCK]The asset to update is not found.");
+        };
+        let revision = get_number(&asset.attributes, Tag::Revision).unwrap_or(0);
+        if let Some(expected) = expected_revision.filter(|expected| *expected != revision) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::RevisionConflict, reason = ErrReason::RevisionMismatch,
+                "[FATAL][MOCK]The expected revision[{}] mismatches the current revision[{}].", expected, revision);
+        }
+        for (tag, value) in update {
+            asset.attributes.insert(*tag, value.clone());
+        }
+        asset.attributes.insert(Tag::UpdateTime, Value::Bytes(time_since_epoch().as_millis().to_string().into_bytes()));
+        asset.attributes.insert(Tag::Revision, Value::Number(revision.wrapping_add(1)));
+        Ok(asset.id)
+    }
+
+    // The Assets matching the query, which are ordered, skipped and limited as the query specifies.
+    fn matched_assets<'a>(&'a self, query: &'a AssetMap) -> Vec<&'a MockAsset> {
+        let mut assets: Vec<&MockAsset> = self.visible_assets(query).collect();
+        let keys = match (get_number(query, Tag::ReturnOrderedBy), query.get(&Tag::ReturnOrderedByKeys)) {
+            (Some(tag), _) => {
+                let order = get_number(query, Tag::ReturnOrder).and_then(|order| ReturnOrder::try_from(order).ok());
+                Tag::try_from(tag).map(|tag| vec![(tag, order.unwrap_or_default())]).unwrap_or_default()
+            },
+            (_, Some(Value::Bytes(bytes))) => OrderKey::decode_list(bytes).unwrap_or_default(),
+            _ => vec![],
+        };
+        assets.sort_by(|a, b| {
+            let ordering = keys.iter().map(|(tag, order)| match order {
+                ReturnOrder::Ascending => compare_by_tag(a, b, *tag),
+                ReturnOrder::Descending => compare_by_tag(a, b, *tag).reverse(),
+            }).find(|ordering| ordering.is_ne());
+            ordering.unwrap_or(Ordering::Equal)
+        });
+        let offset = get_number(query, Tag::ReturnOffset).unwrap_or(0) as usize;
+        let limit = get_number(query, Tag::ReturnLimit).unwrap_or(MAX_RETURN_LIMIT) as usize;
+        assets.into_iter().skip(offset).take(limit).collect()
+    }
+
+    fn query_attrs(&self, query: &AssetMap) -> Result<Vec<AssetMap>> {
+        let assets: Vec<AssetMap> =
+            self.matched_assets(query).iter().map(|asset| asset.to_asset_map(false)).collect();
+        if assets.is_empty() {
+            return macros_lib::throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+                "[FATAL][MOCK]The data to be queried does not exist.");
+        }
+        Ok(assets)
+    }
+
+    fn check_auth(&mut self, query: &AssetMap) -> Result<()> {
+        check_required_tags(query, &[Tag::AuthChallenge, Tag::AuthToken])?;
+        self.challenges.retain(|challenge| challenge.expires_at > Instant::now());
+        let found = self.challenges.iter().any(|challenge| {
+            is_challenge_of(query, challenge)
+                && challenge.scope.iter().all(|(tag, value)| query.get(tag) == Some(value))
+        });
+        if !found {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::ChallengeNotFound,
+                "[FATAL][MOCK]The challenge is not found or has expired.");
+        }
+        Ok(())
+    }
+}
+
+fn check_batch_consistency(attributes_array: &[AssetMap]) -> Result<()> {
+    let Some(first) = attributes_array.first() else {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange, "[FATAL][MOCK]The array is empty.");
+    };
+    for (index, attributes) in attributes_array.iter().enumerate() {
+        for tag in [Tag::GroupId, Tag::RequireAttrEncrypted] {
+            if attributes.get(&tag) != first.get(&tag) {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::InconsistentAttribute,
+                    "[FATAL][MOCK]Tag {} at index {} has inconsistent value", tag, index);
+            }
+        }
+    }
+    Ok(())
+}
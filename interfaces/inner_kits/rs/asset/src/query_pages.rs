@@ -0,0 +1,71 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module defines the iterator over the pages of a paged query.
+
+use asset_definition::{AssetMap, AssetPage, Result, Tag, Value};
+
+#[cfg(feature = "AssetEmptyMode")]
+use crate::empty::Manager;
+#[cfg(feature = "AssetMockMode")]
+use crate::mock::Manager;
+#[cfg(not(any(feature = "AssetEmptyMode", feature = "AssetMockMode")))]
+use crate::full::Manager;
+
+/// Iterator over the pages of the Assets that match a search query.
+///
+/// Each page is fetched from the Asset service only when the iterator advances. The iteration ends after the last
+/// page or the first error.
+pub struct QueryPages<'a> {
+    manager: &'a mut Manager,
+    query: AssetMap,
+    finished: bool,
+}
+
+impl<'a> QueryPages<'a> {
+    pub(crate) fn new(manager: &'a mut Manager, query: &AssetMap) -> Self {
+        let mut query = query.clone();
+        query.remove(&Tag::PageToken);
+        Self { manager, query, finished: false }
+    }
+}
+
+impl Iterator for QueryPages<'_> {
+    type Item = Result<Vec<AssetMap>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // An empty page does not end the iteration as long as the service still returns the token of the next page.
+        while !self.finished {
+            match self.manager.query_page(&self.query) {
+                Ok(AssetPage { assets, next_token }) => {
+                    match next_token {
+                        Some(token) => {
+                            self.query.insert(Tag::PageToken, Value::Bytes(token));
+                        },
+                        None => self.finished = true,
+                    }
+                    if !assets.is_empty() {
+                        return Some(Ok(assets));
+                    }
+                },
+                Err(e) => {
+                    self.finished = true;
+                    return Some(Err(e));
+                },
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module defines the policy to retry the requests failed because the Asset service is unavailable.
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    sync::Arc,
+    time::Duration,
+};
+
+use asset_definition::{AssetError, AssetMap, ConflictResolution, ErrCode, Tag, Value};
+use asset_ipc::IpcCode;
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(2);
+const DEFAULT_MULTIPLIER: u32 = 2;
+
+/// The event reported before a failed request is retried.
+#[derive(Clone)]
+pub struct RetryEvent {
+    /// The code of the request to retry.
+    pub ipc_code: IpcCode,
+    /// The number of the attempt failed, which starts from 1.
+    pub attempt: u32,
+    /// The error of the attempt failed.
+    pub error: ErrCode,
+    /// The time to wait before the next attempt.
+    pub delay: Duration,
+}
+
+/// The listener which observes the retries.
+pub type RetryListener = Arc<dyn Fn(&RetryEvent) + Send + Sync>;
+
+/// The policy to retry the requests failed with [`ErrCode::ServiceUnavailable`] or [`ErrCode::IpcError`], e.g. while
+/// the service is being unloaded.
+///
+/// Only the requests which take the same effect when sent repeatedly are retried, which are the queries, the
+/// removals, and the additions with [`ConflictResolution::Overwrite`].
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// The max number of attempts, including the first one. No retry is made if it is not greater than 1.
+    pub max_attempts: u32,
+    /// The time to wait before the first retry.
+    pub initial_backoff: Duration,
+    /// The upper bound of the time to wait before a retry.
+    pub max_backoff: Duration,
+    /// The factor by which the time to wait grows after each retry.
+    pub multiplier: u32,
+    /// Whether to wait for a random time between the half and the whole of the backoff.
+    pub jitter: bool,
+    /// The listener notified before each retry.
+    pub listener: Option<RetryListener>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+            multiplier: DEFAULT_MULTIPLIER,
+            jitter: true,
+            listener: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create the policy which never retries.
+    pub fn never() -> Self {
+        Self { max_attempts: 1, ..Default::default() }
+    }
+
+    /// Set the listener notified before each retry.
+    pub fn on_retry(mut self, listener: impl Fn(&RetryEvent) + Send + Sync + 'static) -> Self {
+        self.listener = Some(Arc::new(listener));
+        self
+    }
+
+    /// Get the time to wait before retrying the failed attempt, None if the request should not be retried.
+    pub(crate) fn next_delay(&self, ipc_code: IpcCode, attempt: u32, error: &AssetError) -> Option<Duration> {
+        let retryable_error = match error.code {
+            ErrCode::ServiceUnavailable => true,
+            // The service of an earlier version rejects the unknown IPC code with IpcError.
+            ErrCode::IpcError => ipc_code != IpcCode::GetCapabilities,
+            _ => false,
+        };
+        if !retryable_error || attempt >= self.max_attempts {
+            return None;
+        }
+
+        let factor = self.multiplier.max(1).saturating_pow(attempt - 1);
+        let backoff = self.initial_backoff.saturating_mul(factor).min(self.max_backoff);
+        let delay = if self.jitter { backoff / 2 + random_duration(backoff / 2) } else { backoff };
+        if let Some(listener) = &self.listener {
+            listener(&RetryEvent { ipc_code, attempt, error: error.code, delay });
+        }
+        Some(delay)
+    }
+}
+
+/// Check whether the request takes the same effect when sent repeatedly.
+pub(crate) fn is_idempotent<'a>(ipc_code: IpcCode, mut maps: impl Iterator<Item = &'a AssetMap>) -> bool {
+    match ipc_code {
+        IpcCode::Query | IpcCode::PreQuery | IpcCode::QueryPage | IpcCode::QuerySyncResult | IpcCode::BatchQuery
+        | IpcCode::Count | IpcCode::Exists | IpcCode::GetCapabilities | IpcCode::Remove | IpcCode::BatchRemove => true,
+        IpcCode::Add | IpcCode::BatchAdd => maps.all(|map| {
+            matches!(map.get(&Tag::ConflictResolution),
+                Some(Value::Number(n)) if *n == ConflictResolution::Overwrite as u32)
+        }),
+        _ => false,
+    }
+}
+
+fn random_duration(max: Duration) -> Duration {
+    let nanos = max.as_nanos() as u64;
+    if nanos == 0 {
+        return Duration::ZERO;
+    }
+    // The hasher is seeded randomly each time, which is enough for spreading the retries.
+    Duration::from_nanos(RandomState::new().build_hasher().finish() % nanos)
+}
@@ -0,0 +1,84 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module defines the transport which carries the encoded requests of the Manager to the Asset service.
+
+use std::time::Instant;
+
+use asset_definition::{macros_lib, ErrCode, Result};
+use asset_ipc::{ipc_err_handle, IpcCode, SA_ID};
+use asset_log::logw;
+use ipc::{parcel::{MsgOption, MsgParcel}, remote::RemoteObj};
+use samgr::manage::SystemAbilityManager;
+
+extern "C" {
+    fn GetTimeOut(timeout: *mut i32) -> i32;
+}
+
+const LOAD_TIMEOUT_IN_SECONDS: i32 = 4;
+const SUCCESS: i32 = 0;
+
+/// The transport sends the encoded request to the Asset service and receives the encoded reply.
+///
+/// The reply starts with the result code written by the service, which is parsed by the Manager.
+pub trait Transport: Send {
+    /// Send the request identified by the IPC code, and return the reply of the service.
+    fn send_request(&mut self, ipc_code: IpcCode, data: &mut MsgParcel) -> Result<MsgParcel>;
+
+    /// Reconnect to the service after it is reported as unavailable.
+    fn reconnect(&mut self) -> Result<()>;
+}
+
+/// The transport to the Asset service loaded by the system ability manager.
+pub struct RemoteTransport {
+    remote: RemoteObj,
+}
+
+impl RemoteTransport {
+    /// Load the Asset service and connect to it.
+    pub fn connect() -> Result<Self> {
+        Ok(Self { remote: load_asset_service()? })
+    }
+}
+
+impl Transport for RemoteTransport {
+    fn send_request(&mut self, ipc_code: IpcCode, data: &mut MsgParcel) -> Result<MsgParcel> {
+        let mut option = MsgOption::new();
+        option.set_image();
+        self.remote.send_request_ext(ipc_code as u32, data, option).map_err(ipc_err_handle)
+    }
+
+    fn reconnect(&mut self) -> Result<()> {
+        self.remote = load_asset_service()?;
+        Ok(())
+    }
+}
+
+fn load_asset_service() -> Result<RemoteObj> {
+    let mut timeout: i32 = 0;
+    let ret = unsafe { GetTimeOut(&mut timeout as *mut i32) };
+    if ret != SUCCESS {
+        timeout = LOAD_TIMEOUT_IN_SECONDS;
+    }
+    let start_time = Instant::now();
+    match SystemAbilityManager::load_system_ability(SA_ID, timeout) {
+        Some(remote) => Ok(remote),
+        None => {
+            logw!("load_asset_service time:{}s", start_time.elapsed().as_secs_f64());
+            macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::ServiceUnavailable, "[FATAL][RUST SDK]get remote service failed")
+        },
+    }
+}
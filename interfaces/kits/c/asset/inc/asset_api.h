@@ -221,6 +221,46 @@ int32_t OH_Asset_PostQuery(const Asset_Attr *handle, uint32_t handleCnt);
  */
 int32_t OH_Asset_QuerySyncResult(const Asset_Attr *query, uint32_t queryCnt, Asset_SyncResult *syncResult);
 
+/**
+ * @brief Counts the assets matching the search criteria, the attributes of which are not returned.
+ *
+ * @param query Pointer to the search criteria.
+ * @param queryCnt Number of the search criteria.
+ * @param count Pointer to the number of the assets obtained.
+ * @return {@link ASSET_SUCCESS} 0 - The operation is successful.
+ *     {@link ASSET_SERVICE_UNAVAILABLE} 24000001 - The ASSET service is unavailable.
+ *     {@link ASSET_OUT_OF_MEMORY} 24000006 - Insufficient memory.
+ *     {@link ASSET_DATABASE_ERROR} 24000008 - The database operation failed.
+ *     {@link ASSET_IPC_ERROR} 24000010 - IPC failed.
+ *     {@link ASSET_BMS_ERROR} 24000011 - Calling the Bundle Manager service failed.
+ *     {@link ASSET_ACCOUNT_ERROR} 24000012 - Calling the OS Account service failed.
+ *     {@link ASSET_ACCESS_TOKEN_ERROR} 24000013 - Calling the Access Token service failed.
+ *     {@link ASSET_FILE_OPERATION_ERROR} 24000014 - The file operation failed.
+ *     {@link ASSET_PARAM_VERIFICATION_FAILED} 24000018 - Parameter verification failed.
+ * @since 26
+ */
+int32_t OH_Asset_Count(const Asset_Attr *query, uint32_t queryCnt, uint32_t *count);
+
+/**
+ * @brief Checks whether any asset matches the search criteria, the attributes of which are not returned.
+ *
+ * @param query Pointer to the search criteria.
+ * @param queryCnt Number of the search criteria.
+ * @param exists Pointer to the result obtained, which is true if any asset matches.
+ * @return {@link ASSET_SUCCESS} 0 - The operation is successful.
+ *     {@link ASSET_SERVICE_UNAVAILABLE} 24000001 - The ASSET service is unavailable.
+ *     {@link ASSET_OUT_OF_MEMORY} 24000006 - Insufficient memory.
+ *     {@link ASSET_DATABASE_ERROR} 24000008 - The database operation failed.
+ *     {@link ASSET_IPC_ERROR} 24000010 - IPC failed.
+ *     {@link ASSET_BMS_ERROR} 24000011 - Calling the Bundle Manager service failed.
+ *     {@link ASSET_ACCOUNT_ERROR} 24000012 - Calling the OS Account service failed.
+ *     {@link ASSET_ACCESS_TOKEN_ERROR} 24000013 - Calling the Access Token service failed.
+ *     {@link ASSET_FILE_OPERATION_ERROR} 24000014 - The file operation failed.
+ *     {@link ASSET_PARAM_VERIFICATION_FAILED} 24000018 - Parameter verification failed.
+ * @since 26
+ */
+int32_t OH_Asset_Exists(const Asset_Attr *query, uint32_t queryCnt, bool *exists);
+
 /**
  * @brief Parses the query result to obtain the specified attribute value.
  *
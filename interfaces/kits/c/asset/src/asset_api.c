@@ -54,6 +54,16 @@ int32_t OH_Asset_QuerySyncResult(const Asset_Attr *query, uint32_t queryCnt, Ass
     return AssetQuerySyncResult((const AssetAttr *)query, queryCnt, (AssetSyncResult *)syncResult);
 }
 
+int32_t OH_Asset_Count(const Asset_Attr *query, uint32_t queryCnt, uint32_t *count)
+{
+    return AssetCount((const AssetAttr *)query, queryCnt, count);
+}
+
+int32_t OH_Asset_Exists(const Asset_Attr *query, uint32_t queryCnt, bool *exists)
+{
+    return AssetExists((const AssetAttr *)query, queryCnt, exists);
+}
+
 Asset_Attr *OH_Asset_ParseAttr(const Asset_Result *result, Asset_Tag tag)
 {
     return (Asset_Attr *)AssetParseAttr((const AssetResult *)result, (AssetTag)tag);
@@ -81,6 +81,19 @@ impl CallingInfo {
         }
     }
 
+    /// Build identity of the same owner in the group of the same developer, or in its private space if the group id
+    /// is absent.
+    pub fn with_group_id(&self, group_id: Option<Vec<u8>>) -> Self {
+        let developer_id = self.developer_id().cloned().unwrap_or_default();
+        let group = group_id.map(|group_id| Group { developer_id, group_id });
+        let owner_type = match (&group, self.owner_type) {
+            (Some(_), OwnerType::Hap) => OwnerType::HapGroup,
+            (None, OwnerType::HapGroup) => OwnerType::Hap,
+            (_, owner_type) => owner_type,
+        };
+        Self::new(self.user_id, owner_type, self.owner_info.clone(), group)
+    }
+
     /// Get owner type of calling.
     pub fn owner_type(&self) -> u32 {
         self.owner_type as u32
@@ -17,7 +17,7 @@
 
 use std::os::raw::c_char;
 
-use asset_definition::{macros_lib, AssetError, ErrCode, Result};
+use asset_definition::{macros_lib, AssetError, ErrCode, ErrReason, Result};
 mod calling_info;
 mod counter;
 mod process_info;
@@ -111,11 +111,17 @@ pub fn transfer_error_code(err_code: ErrCode) -> AssetError {
     match err_code {
         ErrCode::AccessDenied => {
             AssetError::new(ErrCode::AccessDenied, "[FATAL]HUKS verify auth token failed".to_string(), macros_lib::hisysevent::function!())
+                .with_reason(ErrReason::AuthTokenVerifyFailed)
         },
         ErrCode::StatusMismatch => {
             AssetError::new(ErrCode::StatusMismatch, "[FATAL]Screen status does not match".to_string(), macros_lib::hisysevent::function!())
         },
         ErrCode::InvalidArgument => AssetError::new(ErrCode::InvalidArgument, "[FATAL]Invalid argument.".to_string(), macros_lib::hisysevent::function!()),
+        ErrCode::KeyInvalidated => AssetError::new(
+            ErrCode::KeyInvalidated,
+            "[FATAL]The key has been permanently invalidated.".to_string(),
+            macros_lib::hisysevent::function!(),
+        ),
         ErrCode::BmsError => AssetError::new(ErrCode::BmsError, "[FATAL]Get owner info from bms failed.".to_string(), macros_lib::hisysevent::function!()),
         ErrCode::AccessTokenError => {
             AssetError::new(ErrCode::AccessTokenError, "[FATAL]Get process info failed.".to_string(), macros_lib::hisysevent::function!())
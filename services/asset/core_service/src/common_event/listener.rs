@@ -54,6 +54,7 @@ use asset_plugin_interface::plugin_interface::{
     EventType, ExtDbMap, PARAM_NAME_APP_INDEX, PARAM_NAME_BUNDLE_NAME,
     PARAM_NAME_IS_HAP, PARAM_NAME_USER_ID, PARAM_NAME_OWNER_INFO
 };
+use asset_utils::time;
 
 use crate::data_size_mod::handle_data_size_upload;
 use crate::{sys_event::upload_fault_system_event, PackageInfoFfi, upgrade_operator, upgrade_ce};
@@ -356,6 +357,59 @@ pub(crate) extern "C" fn on_app_restore(user_id: i32, bundle_name: *const u8, ap
     }
 }
 
+fn delete_expired_data_in_dir(
+    user_id: i32,
+    dir: &str,
+    db_key: &Option<Vec<u8>>,
+    expire_time: u32,
+    update_time: &Value,
+) -> Result<bool> {
+    let mut need_sync = false;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if let Some(db_name) = file_name.strip_suffix(DB_SUFFIX) {
+            let mut db = Database::build_with_file_name(user_id, db_name, db_key)?;
+            let (delete_num, mark_num) = db.delete_expired_datas(expire_time, update_time)?;
+            if delete_num > 0 || mark_num > 0 {
+                logi!("Delete expired data in [{}], delete num: {}, sync delete num: {}.", db_name, delete_num,
+                    mark_num);
+            }
+            need_sync |= mark_num > 0;
+        }
+    }
+    Ok(need_sync)
+}
+
+/// Delete the expired data of the user, and return whether the deletion needs to be synchronized to the cloud.
+fn delete_expired_data(user_id: i32) -> Result<bool> {
+    let expire_time = u32::try_from(time::system_time_in_seconds()?).unwrap_or(u32::MAX);
+    let update_time = Value::Bytes(time::system_time_in_millis()?);
+    let de_dir = format!("{}/{}", DE_ROOT_PATH, user_id);
+    let mut need_sync = delete_expired_data_in_dir(user_id, &de_dir, &None, expire_time, &update_time)?;
+    if user_id < MINIMUM_MAIN_USER_ID {
+        return Ok(need_sync);
+    }
+
+    let ce_dir = format!("{}/{}/{}", CE_ROOT_PATH, user_id, ASSET_SERVICE);
+    if !Path::new(&ce_dir).exists() {
+        return Ok(need_sync);
+    }
+    let db_key = get_db_key(user_id, true)?;
+    need_sync |= delete_expired_data_in_dir(user_id, &ce_dir, &db_key, expire_time, &update_time)?;
+    Ok(need_sync)
+}
+
+fn delete_expired_data_with_user_id(user_id: i32) -> bool {
+    match delete_expired_data(user_id) {
+        Ok(need_sync) => need_sync,
+        Err(e) => {
+            loge!("Delete expired data of user [{}] failed, err is: {}", user_id, e);
+            false
+        },
+    }
+}
+
 pub(crate) extern "C" fn on_user_unlocked(user_id: i32) {
     logi!("On user -{}- unlocked.", user_id);
 
@@ -391,6 +445,10 @@ pub(crate) extern "C" fn on_user_unlocked(user_id: i32) {
     let _ = upgrade_operator::upgrade_clone_app_data(user_id);
     let _ = upgrade_ce::upgrade_ce_data(user_id, "user_unlocked");
     upgrade_ce::summary_upgrade_data_count(user_id);
+
+    if delete_expired_data_with_user_id(user_id) {
+        trigger_sync_with_user_id(user_id, false);
+    }
 }
 
 pub(crate) fn notify_on_user_removed(user_id: i32) {
@@ -413,6 +471,9 @@ pub(crate) extern "C" fn on_user_switched(user_id: i32) {
 
 pub(crate) extern "C" fn on_schedule_wakeup() {
     logi!("On SA wakes up at a scheduled time(36H).");
+    for user_id in get_first_unlock_userids() {
+        delete_expired_data_with_user_id(user_id);
+    }
     trigger_sync();
 }
 
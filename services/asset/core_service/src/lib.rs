@@ -36,7 +36,10 @@ use asset_common::{AutoCounter, CallingInfo, ConstAssetBlob, ConstAssetBlobArray
 };
 use asset_crypto_manager::{crypto_manager::CryptoManager, db_key_operator::get_db_key};
 use asset_db_operator::{database_file_upgrade::check_and_split_db, database::{preload_db, clear_db_map}};
-use asset_definition::{macros_lib, AssetMap, ErrCode, Result, SyncResult};
+use asset_definition::{
+    macros_lib, AssetMap, AssetPage, AssetWithSecret, BatchItemResult, Capabilities, ErrCode, Result, SyncResult,
+    TransactionOperation,
+};
 use asset_file_operator::{common::DE_ROOT_PATH, de_operator::create_user_de_dir};
 use asset_ipc::{SA_ID, deserialize};
 use asset_log::{loge, logi};
@@ -44,6 +47,8 @@ use asset_plugin::asset_plugin::{AssetContext, AssetPlugin};
 
 mod common_event;
 mod data_size_mod;
+#[cfg(feature = "AssetTest")]
+pub mod loopback;
 mod operations;
 mod stub;
 mod sys_event;
@@ -506,7 +511,8 @@ pub(crate) fn get_ce_upgrade_info() -> &'static [u8] {
 }
 
 struct AssetService {
-    system_ability: system_ability_fwk::ability::Handler,
+    // None if the service is not published as a system ability, e.g. it is called through the loopback transport.
+    system_ability: Option<system_ability_fwk::ability::Handler>,
 }
 
 macro_rules! execute {
@@ -541,6 +547,22 @@ macro_rules! execute {
                 $func($calling_info, $first_arg, $second_arg), $calling_info, start, func_name, $first_arg)
         }
     }};
+    ($func:path, $calling_info:expr, $first_arg:expr, $($arg:expr),+) => {{
+        let func_name = macros_lib::hisysevent::function!();
+        let start = Instant::now();
+        let _trace = TraceScope::trace(func_name);
+        // Create de database directory if not exists.
+        create_user_de_dir($calling_info.user_id())?;
+        let ce_upgrade_info = get_ce_upgrade_info();
+        if ce_upgrade_info == $calling_info.owner_info() {
+            let _rwlock = UPGRADE_CE_MUTEX.read().unwrap();
+            upload_system_event(
+                $func($calling_info, $first_arg, $($arg),+), $calling_info, start, func_name, $first_arg)
+        } else {
+            upload_system_event(
+                $func($calling_info, $first_arg, $($arg),+), $calling_info, start, func_name, $first_arg)
+        }
+    }};
 }
 
 macro_rules! execute_batch {
@@ -579,10 +601,15 @@ macro_rules! execute_batch {
 
 impl AssetService {
     pub(crate) fn new(handler: system_ability_fwk::ability::Handler) -> Self {
-        Self { system_ability: handler }
+        Self { system_ability: Some(handler) }
+    }
+
+    #[cfg(feature = "AssetTest")]
+    pub(crate) fn new_local() -> Self {
+        Self { system_ability: None }
     }
 
-    fn add(&self, calling_info: &CallingInfo, attributes: &AssetMap) -> Result<()> {
+    fn add(&self, calling_info: &CallingInfo, attributes: &mut AssetMap) -> Result<()> {
         execute!(operations::add, calling_info, attributes)
     }
 
@@ -590,18 +617,37 @@ impl AssetService {
         execute!(operations::remove, calling_info, query)
     }
 
-    fn update(&self, calling_info: &CallingInfo, query: &AssetMap, attributes_to_update: &AssetMap) -> Result<()> {
+    fn update(&self, calling_info: &CallingInfo, query: &AssetMap, attributes_to_update: &mut AssetMap) -> Result<()> {
         execute!(operations::update, calling_info, query, attributes_to_update)
     }
 
+    fn rename(&self, calling_info: &CallingInfo, query: &AssetMap, attributes_to_update: &AssetMap) -> Result<()> {
+        execute!(operations::rename, calling_info, query, attributes_to_update)
+    }
+
+    fn transfer(
+        &self,
+        calling_info: &CallingInfo,
+        query: &AssetMap,
+        target_calling_info: &CallingInfo,
+        target: &AssetMap,
+        keep_source: bool,
+    ) -> Result<()> {
+        execute!(operations::transfer, calling_info, query, target_calling_info, target, keep_source)
+    }
+
     fn pre_query(&self, calling_info: &CallingInfo, query: &AssetMap) -> Result<Vec<u8>> {
         execute!(operations::pre_query, calling_info, query)
     }
 
-    fn query(&self, calling_info: &CallingInfo, query: &AssetMap) -> Result<Vec<AssetMap>> {
+    fn query(&self, calling_info: &CallingInfo, query: &AssetMap) -> Result<Vec<AssetWithSecret>> {
         execute!(operations::query, calling_info, query)
     }
 
+    fn query_page(&self, calling_info: &CallingInfo, query: &AssetMap) -> Result<AssetPage> {
+        execute!(operations::query_page, calling_info, query)
+    }
+
     fn post_query(&self, calling_info: &CallingInfo, query: &AssetMap) -> Result<()> {
         execute!(operations::post_query, calling_info, query)
     }
@@ -610,21 +656,49 @@ impl AssetService {
         execute!(operations::query_sync_result, calling_info, query)
     }
 
-    fn batch_add(&self, calling_info: &CallingInfo, attributes_array: &[AssetMap]) -> Result<Vec<(u32, u32)>> {
+    fn count(&self, calling_info: &CallingInfo, query: &AssetMap) -> Result<u32> {
+        execute!(operations::count, calling_info, query)
+    }
+
+    fn exists(&self, calling_info: &CallingInfo, query: &AssetMap) -> Result<bool> {
+        execute!(operations::exists, calling_info, query)
+    }
+
+    fn get_capabilities(&self) -> Capabilities {
+        operations::get_capabilities()
+    }
+
+    fn batch_add(&self, calling_info: &CallingInfo, attributes_array: &mut [AssetMap]) -> Result<Vec<BatchItemResult>> {
         execute_batch!(operations::batch_add, calling_info, attributes_array)
     }
 
-    fn batch_remove(&self, calling_info: &CallingInfo, attributes_array: &[AssetMap]) -> Result<()> {
+    fn batch_remove(&self, calling_info: &CallingInfo, attributes_array: &[AssetMap]) -> Result<Vec<BatchItemResult>> {
         execute_batch!(operations::batch_remove, calling_info, attributes_array)
     }
 
+    fn batch_query(
+        &self,
+        calling_info: &CallingInfo,
+        queries: &[AssetMap],
+    ) -> Result<Vec<(BatchItemResult, AssetWithSecret)>> {
+        execute_batch!(operations::batch_query, calling_info, queries)
+    }
+
     fn batch_update(
         &self, calling_info: &CallingInfo,
         attributes_array: &[AssetMap],
-        attributes_to_update_array: &[AssetMap]
-    ) -> Result<Vec<(u32, u32)>> {
+        attributes_to_update_array: &mut [AssetMap]
+    ) -> Result<Vec<BatchItemResult>> {
         execute_batch!(operations::batch_update, calling_info, attributes_array, attributes_to_update_array)
     }
+
+    fn transact(
+        &self,
+        calling_info: &CallingInfo,
+        operations: &mut [TransactionOperation],
+    ) -> Result<Vec<BatchItemResult>> {
+        execute_batch!(operations::transact, calling_info, operations)
+    }
 }
 
 #[cfg(feature = "AssetTest")]
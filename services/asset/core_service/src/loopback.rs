@@ -0,0 +1,50 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module implements the loopback transport, which dispatches the requests of the SDK Manager to the stub of
+//! the Asset service in the same process.
+
+use asset_common::CallingInfo;
+use asset_definition::Result;
+use asset_ipc::{ipc_err_handle, IpcCode};
+use asset_sdk::Transport;
+use ipc::parcel::MsgParcel;
+
+use crate::{stub::on_remote_request, AssetService};
+
+/// The transport which calls the stub of the Asset service directly with the injected calling info.
+pub struct LoopbackTransport {
+    calling_info: CallingInfo,
+}
+
+impl LoopbackTransport {
+    /// Create a loopback transport, the requests sent through which are processed on behalf of the calling info.
+    pub fn new(calling_info: CallingInfo) -> Self {
+        Self { calling_info }
+    }
+}
+
+impl Transport for LoopbackTransport {
+    fn send_request(&mut self, ipc_code: IpcCode, data: &mut MsgParcel) -> Result<MsgParcel> {
+        let mut reply = MsgParcel::new();
+        on_remote_request(&AssetService::new_local(), ipc_code as u32, data, &mut reply, Some(&self.calling_info))
+            .map_err(ipc_err_handle)?;
+        Ok(reply)
+    }
+
+    fn reconnect(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
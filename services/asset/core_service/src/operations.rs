@@ -22,26 +22,40 @@ mod operation_add;
 #[cfg(feature = "AssetTest")]
 pub mod operation_add;
 
+mod operation_get_capabilities;
 mod operation_post_query;
 mod operation_pre_query;
 mod operation_query;
+mod operation_query_page;
 mod operation_query_sync_result;
 mod operation_remove;
+mod operation_rename;
+mod operation_transfer;
 mod operation_update;
 mod operation_batch_add;
+mod operation_batch_query;
 mod operation_batch_remove;
 mod operation_batch_update;
+mod operation_transact;
+mod operation_count;
 
 pub(crate) use operation_add::add;
+pub(crate) use operation_get_capabilities::get_capabilities;
 pub(crate) use operation_post_query::post_query;
 pub(crate) use operation_pre_query::pre_query;
 pub(crate) use operation_query::query;
+pub(crate) use operation_query_page::query_page;
 pub(crate) use operation_query_sync_result::query_sync_result;
 pub(crate) use operation_remove::remove;
+pub(crate) use operation_rename::rename;
+pub(crate) use operation_transfer::transfer;
 pub(crate) use operation_update::update;
 pub(crate) use operation_batch_add::batch_add;
+pub(crate) use operation_batch_query::batch_query;
 pub(crate) use operation_batch_remove::batch_remove;
 pub(crate) use operation_batch_update::batch_update;
+pub(crate) use operation_transact::transact;
+pub(crate) use operation_count::{count, exists};
 
 #[cfg(feature = "AssetTest")]
 pub use operation_add::ut_operation_add_stub;
@@ -16,7 +16,7 @@
 //! This module is used to provide common capabilities for the Asset operations.
 
 use asset_common::{CallingInfo, OWNER_INFO_SEPARATOR, OwnerType, TaskManager};
-use asset_definition::{macros_lib, AssetMap, OperationType, Tag, Value, ErrCode, Result, SyncType};
+use asset_definition::{macros_lib, AssetMap, OperationType, Tag, Value, ErrCode, ErrReason, Result, SyncType};
 use asset_log::{loge, logi};
 use asset_db_operator::types::{DbMap, column};
 use asset_plugin::asset_plugin::AssetPlugin;
@@ -80,18 +80,18 @@ pub(crate) fn check_group_validity(attrs: &AssetMap, calling_info: &CallingInfo)
             params.insert(PARAM_NAME_USER_ID, Value::Number(calling_info.user_id() as u32));
             if load.process_event(EventType::IsPermissionEnabled, &mut params).is_err() {
                 return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                    ErrCode::InvalidArgument,
+                    ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
                     "[FATAL]The value of the tag [{}] cannot be set to true when the tag [{}] is specified.", &Tag::IsPersistent, &Tag::GroupId);
             }
         }
         if calling_info.owner_type_enum() == OwnerType::Native {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::Unsupported,
+                ErrCode::Unsupported, reason = ErrReason::GroupUnsupportedForNative,
                 "[FATAL]The tag [{}] is not yet supported for [{}] owner.", &Tag::GroupId, OwnerType::Native);
         }
         if calling_info.app_index() > 0 {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::Unsupported,
+                ErrCode::Unsupported, reason = ErrReason::GroupUnsupportedForCloneApp,
                 "[FATAL]The tag [{}] is not yet supported for clone or sandbox app.", &Tag::GroupId);
         }
     }
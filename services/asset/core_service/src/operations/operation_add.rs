@@ -21,7 +21,7 @@ use asset_sdk::WrapType;
 use asset_common::CallingInfo;
 use asset_definition::{
     macros_lib, Accessibility, AssetMap, AuthType, ConflictResolution, ErrCode,
-    Extension, LocalStatus, Result, SyncStatus, SyncType, Tag, Value,
+    Extension, LocalStatus, Result, SecretBytes, SyncStatus, SyncType, Tag, Value,
 };
 use asset_crypto_manager::{
     crypto::Crypto,
@@ -39,29 +39,39 @@ extern "C" {
     fn CheckSystemHapPermission() -> bool;
 }
 
-fn encrypt_secret(calling_info: &CallingInfo, db_data: &mut DbMap) -> Result<()> {
+pub(crate) fn encrypt_secret(calling_info: &CallingInfo, db_data: &mut DbMap, secret: &SecretBytes) -> Result<()> {
     let secret_key = common::build_secret_key(calling_info, db_data)?;
     generate_secret_key_if_needed(&secret_key).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
 
-    let secret = db_data.get_bytes_attr(&column::SECRET).map_err(|e| macros_lib::track_error!(e,
-        macros_lib::hisysevent::function!()))?;
     let cipher = Crypto::encrypt(&secret_key, secret, &common::build_aad(db_data)?)
         .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
     db_data.insert(column::SECRET, Value::Bytes(cipher));
     Ok(())
 }
 
+/// Continue the revision of the Asset to be replaced, so that a caller holding its revision cannot update the new one.
+pub(crate) fn continue_revision(db: &mut Database, condition: &DbMap, db_data: &mut DbMap) -> Result<()> {
+    let rows = db.query_datas(&vec![column::REVISION], condition, None, false).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    if let Some(row) = rows.iter().max_by_key(|row| common::get_revision(row)) {
+        common::add_next_revision(row, db_data);
+    }
+    Ok(())
+}
+
 fn resolve_conflict(
     calling: &CallingInfo,
     db: &mut Database,
     attrs: &AssetMap,
     query: &DbMap,
     db_data: &mut DbMap,
+    secret: &SecretBytes,
 ) -> Result<()> {
     match attrs.get(&Tag::ConflictResolution) {
         Some(Value::Number(num)) if *num == ConflictResolution::Overwrite as u32 => {
-            encrypt_secret(calling, db_data).map_err(|e| macros_lib::track_error!(e,
+            continue_revision(db, query, db_data)?;
+            encrypt_secret(calling, db_data, secret).map_err(|e| macros_lib::track_error!(e,
                 macros_lib::hisysevent::function!()))?;
             db.replace_datas(query, false, db_data).map_err(|e| macros_lib::track_error!(e,
                 macros_lib::hisysevent::function!()))
@@ -72,7 +82,8 @@ fn resolve_conflict(
             condition.insert(column::SYNC_STATUS, Value::Number(SyncStatus::SyncDel as u32));
             if db.is_data_exists(&condition, false).map_err(|e| macros_lib::track_error!(e,
                 macros_lib::hisysevent::function!()))? {
-                encrypt_secret(calling, db_data).map_err(|e| macros_lib::track_error!(e,
+                continue_revision(db, &condition, db_data)?;
+                encrypt_secret(calling, db_data, secret).map_err(|e| macros_lib::track_error!(e,
                     macros_lib::hisysevent::function!()))?;
                 db.replace_datas(&condition, false, db_data).map_err(|e| macros_lib::track_error!(e,
                     macros_lib::hisysevent::function!()))
@@ -84,16 +95,21 @@ fn resolve_conflict(
     }
 }
 
-fn add_system_attrs(db_data: &mut DbMap) -> Result<()> {
+pub(crate) fn add_system_attrs(db_data: &mut DbMap) -> Result<()> {
     db_data.insert(column::VERSION, Value::Number(DB_DATA_VERSION));
+    db_data.insert(column::REVISION, Value::Number(0));
 
     let time = time::system_time_in_millis()?;
     db_data.insert(column::CREATE_TIME, Value::Bytes(time.clone()));
     db_data.insert(column::UPDATE_TIME, Value::Bytes(time));
+    // The maximum access count is kept as configured, while the remaining one is consumed by each read.
+    if let Some(max_access_count) = db_data.get(column::MAX_ACCESS_COUNT).cloned() {
+        db_data.insert(column::REMAINING_ACCESS_COUNT, max_access_count);
+    }
     Ok(())
 }
 
-fn add_default_attrs(db_data: &mut DbMap) {
+pub(crate) fn add_default_attrs(db_data: &mut DbMap) {
     db_data.entry(column::ACCESSIBILITY).or_insert(Value::Number(Accessibility::default() as u32));
     db_data.entry(column::AUTH_TYPE).or_insert(Value::Number(AuthType::default() as u32));
     db_data.entry(column::SYNC_TYPE).or_insert(Value::Number(SyncType::default() as u32));
@@ -104,12 +120,13 @@ fn add_default_attrs(db_data: &mut DbMap) {
     db_data.entry(column::WRAP_TYPE).or_insert(Value::Number(WrapType::default() as u32));
 }
 
-fn check_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
+pub(crate) fn check_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
     common::check_required_tags(attributes, &common::REQUIRED_ATTRS)?;
 
     let mut valid_tags = common::CRITICAL_LABEL_ATTRS.to_vec();
     valid_tags.extend_from_slice(&common::NORMAL_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::CUSTOM_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::ACCESS_CONTROL_ATTRS);
     valid_tags.extend_from_slice(&common::ASSET_SYNC_ATTRS);
     valid_tags.extend_from_slice(&common::OPTIONAL_ATTRS);
@@ -119,11 +136,13 @@ fn check_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<
     common::check_accessibility_validity(attributes, calling_info)?;
     common::check_sync_permission(attributes, calling_info)?;
     common::check_wrap_permission(attributes, calling_info)?;
+    common::check_expire_time_validity(attributes)?;
+    common::check_bio_policy_validity(attributes)?;
     common::check_system_permission(attributes)?;
     common::check_persistent_permission(attributes)
 }
 
-fn modify_sync_type(db: &mut DbMap) -> Result<()> {
+pub(crate) fn modify_sync_type(db: &mut DbMap) -> Result<()> {
     if db.get(&column::SYNC_TYPE).is_none()
         || (db.get_num_attr(&column::SYNC_TYPE)? & SyncType::TrustedAccount as u32) == 0
     {
@@ -139,10 +158,13 @@ fn modify_sync_type(db: &mut DbMap) -> Result<()> {
     Ok(())
 }
 
-fn local_add(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
+fn local_add(attributes: &mut AssetMap, calling_info: &CallingInfo) -> Result<()> {
     check_arguments(attributes, calling_info).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
 
+    // The secret is moved out rather than copied, and it is kept apart from the DbMap until it is encrypted.
+    let secret = attributes.take_secret_attr(&Tag::Secret).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
     // Fill all attributes to DbMap.
     let mut db_data = common::into_db_map(attributes);
     modify_sync_type(&mut db_data).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
@@ -157,20 +179,26 @@ fn local_add(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
     let mut db = Database::build(calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
 
+    // The expired Asset is purged rather than regarded as existing, so that its alias can be added again.
+    db.delete_expired_datas_by_condition(&query).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
     if db.is_data_exists(&query, false).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))? {
-        resolve_conflict(calling_info, &mut db, attributes, &query, &mut db_data)
+        resolve_conflict(calling_info, &mut db, attributes, &query, &mut db_data, &secret)
             .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
     } else {
-        encrypt_secret(calling_info, &mut db_data).map_err(|e| macros_lib::track_error!(e,
+        encrypt_secret(calling_info, &mut db_data, &secret).map_err(|e| macros_lib::track_error!(e,
             macros_lib::hisysevent::function!()))?;
         let _ = db.insert_datas(&db_data).map_err(|e| macros_lib::track_error!(e,
             macros_lib::hisysevent::function!()))?;
     }
+    // The alias is available again, so the record of its exhausted access count is no longer valid.
+    let _ = db.delete_access_exhausted_datas(&query).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
 
     Ok(())
 }
 
-pub(crate) fn add(calling_info: &CallingInfo, attributes: &AssetMap) -> Result<()> {
+pub(crate) fn add(calling_info: &CallingInfo, attributes: &mut AssetMap) -> Result<()> {
     let local_res = local_add(attributes, calling_info);
 
     if local_res.is_ok() {
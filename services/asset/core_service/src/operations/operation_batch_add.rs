@@ -23,7 +23,7 @@ use asset_db_operator::{
     types::{DB_DATA_VERSION, DbMap, column},
 };
 use asset_definition::{
-    AssetMap, ErrCode, Result, Tag, Value, macros_lib
+    AssetMap, BatchItemResult, ErrCode, ErrReason, Result, Tag, Value, macros_lib
 };
 use asset_utils::time;
 
@@ -44,12 +44,12 @@ fn add_system_attrs(db_data: &mut DbMap) -> Result<()> {
 
 fn local_batch_add(
     calling_info: &CallingInfo,
-    attributes_array: &[AssetMap]
-) -> Result<Vec<(u32, u32)>> {
+    attributes_array: &mut [AssetMap]
+) -> Result<Vec<BatchItemResult>> {
     let attributes = match attributes_array.first() {
         Some(attr) => attr,
         None => return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL]Batch Add argument empty."),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange, "[FATAL]Batch Add argument empty."),
     };
     common::check_value_validity(attributes)
         .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
@@ -65,9 +65,25 @@ fn local_batch_add(
         .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
     let mut db = Database::build(calling_info, db_key)
         .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
-    db.insert_batch_datas(&db_map, attributes_array, calling_info)
+    // The expired Assets of the caller are purged, so that they do not block the aliases added.
+    let mut scope = DbMap::new();
+    common::add_calling_info(calling_info, &mut scope);
+    db.delete_expired_datas_by_condition(&scope).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    let results = db.insert_batch_datas(&db_map, attributes_array, calling_info)
+        .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+
+    // The aliases added are available again, so the records of their exhausted access count are no longer valid.
+    let added_aliases: Vec<Vec<u8>> =
+        results.iter().filter(|item| item.code.is_none()).map(|item| item.alias.clone()).collect();
+    let _ = db.delete_access_exhausted_aliases(&db_map, &added_aliases).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    Ok(results)
 }
 
-pub(crate) fn batch_add(calling_info: &CallingInfo, attributes_array: &[AssetMap]) -> Result<Vec<(u32, u32)>> {
+pub(crate) fn batch_add(
+    calling_info: &CallingInfo,
+    attributes_array: &mut [AssetMap],
+) -> Result<Vec<BatchItemResult>> {
     local_batch_add(calling_info, attributes_array)
 }
@@ -0,0 +1,182 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module is used to query the secrets of batch Assets, which are specified by aliases or label filters.
+
+use asset_common::CallingInfo;
+use asset_crypto_manager::db_key_operator::get_db_key_by_asset_map;
+use asset_db_operator::{
+    common,
+    database::{get_db_by_user_id_db_name, Database},
+    database_file_upgrade::construct_splited_db_name,
+    types::{column, DbMap, QueryOptions},
+};
+use asset_definition::{
+    macros_lib, AssetError, AssetMap, AssetWithSecret, AuthType, BatchItemResult, ErrCode, ErrReason, Extension, Result,
+    Tag, Value,
+};
+use asset_ipc::MAX_BATCH_CHUNK_SIZE;
+
+use crate::operations::{
+    common::{check_group_validity, check_tags_consistency},
+    operation_query::{check_access_exhausted, get_query_options, read_asset_with_secret},
+};
+
+const OPTIONAL_ATTRS: [Tag; 6] =
+    [Tag::Alias, Tag::GroupId, Tag::RequireAttrEncrypted, Tag::ReturnLimit, Tag::ReturnOffset, Tag::ReturnOrderedBy];
+const CONSISTENCY_ATTRS: [Tag; 2] = [Tag::RequireAttrEncrypted, Tag::GroupId];
+// The query without alias is a label filter, which can match more than one Asset.
+const FILTER_ONLY_ATTRS: [Tag; 3] = [Tag::ReturnLimit, Tag::ReturnOffset, Tag::ReturnOrderedBy];
+
+fn check_arguments(calling_info: &CallingInfo, queries: &[AssetMap]) -> Result<()> {
+    check_tags_consistency(&CONSISTENCY_ATTRS, queries)?;
+    let mut valid_tags = common::CRITICAL_LABEL_ATTRS.to_vec();
+    valid_tags.extend_from_slice(&common::NORMAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::CUSTOM_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&OPTIONAL_ATTRS);
+    for query in queries {
+        common::check_tag_validity(query, &valid_tags)?;
+        common::check_value_validity(query)?;
+        if query.contains_key(&Tag::Alias) && FILTER_ONLY_ATTRS.iter().any(|tag| query.contains_key(tag)) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::IllegalTag,
+                "[FATAL]The tags [{}], [{}] and [{}] are only valid for the query without alias.",
+                Tag::ReturnLimit, Tag::ReturnOffset, Tag::ReturnOrderedBy);
+        }
+    }
+    check_group_validity(&queries[0], calling_info)?;
+    common::check_system_permission(&queries[0]).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))
+}
+
+fn get_alias(db_data: &DbMap) -> Vec<u8> {
+    match db_data.get(column::ALIAS) {
+        Some(Value::Bytes(alias)) => alias.clone(),
+        _ => vec![],
+    }
+}
+
+fn into_failure(index: usize, alias: Vec<u8>, e: AssetError) -> (BatchItemResult, AssetWithSecret) {
+    (BatchItemResult::failure(index as u32, alias, e.code, e.msg), AssetWithSecret::from(AssetMap::new()))
+}
+
+fn query_rows(db: &mut Database, calling_info: &CallingInfo, query: &AssetMap) -> Result<Vec<DbMap>> {
+    let mut db_data = common::into_db_map(query);
+    if query.contains_key(&Tag::GroupId) {
+        common::add_group(calling_info, &mut db_data);
+    } else {
+        common::add_owner_info(calling_info, &mut db_data);
+    }
+
+    let query_options = match query.contains_key(&Tag::Alias) {
+        true => QueryOptions {
+            offset: None,
+            limit: None,
+            order_by: None,
+            amend: Some(common::build_unexpired_condition()?),
+        },
+        false => get_query_options(query)?,
+    };
+    let rows = db.query_datas(&vec![], &db_data, Some(&query_options), true)
+        .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+    if rows.is_empty() && query.contains_key(&Tag::Alias) {
+        check_access_exhausted(db, &db_data)?;
+        return macros_lib::throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL]The data to be queried does not exist.");
+    }
+    Ok(rows)
+}
+
+// The label filter matching more Assets than the budget is failed alone, rather than failing the whole batch.
+fn check_row_count(rows: Vec<DbMap>, budget: usize) -> Result<Vec<DbMap>> {
+    if rows.len() > budget {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::LimitExceeded, reason = ErrReason::ChunkSizeExceeded,
+            "[FATAL]The [{}] Assets matched exceed the chunk size[{}] left for the query.", rows.len(), budget);
+    }
+    Ok(rows)
+}
+
+fn read_row(db: &mut Database, calling_info: &CallingInfo, db_data: DbMap) -> Result<AssetWithSecret> {
+    if !matches!(db_data.get(column::AUTH_TYPE), Some(Value::Number(n)) if *n == AuthType::None as u32) {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::AuthAssetUnsupported,
+            "[FATAL]The Asset requiring user authentication cannot be queried in batch.");
+    }
+    read_asset_with_secret(db, calling_info, &AssetMap::new(), db_data)
+}
+
+fn batch_query_in_db(
+    db: &mut Database,
+    calling_info: &CallingInfo,
+    queries: &[AssetMap],
+) -> Result<Vec<(BatchItemResult, AssetWithSecret)>> {
+    // Query all the rows before reading any secret, so the items exceeding the chunk size consume no access count.
+    let mut matched = Vec::with_capacity(queries.len());
+    let mut row_count = 0;
+    for (index, query) in queries.iter().enumerate() {
+        // One item is reserved for each of the later queries, so that the reply never exceeds the chunk size.
+        let budget = (MAX_BATCH_CHUNK_SIZE as usize).saturating_sub(row_count + queries.len() - index - 1);
+        let rows = query_rows(db, calling_info, query).and_then(|rows| check_row_count(rows, budget));
+        row_count += rows.as_ref().map_or(1, |rows| rows.len());
+        matched.push(rows);
+    }
+
+    let mut items = Vec::with_capacity(row_count);
+    for (index, (query, rows)) in queries.iter().zip(matched).enumerate() {
+        let rows = match rows {
+            Ok(rows) => rows,
+            Err(e) => {
+                let alias = query.get_bytes_attr(&Tag::Alias).cloned().unwrap_or_default();
+                items.push(into_failure(index, alias, e));
+                continue;
+            },
+        };
+        for row in rows {
+            let alias = get_alias(&row);
+            match read_row(db, calling_info, row) {
+                Ok(asset) => items.push((BatchItemResult::success(index as u32, alias, None), asset)),
+                Err(e) => items.push(into_failure(index, alias, e)),
+            }
+        }
+    }
+    Ok(items)
+}
+
+pub(crate) fn batch_query(
+    calling_info: &CallingInfo,
+    queries: &[AssetMap],
+) -> Result<Vec<(BatchItemResult, AssetWithSecret)>> {
+    let first = match queries.first() {
+        Some(query) => query,
+        None => return Ok(vec![]),
+    };
+    check_arguments(calling_info, queries).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+
+    let db_key = get_db_key_by_asset_map(calling_info.user_id(), first).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    let db_name = construct_splited_db_name(calling_info, db_key.is_some()).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    match get_db_by_user_id_db_name(calling_info.user_id(), db_name) {
+        Some(db) => batch_query_in_db(db, calling_info, queries),
+        None => {
+            let mut db = Database::build(calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
+                macros_lib::hisysevent::function!()))?;
+            batch_query_in_db(&mut db, calling_info, queries)
+        },
+    }
+}
@@ -23,9 +23,8 @@ use asset_db_operator::{
     types::{DbMap, column},
 };
 use asset_definition::{
-    AssetMap, Result, SyncStatus, Tag, Value, macros_lib,
+    AssetMap, BatchItemResult, Result, SyncStatus, Tag, Value, macros_lib,
 };
-use asset_log::logi;
 use asset_sdk::Extension;
 use asset_utils::time;
 
@@ -49,10 +48,10 @@ fn check_and_get_aliases(attributes_array: &[AssetMap]) -> Result<Vec<Vec<u8>>>
     Ok(aliases)
 }
 
-fn local_batch_remove(attributes_array: &[AssetMap], calling_info: &CallingInfo) -> Result<()> {
+fn local_batch_remove(attributes_array: &[AssetMap], calling_info: &CallingInfo) -> Result<Vec<BatchItemResult>> {
     let attributes = match attributes_array.first() {
         Some(attr) => attr,
-        None => return Ok(()),
+        None => return Ok(vec![]),
     };
     let aliases = check_and_get_aliases(attributes_array).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
@@ -69,12 +68,15 @@ fn local_batch_remove(attributes_array: &[AssetMap], calling_info: &CallingInfo)
     update_datas.insert(column::UPDATE_TIME, Value::Bytes(time));
     update_datas.insert(column::SYNC_STATUS, Value::Number(SyncStatus::SyncDel as u32));
 
-    let total_removed_count: i32 = db.delete_batch_datas(&condition, &update_datas, &aliases)
+    let results = db.delete_batch_datas(&condition, &update_datas, &aliases)
         .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
-    logi!("total removed count = {}", total_removed_count);
-    Ok(())
+
+    // The records of the exhausted access count are removed with the aliases, which are then reported as not found.
+    let _ = db.delete_access_exhausted_aliases(&condition, &aliases).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    Ok(results)
 }
 
-pub(crate) fn batch_remove(calling_info: &CallingInfo, attributes_array: &[AssetMap]) -> Result<()> {
+pub(crate) fn batch_remove(calling_info: &CallingInfo, attributes_array: &[AssetMap]) -> Result<Vec<BatchItemResult>> {
     local_batch_remove(attributes_array, calling_info)
 }
@@ -25,13 +25,14 @@ use asset_db_operator::{
     database::Database, types::{DB_DATA_VERSION, DbMap, column},
 };
 use asset_definition::{
-    macros_lib, AssetMap, ErrCode, Result, Tag,
+    macros_lib, AssetMap, BatchItemResult, ErrCode, ErrReason, Result, Tag,
 };
 use asset_sdk::Value;
 
 use crate::operations::common::check_tags_consistency;
 
 const QUERY_VALID_ATTRS: [Tag; 1] = [Tag::Alias];
+const QUERY_OPTIONAL_ATTRS: [Tag; 1] = [Tag::Revision];
 const UPDATE_OPTIONAL_ATTRS: [Tag; 1] = [Tag::Secret];
 const CONSISTENCY_ATTRS: [Tag; 2] = [Tag::RequireAttrEncrypted, Tag::GroupId];
 
@@ -42,6 +43,7 @@ fn check_attrs_array(attributes_array: &[AssetMap]) -> Result<()> {
         valid_tags.extend_from_slice(&NORMAL_LABEL_ATTRS);
         valid_tags.extend_from_slice(&NORMAL_LOCAL_LABEL_ATTRS);
         valid_tags.extend_from_slice(&ACCESS_CONTROL_ATTRS);
+        valid_tags.extend_from_slice(&QUERY_OPTIONAL_ATTRS);
         check_tag_validity(attrs, &valid_tags)
             .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
         check_value_validity(attrs)?;
@@ -54,7 +56,8 @@ fn check_update_array(attributes_array: &[AssetMap]) -> Result<()> {
     for attrs in attributes_array {
         if attrs.is_empty() {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]The data to update contains empty attributes.");
+                ErrCode::InvalidArgument, reason = ErrReason::MissingTag,
+                "[FATAL]The data to update contains empty attributes.");
         }
         let mut valid_tags = NORMAL_LABEL_ATTRS.to_vec();
         valid_tags.extend_from_slice(&NORMAL_LOCAL_LABEL_ATTRS);
@@ -76,12 +79,12 @@ fn add_default_attrs(db_data: &mut DbMap, calling_info: &CallingInfo) {
 fn local_batch_update(
     calling_info: &CallingInfo,
     attributes_array: &[AssetMap],
-    attributes_to_update_array: &[AssetMap]
-) -> Result<Vec<(u32, u32)>> {
+    attributes_to_update_array: &mut [AssetMap]
+) -> Result<Vec<BatchItemResult>> {
     let attributes = match attributes_array.first() {
         Some(attr) => attr,
         None => return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL]Batch Update argument empty."),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange, "[FATAL]Batch Update argument empty."),
     };
     let mut db_map = into_db_map(attributes);
     check_attrs_array(attributes_array).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
@@ -100,12 +103,12 @@ fn local_batch_update(
 pub(crate) fn batch_update(
     calling_info: &CallingInfo,
     attributes_array: &[AssetMap],
-    attributes_to_update_array: &[AssetMap]
-) -> Result<Vec<(u32, u32)>> {
+    attributes_to_update_array: &mut [AssetMap]
+) -> Result<Vec<BatchItemResult>> {
     if attributes_array.is_empty() || attributes_to_update_array.is_empty()
     || attributes_array.len() != attributes_to_update_array.len(){
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL]Batch Update argument empty.");
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange, "[FATAL]Batch Update argument empty.");
     }
     local_batch_update(calling_info, attributes_array, attributes_to_update_array)
 }
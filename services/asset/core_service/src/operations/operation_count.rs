@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module is used to count the Assets matching a search query, the rows of which are not read.
+
+use asset_common::CallingInfo;
+use asset_crypto_manager::db_key_operator::get_db_key_by_asset_map;
+use asset_db_operator::{
+    common,
+    database::{get_db_by_user_id_db_name, Database},
+    database_file_upgrade::construct_splited_db_name,
+};
+use asset_definition::{macros_lib, AssetMap, Result, Tag};
+
+use crate::operations::common::check_group_validity;
+
+const OPTIONAL_ATTRS: [Tag; 1] = [Tag::QueryFilter];
+
+// The filter of the count is the same as the one of the query, except for the options of the returned data.
+fn check_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
+    let mut valid_tags = common::CRITICAL_LABEL_ATTRS.to_vec();
+    valid_tags.extend_from_slice(&common::NORMAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::CUSTOM_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::ACCESS_CONTROL_ATTRS);
+    valid_tags.extend_from_slice(&common::ASSET_SYNC_ATTRS);
+    valid_tags.extend_from_slice(&OPTIONAL_ATTRS);
+    common::check_tag_validity(attributes, &valid_tags)?;
+    check_group_validity(attributes, calling_info)?;
+    common::check_value_validity(attributes)?;
+    common::check_system_permission(attributes).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))
+}
+
+pub(crate) fn count(calling_info: &CallingInfo, query: &AssetMap) -> Result<u32> {
+    check_arguments(query, calling_info).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+
+    let mut db_data = common::into_db_map(query);
+    if query.get(&Tag::GroupId).is_some() {
+        common::add_group(calling_info, &mut db_data);
+    } else {
+        common::add_owner_info(calling_info, &mut db_data);
+    }
+    // The expired Assets cannot be queried, so they are not counted either.
+    let amend = common::build_unexpired_condition().map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+
+    let db_key = get_db_key_by_asset_map(calling_info.user_id(), query).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    let db_name = construct_splited_db_name(calling_info, db_key.is_some()).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    match get_db_by_user_id_db_name(calling_info.user_id(), db_name) {
+        Some(db) => db.query_visible_data_count(&db_data, Some(&amend))
+            .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!())),
+        None => {
+            let mut db = Database::build(calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
+                macros_lib::hisysevent::function!()))?;
+            db.query_visible_data_count(&db_data, Some(&amend))
+                .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))
+        },
+    }
+}
+
+pub(crate) fn exists(calling_info: &CallingInfo, query: &AssetMap) -> Result<bool> {
+    Ok(count(calling_info, query).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))? > 0)
+}
@@ -0,0 +1,46 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module is used to report the capabilities of the Asset service to the client.
+
+use asset_db_operator::common;
+use asset_definition::{Capabilities, Feature, Tag};
+use asset_ipc::PROTOCOL_VERSION;
+use asset_plugin::asset_plugin::AssetPlugin;
+
+pub(crate) fn get_capabilities() -> Capabilities {
+    let mut features = Feature::Batch as u32
+        | Feature::Wrap as u32
+        | Feature::QueryPage as u32
+        | Feature::BatchQuery as u32
+        | Feature::Transaction as u32
+        | Feature::Rename as u32
+        | Feature::Transfer as u32
+        | Feature::Count as u32;
+    // The synchronization is provided by the plugin, which is absent on some devices.
+    if AssetPlugin::get_instance().load_plugin().is_ok() {
+        features |= Feature::Sync as u32;
+    }
+
+    Capabilities {
+        protocol_version: PROTOCOL_VERSION,
+        supported_tags: Tag::ALL.to_vec(),
+        enum_values: common::ENUM_TAG_VALUES.iter().map(|(tag, values)| (*tag, values.to_vec())).collect(),
+        max_secret_size: common::MAX_SECRET_SIZE as u32,
+        max_label_size: common::MAX_LABEL_SIZE as u32,
+        max_return_limit: common::MAX_RETURN_LIMIT,
+        features,
+    }
+}
@@ -16,15 +16,14 @@
 //! This module prepares for querying Asset that required secondary identity authentication.
 
 use asset_common::CallingInfo;
-use asset_definition::{macros_lib, Accessibility, AssetMap, AuthType, ErrCode, Extension, Result, Tag, Value};
+use asset_definition::{macros_lib, AssetMap, AuthType, ErrCode, ErrReason, Extension, Result, Tag, Value};
 use asset_crypto_manager::{
-    crypto::Crypto, crypto_manager::CryptoManager, secret_key::SecretKey,
-    db_key_operator::get_db_key_by_asset_map,
+    crypto::Crypto, crypto_manager::CryptoManager, db_key_operator::get_db_key_by_asset_map,
 };
 use asset_db_operator::{
     common,
     database::Database,
-    types::{column, DbMap},
+    types::{column, DbMap, QueryOptions},
 };
 
 use crate::operations::common::check_group_validity;
@@ -36,6 +35,7 @@ fn check_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<
     let mut valid_tags = common::CRITICAL_LABEL_ATTRS.to_vec();
     valid_tags.extend_from_slice(&common::NORMAL_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::CUSTOM_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::ACCESS_CONTROL_ATTRS);
     valid_tags.extend_from_slice(&OPTIONAL_ATTRS);
 
@@ -47,30 +47,47 @@ fn check_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<
     match attributes.get(&Tag::AuthType) {
         Some(Value::Number(val)) if *val == (AuthType::None as u32) => {
             macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL][SA]Pre Query AuthType invalid.")
+                ErrCode::InvalidArgument, reason = ErrReason::InvalidEnumVariant,
+                "[FATAL][SA]Pre Query AuthType invalid.")
         },
         _ => Ok(()),
     }
 }
 
-fn query_key_attrs(calling_info: &CallingInfo, db_data: &DbMap, attrs: &AssetMap) -> Result<(Accessibility, bool)> {
+// Query the access control attributes of the data that requires user authentication.
+fn query_key_attrs(
+    calling_info: &CallingInfo,
+    db_data: &DbMap,
+    attrs: &AssetMap,
+) -> Result<DbMap> {
     let db_key = get_db_key_by_asset_map(calling_info.user_id(), attrs).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     let mut db = Database::build(calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
 
-    let results = db.query_datas(&vec![column::ACCESSIBILITY, column::REQUIRE_PASSWORD_SET], db_data, None, true)
+    let columns = vec![
+        column::AUTH_TYPE,
+        column::ACCESSIBILITY,
+        column::REQUIRE_PASSWORD_SET,
+        column::INVALIDATE_ON_BIO_CHANGE,
+    ];
+    // The expired data is not visible, although it has not been purged yet.
+    let query_options = QueryOptions {
+        offset: None,
+        limit: None,
+        order_by: None,
+        amend: Some(common::build_unexpired_condition()?),
+    };
+    let mut results = db.query_datas(&columns, db_data, Some(&query_options), true)
         .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+    results.retain(|data| data.get_num_attr(&column::AUTH_TYPE).map_or(true, |n| n != AuthType::None as u32));
     match results.len() {
         0 => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::NotFound, "[FATAL][SA]No data that meets the query conditions is found."),
-        1 => {
-            let access_type = results[0].get_enum_attr::<Accessibility>(&column::ACCESSIBILITY)?;
-            let require_password_set = results[0].get_bool_attr(&column::REQUIRE_PASSWORD_SET)?;
-            Ok((access_type, require_password_set))
-        },
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+            "[FATAL][SA]No data that meets the query conditions is found."),
+        1 => Ok(results.remove(0)),
         _ => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported,
+            ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
             "[FATAL][SA]Data of multiple access control types cannot be accessed at the same time."),
     }
 }
@@ -84,15 +101,14 @@ pub(crate) fn pre_query(calling_info: &CallingInfo, query: &AssetMap) -> Result<
     } else {
         common::add_owner_info(calling_info, &mut db_data);
     }
-    db_data.entry(column::AUTH_TYPE).or_insert(Value::Number(AuthType::Any as u32));
 
-    let (access_type, require_password_set) = query_key_attrs(calling_info, &db_data, query)
+    let key_attrs = query_key_attrs(calling_info, &db_data, query)
         .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
     let valid_time = match query.get(&Tag::AuthValidityPeriod) {
         Some(Value::Number(num)) => *num,
         _ => DEFAULT_AUTH_VALIDITY_IN_SECS,
     };
-    let secret_key = SecretKey::new_without_alias(calling_info, AuthType::Any, access_type, require_password_set)?;
+    let secret_key = common::build_secret_key(calling_info, &key_attrs)?;
     let mut crypto = Crypto::build(secret_key, calling_info.clone(), valid_time)?;
     let challenge = crypto.init_key().map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?.to_vec();
@@ -15,10 +15,13 @@
 
 //! This module is used to query the Asset, including single and batch query.
 
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::HashMap};
 
 use asset_common::CallingInfo;
-use asset_definition::{macros_lib, AssetMap, AuthType, ErrCode, Extension, Result, ReturnType, Tag, Value};
+use asset_definition::{
+    macros_lib, AssetMap, AssetWithSecret, AuthType, ErrCode, ErrReason, Extension, KeyStatus, OrderKey, Result,
+    ReturnOrder, ReturnType, SecretBytes, Tag, Value,
+};
 use asset_db_operator::{
     common,
     database_file_upgrade::construct_splited_db_name,
@@ -32,8 +35,8 @@ use asset_crypto_manager::{
 
 use crate::operations::common::{check_group_validity, inform_asset_ext};
 
-fn into_asset_maps(db_results: &Vec<DbMap>) -> Result<Vec<AssetMap>> {
-    let mut map_set = Vec::new();
+fn into_asset_maps(db_results: Vec<DbMap>) -> Result<Vec<AssetMap>> {
+    let mut map_set = Vec::with_capacity(db_results.len());
     for db_result in db_results {
         let map = common::into_asset_map(db_result);
         common::check_value_validity(&map)?;
@@ -42,10 +45,8 @@ fn into_asset_maps(db_results: &Vec<DbMap>) -> Result<Vec<AssetMap>> {
     Ok(map_set)
 }
 
-fn upgrade_aad(db: &mut Database, calling_info: &CallingInfo, db_data: &mut DbMap) -> Result<()> {
+fn upgrade_aad(db: &mut Database, calling_info: &CallingInfo, db_data: &mut DbMap, secret: &SecretBytes) -> Result<()> {
     db_data.insert_attr(column::VERSION, DB_DATA_VERSION);
-    let secret = db_data.get_bytes_attr(&column::SECRET).map_err(|e| macros_lib::track_error!(e,
-        macros_lib::hisysevent::function!()))?;
     let secret_key = common::build_secret_key(calling_info, db_data).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     let cipher = Crypto::encrypt(&secret_key, secret,
@@ -65,25 +66,23 @@ fn upgrade_aad(db: &mut Database, calling_info: &CallingInfo, db_data: &mut DbMa
         macros_lib::hisysevent::function!()))?;
     if update_num == 0 {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::NotFound, "[FATAL]Upgrade asset failed.");
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL]Upgrade asset failed.");
     }
     Ok(())
 }
 
-fn decrypt_secret(calling_info: &CallingInfo, db_data: &mut DbMap) -> Result<()> {
+fn decrypt_secret(calling_info: &CallingInfo, db_data: &DbMap) -> Result<SecretBytes> {
     let secret = db_data.get_bytes_attr(&column::SECRET).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     let secret_key = common::build_secret_key(calling_info, db_data).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     let aad = common::build_aad(db_data).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
-    let secret = Crypto::decrypt(&secret_key, secret, &aad).map_err(|e| macros_lib::track_error!(e,
-        macros_lib::hisysevent::function!()))?;
-    db_data.insert(column::SECRET, Value::Bytes(secret));
-    Ok(())
+    Crypto::decrypt(&secret_key, secret, &aad).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))
 }
 
-fn exec_crypto(calling_info: &CallingInfo, query: &AssetMap, db_data: &mut DbMap) -> Result<()> {
+fn exec_crypto(calling_info: &CallingInfo, query: &AssetMap, db_data: &DbMap) -> Result<SecretBytes> {
     common::check_required_tags(query, &AUTH_QUERY_ATTRS)?;
     let challenge = query.get_bytes_attr(&Tag::AuthChallenge)
         .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
@@ -98,38 +97,116 @@ fn exec_crypto(calling_info: &CallingInfo, query: &AssetMap, db_data: &mut DbMap
         Ok(crypto) => {
             let aad = common::build_aad(db_data).map_err(|e| macros_lib::track_error!(e,
                 macros_lib::hisysevent::function!()))?;
-            let secret = crypto.exec_crypt(secret, &aad, auth_token).map_err(|e| macros_lib::track_error!(e,
-                macros_lib::hisysevent::function!()))?;
-            db_data.insert(column::SECRET, Value::Bytes(secret));
-            Ok(())
+            crypto.exec_crypt(secret, &aad, auth_token).map_err(|e| macros_lib::track_error!(e,
+                macros_lib::hisysevent::function!()))
         },
         Err(e) => Err(macros_lib::track_error!(e, macros_lib::hisysevent::function!())),
     }
 }
 
+// Decrypt the secret of the data, which is returned apart from the data rather than put back into it.
+pub(crate) fn read_secret(calling_info: &CallingInfo, query: &AssetMap, db_data: &DbMap) -> Result<SecretBytes> {
+    match db_data.get(column::AUTH_TYPE) {
+        Some(Value::Number(auth_type)) if *auth_type != AuthType::None as u32 => {
+            exec_crypto(calling_info, query, db_data)
+        },
+        _ => decrypt_secret(calling_info, db_data),
+    }
+}
+
+pub(crate) fn check_access_exhausted(db: &mut Database, db_data: &DbMap) -> Result<()> {
+    if db.is_access_exhausted(db_data).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))? {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::AccessCountExhausted, "[FATAL]The access count of the data to be queried is exhausted.");
+    }
+    Ok(())
+}
+
+// Read the secret and consume one access in the same transaction if the access count of the data is limited.
+// Return the secret and whether the data still exists after reading.
+fn read_secret_with_access_count(
+    db: &mut Database,
+    calling_info: &CallingInfo,
+    query: &AssetMap,
+    db_data: &mut DbMap,
+) -> Result<(SecretBytes, bool)> {
+    if !db_data.contains_key(column::REMAINING_ACCESS_COUNT) {
+        return Ok((read_secret(calling_info, query, db_data)?, true));
+    }
+
+    let id = db_data.get_num_attr(&column::ID).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    let mut secret = None;
+    let consumed = db.consume_access_count(id, || {
+        secret = Some(read_secret(calling_info, query, db_data)?);
+        Ok(())
+    });
+    let exists = match consumed {
+        Ok(0) => {
+            db_data.remove(column::REMAINING_ACCESS_COUNT);
+            false
+        },
+        Ok(remaining) => {
+            db_data.insert_attr(column::REMAINING_ACCESS_COUNT, remaining);
+            true
+        },
+        Err(e) if e.code == ErrCode::NotFound => {
+            check_access_exhausted(db, db_data)?;
+            return Err(macros_lib::track_error!(e, macros_lib::hisysevent::function!()));
+        },
+        Err(e) => return Err(macros_lib::track_error!(e, macros_lib::hisysevent::function!())),
+    };
+    match secret {
+        Some(secret) => Ok((secret, exists)),
+        None => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::SystemError, "[FATAL]The secret is not read while consuming the access count."),
+    }
+}
+
+// Read the secret of the data, and upgrade the AAD of the secret if it is encrypted by an earlier version.
+pub(crate) fn read_and_upgrade_secret(
+    db: &mut Database,
+    calling_info: &CallingInfo,
+    query: &AssetMap,
+    db_data: &mut DbMap,
+) -> Result<SecretBytes> {
+    let (secret, exists) = read_secret_with_access_count(db, calling_info, query, db_data)?;
+    if exists && common::need_upgrade(db_data).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))? {
+        upgrade_aad(db, calling_info, db_data, &secret).map_err(|e| macros_lib::track_error!(e,
+            macros_lib::hisysevent::function!()))?;
+    }
+    Ok(secret)
+}
+
+// Read the secret of the data, which is returned with the attributes of the data but kept apart from them.
+pub(crate) fn read_asset_with_secret(
+    db: &mut Database,
+    calling_info: &CallingInfo,
+    query: &AssetMap,
+    mut db_data: DbMap,
+) -> Result<AssetWithSecret> {
+    let secret = read_and_upgrade_secret(db, calling_info, query, &mut db_data)?;
+    db_data.remove(column::SECRET);
+    let attributes = common::into_asset_map(db_data);
+    common::check_value_validity(&attributes)?;
+    Ok(AssetWithSecret { attributes, secret: Some(secret) })
+}
+
 fn query_all_inner(
     mut results: Vec<DbMap>,
     db: &mut Database,
     calling_info: &CallingInfo,
+    db_data: &DbMap,
     query: &AssetMap
-) -> Result<Vec<AssetMap>> {
+) -> Result<Vec<AssetWithSecret>> {
     match results.len() {
-        0 => macros_lib::throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::NotFound, "[FATAL]The data to be queried does not exist."),
-        1 => {
-            match results[0].get(column::AUTH_TYPE) {
-                Some(Value::Number(auth_type)) if *auth_type == AuthType::Any as u32 => {
-                    exec_crypto(calling_info, query, &mut results[0])?;
-                },
-                _ => decrypt_secret(calling_info, &mut results[0])?,
-            };
-            if common::need_upgrade(&results[0]).map_err(|e| macros_lib::track_error!(e,
-                macros_lib::hisysevent::function!()))? {
-                upgrade_aad(db, calling_info, &mut results[0]).map_err(|e| macros_lib::track_error!(e,
-                    macros_lib::hisysevent::function!()))?;
-            }
-            into_asset_maps(&results)
+        0 => {
+            check_access_exhausted(db, db_data)?;
+            macros_lib::throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL]The data to be queried does not exist.")
         },
+        1 => Ok(vec![read_asset_with_secret(db, calling_info, query, results.remove(0))?]),
         n => {
             macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
                 ErrCode::DatabaseError, "[FATAL]The database contains {} records with the specified alias.", n)
@@ -137,31 +214,61 @@ fn query_all_inner(
     }
 }
 
-fn query_all(calling_info: &CallingInfo, db_data: &DbMap, query: &AssetMap) -> Result<Vec<AssetMap>> {
+fn query_all(calling_info: &CallingInfo, db_data: &DbMap, query: &AssetMap) -> Result<Vec<AssetWithSecret>> {
+    let query_options = QueryOptions {
+        offset: None,
+        limit: None,
+        order_by: None,
+        amend: Some(common::build_unexpired_condition().map_err(|e| macros_lib::track_error!(e,
+            macros_lib::hisysevent::function!()))?),
+    };
     let db_key = get_db_key_by_asset_map(calling_info.user_id(), query).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     let db_name = construct_splited_db_name(calling_info, db_key.is_some()).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     match get_db_by_user_id_db_name(calling_info.user_id(), db_name) {
         Some(db) => {
-            let results = db.query_datas(&vec![], db_data, None, true).map_err(|e| macros_lib::track_error!(e,
-                macros_lib::hisysevent::function!()))?;
-            query_all_inner(results, db, calling_info, query).map_err(|e| macros_lib::track_error!(e,
+            let results = db.query_datas(&vec![], db_data, Some(&query_options), true)
+                .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+            query_all_inner(results, db, calling_info, db_data, query).map_err(|e| macros_lib::track_error!(e,
                 macros_lib::hisysevent::function!()))
         },
         None => {
             let mut db = Database::build(calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
                 macros_lib::hisysevent::function!()))?;
-            let results = db.query_datas(&vec![], db_data, None, true).map_err(|e| macros_lib::track_error!(e,
-                macros_lib::hisysevent::function!()))?;
-            query_all_inner(results, &mut db, calling_info, query).map_err(|e| macros_lib::track_error!(e,
+            let results = db.query_datas(&vec![], db_data, Some(&query_options), true)
+                .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+            query_all_inner(results, &mut db, calling_info, db_data, query).map_err(|e| macros_lib::track_error!(e,
                 macros_lib::hisysevent::function!()))
         },
     }
 }
 
-fn get_query_options(attrs: &AssetMap) -> QueryOptions {
-    QueryOptions {
+// The tags by which the query results are sorted with the directions, which have been verified.
+fn get_order_keys(attrs: &AssetMap) -> Result<Vec<(Tag, ReturnOrder)>> {
+    match (attrs.get(&Tag::ReturnOrderedBy), attrs.get(&Tag::ReturnOrderedByKeys)) {
+        (Some(Value::Number(order_by)), _) => {
+            let order = match attrs.get(&Tag::ReturnOrder) {
+                Some(Value::Number(order)) => ReturnOrder::try_from(*order)?,
+                _ => ReturnOrder::default(),
+            };
+            Ok(vec![(Tag::try_from(*order_by)?, order)])
+        },
+        (_, Some(Value::Bytes(keys))) => OrderKey::decode_list(keys),
+        _ => Ok(vec![]),
+    }
+}
+
+pub(crate) fn get_query_options(attrs: &AssetMap) -> Result<QueryOptions> {
+    // The time is stored as the decimal string of milliseconds with the same length, so it is sorted as the bytes.
+    let order_by: Vec<(&'static str, Ordering)> = get_order_keys(attrs)?
+        .into_iter()
+        .filter_map(|(tag, order)| common::get_column_name(tag).map(|column| match order {
+            ReturnOrder::Ascending => (column, Ordering::Greater),
+            ReturnOrder::Descending => (column, Ordering::Less),
+        }))
+        .collect();
+    Ok(QueryOptions {
         offset: match attrs.get(&Tag::ReturnOffset) {
             Some(Value::Number(offset)) => Some(*offset),
             _ => None,
@@ -170,71 +277,132 @@ fn get_query_options(attrs: &AssetMap) -> QueryOptions {
             Some(Value::Number(limit)) => Some(*limit),
             _ => None,
         },
-        order_by: match attrs.get(&Tag::ReturnOrderedBy) {
-            Some(Value::Number(order_by)) => {
-                let tag = Tag::try_from(*order_by).expect("Tag::ReturnOrderBy has been verified");
-                common::get_column_name(tag).map(|order_by| vec![order_by])
-            },
-            _ => None,
-        },
-        order: {
-            if attrs.contains_key(&Tag::ReturnOrderedBy) {
-                Some(Ordering::Greater)
-            } else {
-                None
-            }
-        },
-        amend: None,
-    }
+        order_by: if order_by.is_empty() { None } else { Some(order_by) },
+        amend: Some(common::build_unexpired_condition()?),
+    })
 }
 
-pub(crate) fn query_attrs(calling_info: &CallingInfo, db_data: &DbMap, attrs: &AssetMap) -> Result<Vec<AssetMap>> {
+// Query the data matching the conditions from the database of the caller.
+pub(crate) fn query_db_datas(
+    calling_info: &CallingInfo,
+    db_data: &DbMap,
+    attrs: &AssetMap,
+    query_options: &QueryOptions,
+) -> Result<Vec<DbMap>> {
     let db_key = get_db_key_by_asset_map(calling_info.user_id(), attrs).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     let db_name = construct_splited_db_name(calling_info, db_key.is_some()).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
-    let mut results = match get_db_by_user_id_db_name(calling_info.user_id(), db_name) {
-        Some(db) => {
-            db.query_datas(&vec![], db_data, Some(&get_query_options(attrs)), true)
-                .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?
-        },
+    match get_db_by_user_id_db_name(calling_info.user_id(), db_name) {
+        Some(db) => db.query_datas(&vec![], db_data, Some(query_options), true)
+            .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!())),
         None => {
             let mut db = Database::build(calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
                 macros_lib::hisysevent::function!()))?;
-            db.query_datas(&vec![], db_data, Some(&get_query_options(attrs)), true)
-                .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?
+            db.query_datas(&vec![], db_data, Some(query_options), true)
+                .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))
         },
-    };
-    if results.is_empty() {
-        return macros_lib::throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::NotFound, "[FATAL]The data to be queried does not exist.");
     }
+}
 
+// Convert the queried data into the attributes returned to the caller, the secrets are not included.
+pub(crate) fn into_attr_maps(
+    calling_info: &CallingInfo,
+    attrs: &AssetMap,
+    mut results: Vec<DbMap>,
+) -> Result<Vec<AssetMap>> {
     for data in &mut results {
         data.remove(&column::SECRET);
     }
-    into_asset_maps(&results).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))
+    let key_statuses =
+        if matches!(attrs.get(&Tag::ReturnType), Some(Value::Number(n)) if *n == ReturnType::KeyStatus as u32) {
+            Some(query_key_status(calling_info, &results)
+                .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?)
+        } else {
+            None
+        };
+    let mut maps =
+        into_asset_maps(results).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+    if let Some(key_statuses) = key_statuses {
+        for (map, status) in maps.iter_mut().zip(key_statuses) {
+            map.insert_attr(Tag::KeyStatus, status);
+        }
+    }
+    Ok(maps)
 }
 
-const OPTIONAL_ATTRS: [Tag; 6] =
-    [Tag::ReturnLimit, Tag::ReturnOffset, Tag::ReturnOrderedBy, Tag::ReturnType, Tag::AuthToken, Tag::AuthChallenge];
+pub(crate) fn query_attrs(calling_info: &CallingInfo, db_data: &DbMap, attrs: &AssetMap) -> Result<Vec<AssetMap>> {
+    let query_options = get_query_options(attrs).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    let results = query_db_datas(calling_info, db_data, attrs, &query_options)
+        .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+    if results.is_empty() {
+        return macros_lib::throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL]The data to be queried does not exist.");
+    }
+    into_attr_maps(calling_info, attrs, results)
+}
+
+// Assets protected by the same secret key share the key status, so check each key only once.
+fn query_key_status(calling_info: &CallingInfo, db_results: &[DbMap]) -> Result<Vec<KeyStatus>> {
+    let mut key_statuses: HashMap<Vec<u8>, KeyStatus> = HashMap::new();
+    let mut statuses = Vec::with_capacity(db_results.len());
+    for db_result in db_results {
+        let secret_key = common::build_secret_key(calling_info, db_result)?;
+        let status = match key_statuses.get(secret_key.alias()) {
+            Some(status) => *status,
+            None => {
+                let status = secret_key.status()?;
+                key_statuses.insert(secret_key.alias().clone(), status);
+                status
+            },
+        };
+        statuses.push(status);
+    }
+    Ok(statuses)
+}
+
+const OPTIONAL_ATTRS: [Tag; 9] = [
+    Tag::ReturnLimit,
+    Tag::ReturnOffset,
+    Tag::ReturnOrderedBy,
+    Tag::ReturnOrderedByKeys,
+    Tag::ReturnOrder,
+    Tag::ReturnType,
+    Tag::AuthToken,
+    Tag::AuthChallenge,
+    Tag::QueryFilter,
+];
 const AUTH_QUERY_ATTRS: [Tag; 2] = [Tag::AuthChallenge, Tag::AuthToken];
 
 fn check_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
     let mut valid_tags = common::CRITICAL_LABEL_ATTRS.to_vec();
     valid_tags.extend_from_slice(&common::NORMAL_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::CUSTOM_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::ACCESS_CONTROL_ATTRS);
     valid_tags.extend_from_slice(&common::ASSET_SYNC_ATTRS);
     valid_tags.extend_from_slice(&OPTIONAL_ATTRS);
     common::check_tag_validity(attributes, &valid_tags)?;
     check_group_validity(attributes, calling_info)?;
     common::check_value_validity(attributes)?;
+    if attributes.contains_key(&Tag::ReturnOrderedBy) && attributes.contains_key(&Tag::ReturnOrderedByKeys) {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
+            "[FATAL]The tags [{}] and [{}] cannot be specified at the same time.",
+            Tag::ReturnOrderedBy, Tag::ReturnOrderedByKeys);
+    }
+    if attributes.contains_key(&Tag::ReturnOrder) && attributes.contains_key(&Tag::ReturnOrderedByKeys) {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
+            "[FATAL]The tag [{}] cannot be specified with [{}], which carries the direction of each key.",
+            Tag::ReturnOrder, Tag::ReturnOrderedByKeys);
+    }
     common::check_system_permission(attributes).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))
 }
 
-pub(crate) fn query(calling_info: &CallingInfo, query: &AssetMap) -> Result<Vec<AssetMap>> {
+pub(crate) fn query(calling_info: &CallingInfo, query: &AssetMap) -> Result<Vec<AssetWithSecret>> {
     check_arguments(query, calling_info).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
 
     inform_asset_ext(calling_info, query);
@@ -250,11 +418,12 @@ pub(crate) fn query(calling_info: &CallingInfo, query: &AssetMap) -> Result<Vec<
         Some(Value::Number(return_type)) if *return_type == (ReturnType::All as u32) => {
             if !query.contains_key(&Tag::Alias) {
                 macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                    ErrCode::Unsupported, "[FATAL]Batch secret query is not supported.")
+                    ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+                    "[FATAL]Batch secret query is not supported.")
             } else {
                 query_all(calling_info, &db_data, query)
             }
         },
-        _ => query_attrs(calling_info, &db_data, query),
+        _ => Ok(query_attrs(calling_info, &db_data, query)?.into_iter().map(AssetWithSecret::from).collect()),
     }
 }
@@ -0,0 +1,197 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module is used to query the attributes of Assets page by page.
+//!
+//! The pages are sorted by the Id of the Assets, which never changes after the Asset is added. The continuation token
+//! carries the Id of the last Asset in the page instead of an offset, so that adding or removing Assets between two
+//! calls does not cause the Assets to be skipped or returned twice.
+
+use std::{cmp::Ordering, sync::OnceLock};
+
+use asset_common::CallingInfo;
+use asset_definition::{macros_lib, AssetMap, AssetPage, ErrCode, ErrReason, Extension, Result, ReturnType, Tag, Value};
+use asset_db_operator::{
+    common,
+    types::{column, DbMap, QueryOptions},
+};
+use asset_utils::hasher;
+
+use crate::operations::{
+    common::{check_group_validity, inform_asset_ext},
+    operation_query::{into_attr_maps, query_db_datas},
+};
+
+extern "C" {
+    fn GenerateRandom(random: *mut u8, random_len: u32) -> i32;
+}
+
+const SUCCESS: i32 = 0;
+const DEFAULT_PAGE_SIZE: u32 = 100;
+const MAX_PAGE_SIZE: u32 = 1000;
+
+const PAGE_TOKEN_VERSION: u8 = 1;
+const TOKEN_KEY_SIZE: usize = 32;
+const ID_SIZE: usize = std::mem::size_of::<u32>();
+const DIGEST_SIZE: usize = 32;
+const MAC_SIZE: usize = 32;
+// The token consists of the version, the Id of the last Asset in the page, the digest of the query and the MAC.
+const PAGE_TOKEN_SIZE: usize = 1 + ID_SIZE + DIGEST_SIZE + MAC_SIZE;
+
+const OPTIONAL_ATTRS: [Tag; 4] = [Tag::ReturnLimit, Tag::ReturnType, Tag::PageToken, Tag::QueryFilter];
+
+// The key used to sign the tokens lives in memory only, the tokens are invalidated once the service restarts.
+fn token_key() -> Result<&'static [u8; TOKEN_KEY_SIZE]> {
+    static TOKEN_KEY: OnceLock<[u8; TOKEN_KEY_SIZE]> = OnceLock::new();
+    if let Some(key) = TOKEN_KEY.get() {
+        return Ok(key);
+    }
+
+    let mut key = [0; TOKEN_KEY_SIZE];
+    if unsafe { GenerateRandom(key.as_mut_ptr(), key.len() as u32) } != SUCCESS {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::CryptoError, "[FATAL]Generate random failed!");
+    }
+    Ok(TOKEN_KEY.get_or_init(|| key))
+}
+
+// Bind the token to the caller and the query conditions, so that it can not be used for another query.
+fn digest_query(calling_info: &CallingInfo, db_data: &DbMap) -> Vec<u8> {
+    let mut message = calling_info.user_id().to_be_bytes().to_vec();
+    let mut columns: Vec<&&str> = db_data.keys().collect();
+    columns.sort();
+    for column in columns {
+        message.extend_from_slice(&(column.len() as u32).to_be_bytes());
+        message.extend_from_slice(column.as_bytes());
+        match &db_data[*column] {
+            Value::Bool(b) => message.push(*b as u8),
+            Value::Number(n) => message.extend_from_slice(&n.to_be_bytes()),
+            Value::Bytes(bytes) => {
+                message.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+                message.extend_from_slice(bytes);
+            },
+        }
+    }
+    hasher::sha256(true, &message)
+}
+
+fn encode_token(last_id: u32, digest: &[u8]) -> Result<Vec<u8>> {
+    let mut token = Vec::with_capacity(PAGE_TOKEN_SIZE);
+    token.push(PAGE_TOKEN_VERSION);
+    token.extend_from_slice(&last_id.to_be_bytes());
+    token.extend_from_slice(digest);
+    let mac = hasher::hmac_sha256(token_key()?, &token)?;
+    token.extend_from_slice(&mac);
+    Ok(token)
+}
+
+fn decode_token(token: &[u8], digest: &[u8]) -> Result<u32> {
+    if token.len() != PAGE_TOKEN_SIZE || token[0] != PAGE_TOKEN_VERSION {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue, "[FATAL]The page token is malformed.");
+    }
+
+    let (payload, mac) = token.split_at(PAGE_TOKEN_SIZE - MAC_SIZE);
+    let expected_mac = hasher::hmac_sha256(token_key()?, payload)?;
+    let diff = expected_mac.iter().zip(mac.iter()).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+    if diff != 0 || &payload[1 + ID_SIZE..] != digest {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+            "[FATAL]The page token is expired or does not belong to the query.");
+    }
+
+    let mut last_id = [0; ID_SIZE];
+    last_id.copy_from_slice(&payload[1..1 + ID_SIZE]);
+    Ok(u32::from_be_bytes(last_id))
+}
+
+fn get_page_size(query: &AssetMap) -> Result<u32> {
+    match query.get(&Tag::ReturnLimit) {
+        Some(Value::Number(limit)) if *limit == 0 || *limit > MAX_PAGE_SIZE => {
+            macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::NumberOutOfRange,
+                "[FATAL]The page size[{}] is out of range (0, {}].", *limit, MAX_PAGE_SIZE)
+        },
+        Some(Value::Number(limit)) => Ok(*limit),
+        _ => Ok(DEFAULT_PAGE_SIZE),
+    }
+}
+
+fn query_page_inner(calling_info: &CallingInfo, db_data: &DbMap, query: &AssetMap) -> Result<AssetPage> {
+    let digest = digest_query(calling_info, db_data);
+    let mut amend = common::build_unexpired_condition()?;
+    if let Some(Value::Bytes(token)) = query.get(&Tag::PageToken) {
+        let last_id = decode_token(token, &digest)?;
+        amend.push_str(&format!(" and {} > {}", column::ID, last_id));
+    }
+
+    // Query one more Asset to find out whether there is a next page.
+    let page_size = get_page_size(query)?;
+    let query_options = QueryOptions {
+        offset: None,
+        limit: Some(page_size + 1),
+        order_by: Some(vec![(column::ID, Ordering::Greater)]),
+        amend: Some(amend),
+    };
+    let mut results = query_db_datas(calling_info, db_data, query, &query_options)
+        .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+
+    let next_token = if results.len() > page_size as usize {
+        results.truncate(page_size as usize);
+        let last_id = results[page_size as usize - 1].get_num_attr(&column::ID)?;
+        Some(encode_token(last_id, &digest)?)
+    } else {
+        None
+    };
+    let assets = into_attr_maps(calling_info, query, results)
+        .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+    Ok(AssetPage { assets, next_token })
+}
+
+fn check_arguments(query: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
+    let mut valid_tags = common::CRITICAL_LABEL_ATTRS.to_vec();
+    valid_tags.extend_from_slice(&common::NORMAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::CUSTOM_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::ACCESS_CONTROL_ATTRS);
+    valid_tags.extend_from_slice(&common::ASSET_SYNC_ATTRS);
+    valid_tags.extend_from_slice(&OPTIONAL_ATTRS);
+    common::check_tag_validity(query, &valid_tags)?;
+    check_group_validity(query, calling_info)?;
+    common::check_value_validity(query)?;
+    if let Some(Value::Number(return_type)) = query.get(&Tag::ReturnType) {
+        if *return_type == ReturnType::All as u32 {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::Unsupported, reason = ErrReason::FeatureUnsupported,
+                "[FATAL]The secrets are not returned by the paged query.");
+        }
+    }
+    common::check_system_permission(query).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))
+}
+
+pub(crate) fn query_page(calling_info: &CallingInfo, query: &AssetMap) -> Result<AssetPage> {
+    check_arguments(query, calling_info).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+
+    inform_asset_ext(calling_info, query);
+
+    let mut db_data = common::into_db_map(query);
+    if query.get(&Tag::GroupId).is_some() {
+        common::add_group(calling_info, &mut db_data);
+    } else {
+        common::add_owner_info(calling_info, &mut db_data);
+    }
+    query_page_inner(calling_info, &db_data, query)
+}
@@ -38,11 +38,7 @@ fn check_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<
 fn map_err(code: u32) -> AssetError {
     match ErrCode::try_from(code) {
         Ok(code) => {
-            AssetError {
-                code,
-                msg: "get sync result failed".to_string(),
-                call_chain: AssetError::shorten_func_name(macros_lib::hisysevent::function!()).to_string(),
-            }
+            AssetError::new(code, "get sync result failed".to_string(), macros_lib::hisysevent::function!())
         },
         Err(err) => err,
     }
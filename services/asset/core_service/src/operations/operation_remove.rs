@@ -22,26 +22,27 @@ use asset_db_operator::{
     database::Database,
     types::{column, DbMap},
 };
-use asset_definition::{macros_lib, AssetMap, ErrCode, Result, SyncStatus, SyncType, Tag, Value};
+use asset_definition::{macros_lib, AssetMap, ErrCode, ErrReason, Result, SyncStatus, SyncType, Tag, Value};
 use asset_log::logi;
 use asset_utils::time;
 
 use crate::operations::common::{check_group_validity, inform_asset_ext, update_cloud_sync_status};
 
-fn add_system_attrs(db_data: &mut DbMap) -> Result<()> {
+pub(crate) fn add_system_attrs(db_data: &mut DbMap) -> Result<()> {
     let time = time::system_time_in_millis()?;
     db_data.insert(column::UPDATE_TIME, Value::Bytes(time));
     Ok(())
 }
 
-fn add_normal_attrs(db_data: &mut DbMap) {
+pub(crate) fn add_normal_attrs(db_data: &mut DbMap) {
     db_data.insert(column::SYNC_STATUS, Value::Number(SyncStatus::SyncDel as u32));
 }
 
-fn check_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
+pub(crate) fn check_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
     let mut valid_tags = common::CRITICAL_LABEL_ATTRS.to_vec();
     valid_tags.extend_from_slice(&common::NORMAL_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::CUSTOM_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::ACCESS_CONTROL_ATTRS);
     valid_tags.extend_from_slice(&common::ASSET_SYNC_ATTRS);
     common::check_tag_validity(attributes, &valid_tags)?;
@@ -69,18 +70,26 @@ pub(crate) fn remove(calling_info: &CallingInfo, query: &AssetMap) -> Result<()>
         macros_lib::hisysevent::function!()))?;
     let mut db = Database::build(calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
+    // The expired Asset has been removed in effect, so it is purged and reported as not found.
+    db.delete_expired_datas_by_condition(&db_data).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    // The record of the exhausted access count is removed with the alias, which is then reported as not found.
+    if db_data.contains_key(column::ALIAS) {
+        let _ = db.delete_access_exhausted_datas(&db_data).map_err(|e| macros_lib::track_error!(e,
+            macros_lib::hisysevent::function!()))?;
+    }
     let results = db.query_datas(&vec![], &db_data, None, true).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     if results.is_empty() {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::NotFound, "[FATAL]The data to be deleted does not exist.");
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL]The data to be deleted does not exist.");
     }
 
     let update_num = db.update_datas(&db_data, true, &update_db_data).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     if update_num == 0 {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::NotFound, "[FATAL]The data to be deleted does not exist.");
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL]The data to be deleted does not exist.");
     }
 
     let mut reverse_condition = DbMap::new();
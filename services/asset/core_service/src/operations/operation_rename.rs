@@ -0,0 +1,153 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module is used to rename the Asset, the secret of which is re-encrypted with the new alias.
+
+use asset_common::CallingInfo;
+use asset_crypto_manager::db_key_operator::get_db_key_by_asset_map;
+use asset_db_operator::{
+    common,
+    database::Database,
+    types::{column, DbMap, DbWrite, DB_DATA_VERSION},
+};
+use asset_definition::{
+    macros_lib, AssetMap, ErrCode, ErrReason, LocalStatus, Result, SecretBytes, SyncStatus, Tag, Value,
+};
+use asset_utils::time;
+
+use crate::operations::{
+    common::{check_group_validity, inform_asset_ext, update_cloud_sync_status},
+    operation_query::read_secret,
+    operation_remove, operation_update,
+};
+
+const QUERY_REQUIRED_ATTRS: [Tag; 1] = [Tag::Alias];
+const QUERY_OPTIONAL_ATTRS: [Tag; 2] = [Tag::AuthChallenge, Tag::AuthToken];
+const UPDATE_ATTRS: [Tag; 1] = [Tag::Alias];
+// The cloud record belongs to the old alias, the renamed Asset is synchronized as a new one.
+const CLOUD_ATTRS: [&str; 3] = [column::ID, column::CLOUD_VERSION, column::GLOBAL_ID];
+
+fn check_arguments(query: &AssetMap, update: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
+    common::check_required_tags(query, &QUERY_REQUIRED_ATTRS)?;
+    let mut valid_tags = common::CRITICAL_LABEL_ATTRS.to_vec();
+    valid_tags.extend_from_slice(&common::NORMAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::CUSTOM_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::ACCESS_CONTROL_ATTRS);
+    valid_tags.extend_from_slice(&QUERY_OPTIONAL_ATTRS);
+    common::check_tag_validity(query, &valid_tags)?;
+    check_group_validity(query, calling_info)?;
+    common::check_value_validity(query)?;
+    common::check_system_permission(query)?;
+
+    common::check_required_tags(update, &UPDATE_ATTRS)?;
+    common::check_tag_validity(update, &UPDATE_ATTRS)?;
+    common::check_value_validity(update)
+}
+
+// Build the renamed Asset from the one read with the decrypted secret.
+fn build_renamed_row(
+    calling_info: &CallingInfo,
+    row: &DbMap,
+    secret: &SecretBytes,
+    new_alias: &Value,
+) -> Result<DbMap> {
+    let mut renamed = row.clone();
+    for column in CLOUD_ATTRS {
+        renamed.remove(column);
+    }
+    renamed.insert(column::ALIAS, new_alias.clone());
+    renamed.insert(column::VERSION, Value::Number(DB_DATA_VERSION));
+    // The alias is a part of the AAD, so the secret is encrypted after the alias is replaced.
+    let cipher = operation_update::encrypt(calling_info, &renamed, secret)?;
+    renamed.insert(column::SECRET, Value::Bytes(cipher));
+    renamed.insert(column::UPDATE_TIME, Value::Bytes(time::system_time_in_millis()?));
+    renamed.insert(column::LOCAL_STATUS, Value::Number(LocalStatus::Local as u32));
+    renamed.insert(column::SYNC_STATUS, Value::Number(SyncStatus::SyncAdd as u32));
+    common::add_next_revision(row, &mut renamed);
+    Ok(renamed)
+}
+
+pub(crate) fn rename(calling_info: &CallingInfo, query: &AssetMap, update: &AssetMap) -> Result<()> {
+    check_arguments(query, update, calling_info).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+
+    let mut condition = common::into_db_map(query);
+    if query.get(&Tag::GroupId).is_some() {
+        common::add_group(calling_info, &mut condition);
+    } else {
+        common::add_owner_info(calling_info, &mut condition);
+    }
+    let mut new_condition = condition.clone();
+    new_condition.retain(|column, _| matches!(*column, column::OWNER | column::OWNER_TYPE | column::GROUP_ID));
+    new_condition.insert(column::ALIAS, update[&Tag::Alias].clone());
+
+    let mut update_db_data = DbMap::new();
+    operation_remove::add_system_attrs(&mut update_db_data).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    operation_remove::add_normal_attrs(&mut update_db_data);
+
+    let db_key = get_db_key_by_asset_map(calling_info.user_id(), query).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    let mut db = Database::build(calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+
+    // The expired Assets are purged, so that they are neither renamed nor block the new alias.
+    db.delete_expired_datas_by_condition(&condition).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    db.delete_expired_datas_by_condition(&new_condition).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+
+    // The old Asset is removed and the renamed one is added in the same transaction.
+    let mut origin = None;
+    let mut renamed = None;
+    let failure = db.exec_writes_in_transaction(2, |index, query_rows| match index {
+        0 => {
+            let mut rows = query_rows(&condition)?;
+            if rows.len() != 1 {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+                    "query to-be-renamed asset failed, found [{}] assets", rows.len());
+            }
+            if !query_rows(&new_condition)?.is_empty() {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::Duplicated, "[FATAL]The new alias already exists.");
+            }
+            let row = rows.remove(0);
+            let secret = read_secret(calling_info, query, &row)?;
+            renamed = Some(build_renamed_row(calling_info, &row, &secret, &update[&Tag::Alias])?);
+            origin = Some(row);
+            Ok(DbWrite::Remove(condition.clone(), update_db_data.clone()))
+        },
+        _ => match renamed.take() {
+            Some(renamed) => Ok(DbWrite::Replace(new_condition.clone(), renamed)),
+            None => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::DatabaseError, "[FATAL]The renamed asset is not prepared."),
+        },
+    }).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+    if let Some((_, e)) = failure {
+        return Err(macros_lib::track_error!(e, macros_lib::hisysevent::function!()));
+    }
+
+    // The new alias is available again, so the record of its exhausted access count is no longer valid.
+    let _ = db.delete_access_exhausted_datas(&new_condition).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    if let Some(origin) = origin {
+        update_cloud_sync_status(calling_info, &vec![origin]);
+    }
+    inform_asset_ext(calling_info, query);
+
+    Ok(())
+}
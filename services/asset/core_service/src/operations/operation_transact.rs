@@ -0,0 +1,223 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module is used to add, update and remove Assets of the same database in a single transaction.
+
+use asset_common::CallingInfo;
+use asset_crypto_manager::db_key_operator::get_db_key_by_asset_map;
+use asset_db_operator::{
+    common,
+    database::Database,
+    types::{column, DbMap, DbWrite},
+};
+use asset_definition::{
+    macros_lib, AssetMap, BatchItemResult, ConflictResolution, ErrCode, ErrReason, Extension, Result, Tag,
+    TransactionOperation, Value,
+};
+use asset_ipc::MAX_BATCH_CHUNK_SIZE;
+
+use crate::operations::{
+    common::{check_tags_consistency, inform_asset_ext, update_cloud_sync_status},
+    operation_add, operation_remove, operation_update,
+};
+
+// The operations of a transaction must be executed in the same database.
+const CONSISTENCY_ATTRS: [Tag; 2] = [Tag::RequireAttrEncrypted, Tag::GroupId];
+
+fn get_alias(operation: &TransactionOperation) -> Vec<u8> {
+    operation.attributes().get_bytes_attr(&Tag::Alias).cloned().unwrap_or_default()
+}
+
+fn check_consistency(operations: &[TransactionOperation]) -> Result<()> {
+    if operations.is_empty() || operations.len() > MAX_BATCH_CHUNK_SIZE as usize {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL]The operation count[{}] is out of range (0, {}].",
+            operations.len(), MAX_BATCH_CHUNK_SIZE);
+    }
+    let scopes: Vec<AssetMap> = operations.iter().map(|operation| {
+        CONSISTENCY_ATTRS.iter()
+            .filter_map(|tag| operation.attributes().get(tag).map(|value| (*tag, value.clone())))
+            .collect()
+    }).collect();
+    check_tags_consistency(&CONSISTENCY_ATTRS, &scopes)
+}
+
+fn check_operation(calling_info: &CallingInfo, operation: &TransactionOperation) -> Result<()> {
+    match operation {
+        TransactionOperation::Add(attributes) => operation_add::check_arguments(attributes, calling_info),
+        TransactionOperation::Update(query, update) => operation_update::check_arguments(query, update, calling_info),
+        TransactionOperation::Remove(query) => operation_remove::check_arguments(query, calling_info),
+    }
+}
+
+fn locate(calling_info: &CallingInfo, query: &AssetMap) -> DbMap {
+    let mut condition = common::into_db_map(query);
+    if query.contains_key(&Tag::GroupId) {
+        common::add_group(calling_info, &mut condition);
+    } else {
+        common::add_owner_info(calling_info, &mut condition);
+    }
+    condition
+}
+
+fn prepare_add(
+    calling_info: &CallingInfo,
+    attributes: &mut AssetMap,
+    query: &dyn Fn(&DbMap) -> Result<Vec<DbMap>>,
+) -> Result<(DbMap, DbWrite)> {
+    // The secret is moved out rather than copied, and it is kept apart from the DbMap until it is encrypted.
+    let secret = attributes.take_secret_attr(&Tag::Secret)?;
+    let mut db_data = common::into_db_map(attributes);
+    operation_add::modify_sync_type(&mut db_data)?;
+    common::add_calling_info(calling_info, &mut db_data);
+    operation_add::add_system_attrs(&mut db_data)?;
+    operation_add::add_default_attrs(&mut db_data);
+    let condition = common::get_query_condition(attributes, calling_info)?;
+
+    let is_overwrite = matches!(attributes.get(&Tag::ConflictResolution),
+        Some(Value::Number(num)) if *num == ConflictResolution::Overwrite as u32);
+    let rows = query(&condition)?;
+    if !is_overwrite && !rows.is_empty() {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::Duplicated, "[FATAL][SA]The specified alias already exists.");
+    }
+    // The replaced Asset passes its revision on, so that a caller holding the old revision cannot update it.
+    if let Some(row) = rows.iter().max_by_key(|row| common::get_revision(row)) {
+        common::add_next_revision(row, &mut db_data);
+    }
+    operation_add::encrypt_secret(calling_info, &mut db_data, &secret)?;
+    Ok((condition.clone(), DbWrite::Replace(condition, db_data)))
+}
+
+fn prepare_update(
+    calling_info: &CallingInfo,
+    query_attrs: &AssetMap,
+    update: &mut AssetMap,
+    query: &dyn Fn(&DbMap) -> Result<Vec<DbMap>>,
+) -> Result<(Vec<DbMap>, DbWrite)> {
+    let mut condition = locate(calling_info, query_attrs);
+    let expected_revision = condition.remove(column::REVISION);
+    let rows = query(&condition)?;
+    if rows.len() != 1 {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+            "[FATAL]Query to-be-updated asset failed, found [{}] assets", rows.len());
+    }
+    // The rows are locked by the transaction, so the revision checked is the one to be updated.
+    common::check_revision(expected_revision.as_ref(), &rows[0])?;
+
+    let mut update_db_data = DbMap::new();
+    operation_update::add_attrs(update, &mut update_db_data)?;
+    // The secret is moved out after the attributes to update are inspected, and it is kept apart until encrypted.
+    let secret = common::take_secret(update)?;
+    update_db_data.extend(common::into_db_map(update));
+    common::add_next_revision(&rows[0], &mut update_db_data);
+    if let Some(secret) = secret {
+        let mut row = rows[0].clone();
+        if common::need_upgrade(&row)? {
+            operation_update::upgrade_to_latest_version(&mut row, &mut update_db_data);
+        }
+        // Using the row with AAD to encrypt secret, otherwise encryption failed.
+        let cipher = operation_update::encrypt(calling_info, &row, &secret)?;
+        update_db_data.insert(column::SECRET, Value::Bytes(cipher));
+    }
+    Ok((rows, DbWrite::Update(condition, update_db_data)))
+}
+
+fn prepare_remove(
+    calling_info: &CallingInfo,
+    query_attrs: &AssetMap,
+    query: &dyn Fn(&DbMap) -> Result<Vec<DbMap>>,
+) -> Result<(Vec<DbMap>, DbWrite)> {
+    let condition = locate(calling_info, query_attrs);
+    let rows = query(&condition)?;
+    if rows.is_empty() {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL]The data to be deleted does not exist.");
+    }
+    let mut update_db_data = DbMap::new();
+    operation_remove::add_system_attrs(&mut update_db_data)?;
+    operation_remove::add_normal_attrs(&mut update_db_data);
+    Ok((rows, DbWrite::Remove(condition, update_db_data)))
+}
+
+pub(crate) fn transact(
+    calling_info: &CallingInfo,
+    operations: &mut [TransactionOperation],
+) -> Result<Vec<BatchItemResult>> {
+    check_consistency(operations).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+    for (index, operation) in operations.iter().enumerate() {
+        if let Err(e) = check_operation(calling_info, operation) {
+            return Ok(vec![BatchItemResult::failure(index as u32, get_alias(operation), e.code, e.msg)]);
+        }
+    }
+
+    let db_key = get_db_key_by_asset_map(calling_info.user_id(), operations[0].attributes())
+        .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+    let mut db = Database::build(calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+
+    // The expired Assets of the caller are purged, so that they are neither operated nor block the aliases added.
+    let mut scope = DbMap::new();
+    if operations[0].attributes().contains_key(&Tag::GroupId) {
+        common::add_group(calling_info, &mut scope);
+    } else {
+        common::add_owner_info(calling_info, &mut scope);
+    }
+    db.delete_expired_datas_by_condition(&scope).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+
+    let mut added_conditions = vec![];
+    let mut sync_rows = vec![];
+    let failure = db.exec_writes_in_transaction(operations.len(), |index, query| match &mut operations[index] {
+        TransactionOperation::Add(attributes) => {
+            let (condition, write) = prepare_add(calling_info, attributes, query)?;
+            added_conditions.push(condition);
+            // The secret has been taken out of the attributes, so no plaintext is kept for the sync status.
+            sync_rows.push(common::into_db_map(attributes));
+            Ok(write)
+        },
+        TransactionOperation::Update(query_attrs, update) => {
+            let (rows, write) = prepare_update(calling_info, query_attrs, update, query)?;
+            sync_rows.extend(rows);
+            Ok(write)
+        },
+        TransactionOperation::Remove(query_attrs) => {
+            let (rows, write) = prepare_remove(calling_info, query_attrs, query)?;
+            sync_rows.extend(rows);
+            Ok(write)
+        },
+    }).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+    if let Some((index, e)) = failure {
+        return Ok(vec![BatchItemResult::failure(index as u32, get_alias(&operations[index]), e.code, e.msg)]);
+    }
+
+    // The aliases added are available again, so the records of their exhausted access count are no longer valid.
+    for condition in &added_conditions {
+        let _ = db.delete_access_exhausted_datas(condition).map_err(|e| macros_lib::track_error!(e,
+            macros_lib::hisysevent::function!()))?;
+    }
+    update_cloud_sync_status(calling_info, &sync_rows);
+    let mut results = Vec::with_capacity(operations.len());
+    for (index, operation) in operations.iter().enumerate() {
+        match operation {
+            TransactionOperation::Update(_, update) => inform_asset_ext(calling_info, update),
+            _ => inform_asset_ext(calling_info, operation.attributes()),
+        }
+        results.push(BatchItemResult::success(index as u32, get_alias(operation), None));
+    }
+    Ok(results)
+}
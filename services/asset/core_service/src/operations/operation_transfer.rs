@@ -0,0 +1,215 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! This module is used to move or copy the Asset between the private space of the caller and its groups.
+
+use asset_common::CallingInfo;
+use asset_crypto_manager::db_key_operator::get_db_key_by_asset_map;
+use asset_db_operator::{
+    common,
+    database::Database,
+    database_file_upgrade::construct_splited_db_name,
+    types::{column, DbMap, DbWrite, QueryOptions, DB_DATA_VERSION},
+};
+use asset_definition::{
+    macros_lib, AssetMap, ConflictResolution, ErrCode, ErrReason, LocalStatus, Result, SecretBytes, SyncStatus, Tag,
+    Value,
+};
+use asset_log::loge;
+use asset_utils::time;
+
+use crate::operations::{
+    common::{check_group_validity, inform_asset_ext, update_cloud_sync_status},
+    operation_add,
+    operation_query::read_secret,
+    operation_remove,
+};
+
+const QUERY_REQUIRED_ATTRS: [Tag; 1] = [Tag::Alias];
+const QUERY_OPTIONAL_ATTRS: [Tag; 2] = [Tag::AuthChallenge, Tag::AuthToken];
+const TARGET_ATTRS: [Tag; 2] = [Tag::GroupId, Tag::ConflictResolution];
+// The identity of the Asset in the source space, which is rebuilt in the target space.
+const SOURCE_ATTRS: [&str; 6] =
+    [column::ID, column::OWNER, column::OWNER_TYPE, column::GROUP_ID, column::CLOUD_VERSION, column::GLOBAL_ID];
+
+fn check_arguments(
+    calling_info: &CallingInfo,
+    query: &AssetMap,
+    target_calling_info: &CallingInfo,
+    target: &AssetMap,
+) -> Result<()> {
+    common::check_required_tags(query, &QUERY_REQUIRED_ATTRS)?;
+    let mut valid_tags = common::CRITICAL_LABEL_ATTRS.to_vec();
+    valid_tags.extend_from_slice(&common::NORMAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::CUSTOM_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::ACCESS_CONTROL_ATTRS);
+    valid_tags.extend_from_slice(&QUERY_OPTIONAL_ATTRS);
+    common::check_tag_validity(query, &valid_tags)?;
+    check_group_validity(query, calling_info)?;
+    common::check_value_validity(query)?;
+    common::check_system_permission(query)?;
+
+    common::check_tag_validity(target, &TARGET_ATTRS)?;
+    check_group_validity(target, target_calling_info)?;
+    common::check_value_validity(target)
+}
+
+// The Asset can only be transferred between the spaces of the same application, and the groups of the same developer.
+fn check_spaces(calling_info: &CallingInfo, target_calling_info: &CallingInfo, is_ce: bool) -> Result<()> {
+    if calling_info.user_id() != target_calling_info.user_id()
+        || calling_info.owner_info() != target_calling_info.owner_info() {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::PermissionDenied, "[FATAL]The Asset cannot be transferred to the space of another owner.");
+    }
+    if let (Some(developer_id), Some(target_developer_id)) =
+        (calling_info.developer_id(), target_calling_info.developer_id()) {
+        if developer_id != target_developer_id {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::PermissionDenied, "[FATAL]The Asset cannot be transferred to the group of another developer.");
+        }
+    }
+    if construct_splited_db_name(calling_info, is_ce)? == construct_splited_db_name(target_calling_info, is_ce)? {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
+            "[FATAL]The source and the target of the transfer are the same space.");
+    }
+    Ok(())
+}
+
+fn query_source(db: &mut Database, condition: &DbMap) -> Result<DbMap> {
+    let query_options = QueryOptions {
+        offset: None,
+        limit: None,
+        order_by: None,
+        amend: Some(common::build_unexpired_condition()?),
+    };
+    let mut rows = db.query_datas(&vec![], condition, Some(&query_options), true)?;
+    if rows.len() != 1 {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+            "query to-be-transferred asset failed, found [{}] assets", rows.len());
+    }
+    Ok(rows.remove(0))
+}
+
+// Build the Asset in the target space from the one read with the decrypted secret.
+fn build_target_row(target_calling_info: &CallingInfo, row: &DbMap, secret: &SecretBytes) -> Result<DbMap> {
+    let mut target_row = row.clone();
+    for column in SOURCE_ATTRS {
+        target_row.remove(column);
+    }
+    common::add_calling_info(target_calling_info, &mut target_row);
+    target_row.insert(column::VERSION, Value::Number(DB_DATA_VERSION));
+    target_row.insert(column::REVISION, Value::Number(0));
+    target_row.insert(column::UPDATE_TIME, Value::Bytes(time::system_time_in_millis()?));
+    target_row.insert(column::LOCAL_STATUS, Value::Number(LocalStatus::Local as u32));
+    target_row.insert(column::SYNC_STATUS, Value::Number(SyncStatus::SyncAdd as u32));
+    // The secret is re-encrypted by the key of the target owner, with the AAD of the target owner.
+    operation_add::encrypt_secret(target_calling_info, &mut target_row, secret)?;
+    Ok(target_row)
+}
+
+fn remove_source(db: &mut Database, condition: &DbMap) -> Result<()> {
+    let mut update_db_data = DbMap::new();
+    operation_remove::add_system_attrs(&mut update_db_data)?;
+    operation_remove::add_normal_attrs(&mut update_db_data);
+    match db.exec_writes_in_transaction(1, |_, _| Ok(DbWrite::Remove(condition.clone(), update_db_data.clone())))? {
+        Some((_, e)) => Err(e),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn transfer(
+    calling_info: &CallingInfo,
+    query: &AssetMap,
+    target_calling_info: &CallingInfo,
+    target: &AssetMap,
+    keep_source: bool,
+) -> Result<()> {
+    check_arguments(calling_info, query, target_calling_info, target).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    let db_key = get_db_key_by_asset_map(calling_info.user_id(), query).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    check_spaces(calling_info, target_calling_info, db_key.is_some()).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+
+    let mut condition = common::into_db_map(query);
+    if query.get(&Tag::GroupId).is_some() {
+        common::add_group(calling_info, &mut condition);
+    } else {
+        common::add_owner_info(calling_info, &mut condition);
+    }
+    let mut db = Database::build(calling_info, db_key.clone()).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    let row = query_source(&mut db, &condition).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    if keep_source && row.contains_key(column::REMAINING_ACCESS_COUNT) {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::Unsupported, reason = ErrReason::LimitedAccessUnsupported,
+            "[FATAL]The Asset with limited access count cannot be copied.");
+    }
+    let secret = read_secret(calling_info, query, &row).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    let mut target_row = build_target_row(target_calling_info, &row, &secret).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+
+    let mut target_condition = DbMap::new();
+    if target_calling_info.group().is_some() {
+        common::add_group(target_calling_info, &mut target_condition);
+    } else {
+        common::add_owner_info(target_calling_info, &mut target_condition);
+    }
+    target_condition.insert(column::ALIAS, row[column::ALIAS].clone());
+    let mut target_db = Database::build(target_calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    // The expired Asset in the target space is purged, so that it does not block the transfer.
+    target_db.delete_expired_datas_by_condition(&target_condition).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    let is_overwrite = matches!(target.get(&Tag::ConflictResolution),
+        Some(Value::Number(num)) if *num == ConflictResolution::Overwrite as u32);
+    if !is_overwrite && target_db.is_data_exists(&target_condition, true).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))? {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::Duplicated, "[FATAL]The specified alias already exists in the target space.");
+    }
+    // The Asset overwritten in the target space is backed up, so that it can be restored if the transfer is withdrawn.
+    let target_backup = target_db.query_datas(&vec![], &target_condition, None, false)
+        .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
+    if let Some(old_row) = target_backup.iter().max_by_key(|old_row| common::get_revision(old_row)) {
+        common::add_next_revision(old_row, &mut target_row);
+    }
+    target_db.replace_datas(&target_condition, false, &target_row).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+
+    // The databases of the source and the target are different, so the target space is restored if the source fails
+    // to be removed.
+    if !keep_source {
+        if let Err(e) = remove_source(&mut db, &condition) {
+            if let Err(withdraw_err) = target_db.restore_datas(&target_condition, &target_backup) {
+                loge!("[FATAL]Withdraw the transferred asset failed, err is {}.", withdraw_err.code);
+            }
+            return Err(macros_lib::track_error!(e, macros_lib::hisysevent::function!()));
+        }
+        update_cloud_sync_status(calling_info, &vec![row]);
+    }
+    // The alias is available again, so the record of its exhausted access count is no longer valid.
+    let _ = target_db.delete_access_exhausted_datas(&target_condition).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    update_cloud_sync_status(target_calling_info, &vec![target_row]);
+    inform_asset_ext(calling_info, query);
+
+    Ok(())
+}
@@ -22,16 +22,16 @@ use asset_db_operator::{
     database::Database,
     types::{column, DbMap, DB_DATA_VERSION},
 };
-use asset_definition::{macros_lib, AssetMap, ErrCode, Extension, LocalStatus, Result, SyncStatus, Tag, Value};
+use asset_definition::{
+    macros_lib, AssetMap, ErrCode, ErrReason, Extension, LocalStatus, Result, SecretBytes, SyncStatus, Tag, Value,
+};
 use asset_utils::time;
 
 use crate::operations::common::{check_group_validity, inform_asset_ext, update_cloud_sync_status};
 
-fn encrypt(calling_info: &CallingInfo, db_data: &DbMap) -> Result<Vec<u8>> {
+pub(crate) fn encrypt(calling_info: &CallingInfo, db_data: &DbMap, secret: &SecretBytes) -> Result<Vec<u8>> {
     let secret_key = common::build_secret_key(calling_info, db_data).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
-    let secret = db_data.get_bytes_attr(&column::SECRET).map_err(|e| macros_lib::track_error!(e,
-        macros_lib::hisysevent::function!()))?;
     let cipher = Crypto::encrypt(&secret_key, secret,
         &common::build_aad(db_data).map_err(|e| macros_lib::track_error!(e,
             macros_lib::hisysevent::function!()))?).map_err(|e| macros_lib::track_error!(e,
@@ -40,7 +40,8 @@ fn encrypt(calling_info: &CallingInfo, db_data: &DbMap) -> Result<Vec<u8>> {
 }
 
 fn is_only_change_local_labels(update: &AssetMap) -> bool {
-    let valid_tags = common::NORMAL_LOCAL_LABEL_ATTRS.to_vec();
+    let mut valid_tags = common::NORMAL_LOCAL_LABEL_ATTRS.to_vec();
+    valid_tags.push(Tag::DataLabelCustomNormalLocal);
     for tag in update.keys() {
         if !valid_tags.contains(tag) {
             return false;
@@ -49,7 +50,7 @@ fn is_only_change_local_labels(update: &AssetMap) -> bool {
     true
 }
 
-fn add_attrs(update: &AssetMap, db_data: &mut DbMap) -> Result<()> {
+pub(crate) fn add_attrs(update: &AssetMap, db_data: &mut DbMap) -> Result<()> {
     if !is_only_change_local_labels(update) {
         add_system_attrs(db_data)?;
         add_normal_attrs(db_data);
@@ -69,15 +70,18 @@ fn add_normal_attrs(db_data: &mut DbMap) {
 }
 
 const QUERY_REQUIRED_ATTRS: [Tag; 1] = [Tag::Alias];
+const QUERY_OPTIONAL_ATTRS: [Tag; 1] = [Tag::Revision];
 const UPDATE_OPTIONAL_ATTRS: [Tag; 1] = [Tag::Secret];
 
-fn check_arguments(query: &AssetMap, attrs_to_update: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
+pub(crate) fn check_arguments(query: &AssetMap, attrs_to_update: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
     // Check attributes used to query.
     common::check_required_tags(query, &QUERY_REQUIRED_ATTRS)?;
     let mut valid_tags = common::CRITICAL_LABEL_ATTRS.to_vec();
     valid_tags.extend_from_slice(&common::NORMAL_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::CUSTOM_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::ACCESS_CONTROL_ATTRS);
+    valid_tags.extend_from_slice(&QUERY_OPTIONAL_ATTRS);
     common::check_tag_validity(query, &valid_tags)?;
     check_group_validity(query, calling_info)?;
     common::check_value_validity(query)?;
@@ -85,23 +89,24 @@ fn check_arguments(query: &AssetMap, attrs_to_update: &AssetMap, calling_info: &
 
     if attrs_to_update.is_empty() {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL]The attributes to update is empty.");
+            ErrCode::InvalidArgument, reason = ErrReason::MissingTag, "[FATAL]The attributes to update is empty.");
     }
     // Check attributes to update.
     valid_tags = common::NORMAL_LABEL_ATTRS.to_vec();
     valid_tags.extend_from_slice(&common::NORMAL_LOCAL_LABEL_ATTRS);
+    valid_tags.extend_from_slice(&common::NORMAL_CUSTOM_LABEL_ATTRS);
     valid_tags.extend_from_slice(&common::ASSET_SYNC_ATTRS);
     valid_tags.extend_from_slice(&UPDATE_OPTIONAL_ATTRS);
     common::check_tag_validity(attrs_to_update, &valid_tags)?;
     common::check_value_validity(attrs_to_update)
 }
 
-fn upgrade_to_latest_version(origin_db_data: &mut DbMap, update_db_data: &mut DbMap) {
+pub(crate) fn upgrade_to_latest_version(origin_db_data: &mut DbMap, update_db_data: &mut DbMap) {
     origin_db_data.insert_attr(column::VERSION, DB_DATA_VERSION);
     update_db_data.insert_attr(column::VERSION, DB_DATA_VERSION);
 }
 
-pub(crate) fn update(calling_info: &CallingInfo, query: &AssetMap, update: &AssetMap) -> Result<()> {
+pub(crate) fn update(calling_info: &CallingInfo, query: &AssetMap, update: &mut AssetMap) -> Result<()> {
     check_arguments(query, update, calling_info).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
 
@@ -111,47 +116,64 @@ pub(crate) fn update(calling_info: &CallingInfo, query: &AssetMap, update: &Asse
     } else {
         common::add_owner_info(calling_info, &mut query_db_data);
     }
-    let mut update_db_data = common::into_db_map(update);
-
+    // The expected revision is checked against the Asset found, rather than used to find the Asset.
+    let expected_revision = query_db_data.remove(column::REVISION);
+    let mut update_db_data = DbMap::new();
     add_attrs(update, &mut update_db_data).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
+    // The secret is moved out after the attributes to update are inspected, and it is kept apart until encrypted.
+    let secret = common::take_secret(update).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
+    update_db_data.extend(common::into_db_map(update));
 
     let db_key = get_db_key_by_asset_map(calling_info.user_id(), query).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     let mut db = Database::build(calling_info, db_key).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
+    // The expired Asset is purged rather than updated.
+    db.delete_expired_datas_by_condition(&query_db_data).map_err(|e| macros_lib::track_error!(e,
+        macros_lib::hisysevent::function!()))?;
     let results = db.query_datas(&vec![], &query_db_data, None, true).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     if results.is_empty() {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::NotFound, "[FATAL]The asset to update is not found.");
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL]The asset to update is not found.");
     }
+    common::check_revision(expected_revision.as_ref(), &results[0])?;
+    let mut update_condition = query_db_data.clone();
+    common::add_revision_condition(&results[0], &mut update_condition);
+    common::add_next_revision(&results[0], &mut update_db_data);
 
-    if update.contains_key(&Tag::Secret) {
+    if let Some(secret) = secret {
         let mut results = db.query_datas(&vec![], &query_db_data, None, true).map_err(|e| macros_lib::track_error!(e,
             macros_lib::hisysevent::function!()))?;
         if results.len() != 1 {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::NotFound, "query to-be-updated asset failed, found [{}] assets", results.len());
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+                "query to-be-updated asset failed, found [{}] assets", results.len());
         }
 
         let result = results.get_mut(0).unwrap();
-        result.insert(column::SECRET, update[&Tag::Secret].clone());
 
         if common::need_upgrade(result).map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))? {
             upgrade_to_latest_version(result, &mut update_db_data);
         }
         // Using result with AAD to encrypt secret, otherwise encryption failed.
-        let cipher = encrypt(calling_info, result)?;
+        let cipher = encrypt(calling_info, result, &secret)?;
         update_db_data.insert(column::SECRET, Value::Bytes(cipher));
     }
 
-    // call sql to update
-    let update_num = db.update_datas(&query_db_data, true, &update_db_data).map_err(|e| macros_lib::track_error!(e,
+    // call sql to update, the revision in the condition ensures that the Asset is not modified after being queried.
+    let update_num = db.update_datas(&update_condition, true, &update_db_data).map_err(|e| macros_lib::track_error!(e,
         macros_lib::hisysevent::function!()))?;
     if update_num == 0 {
+        if db.is_data_exists(&query_db_data, true)? {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::RevisionConflict, reason = ErrReason::RevisionMismatch,
+                "[FATAL]Update asset failed, the asset has been modified concurrently.");
+        }
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::NotFound, "[FATAL]Update asset failed, update 0 asset.");
+            ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL]Update asset failed, update 0 asset.");
     } else {
         update_cloud_sync_status(calling_info, &results);
     }
@@ -20,12 +20,13 @@ use asset_db_operator::database_file_upgrade::construct_splited_db_name;
 use ipc::{parcel::MsgParcel, remote::RemoteStub, IpcResult, IpcStatusCode};
 
 use asset_ipc::{
-    deserialize_map, deserialize_maps, serialize_batch_result, serialize_maps,
-    serialize_sync_result, IpcCode, IPC_SUCCESS, SA_NAME,
+    deserialize_map, deserialize_maps, deserialize_operations, serialize_assets_with_secret,
+    serialize_batch_query_result, serialize_batch_result, serialize_capabilities, serialize_page, serialize_sync_result,
+    IpcCode, IPC_SUCCESS, MAX_BATCH_CHUNK_SIZE, SA_NAME,
 };
  use asset_sdk::{ 
     macros_lib,
-    AssetError, ErrCode, Result, Tag, Value, 
+    AssetError, AssetWithSecret, BatchItemResult, ErrCode, ErrReason, Result, Tag, Value, 
  };
 use asset_log::{loge, logi};
 use asset_plugin::asset_plugin::AssetPlugin;
@@ -50,24 +51,24 @@ impl RemoteStub for AssetService {
         if counter.lock().unwrap().is_stop() {
             loge!("[FATAL]Service is stop.");
             let _ = reply_handle(
-                Err(AssetError { 
-                    code: ErrCode::ServiceUnavailable, 
-                    msg: "service stop".to_string(),
-                    call_chain: AssetError::shorten_func_name(macros_lib::hisysevent::function!()).to_string(),
-                }),
+                Err(AssetError::new(
+                    ErrCode::ServiceUnavailable,
+                    "service stop".to_string(),
+                    macros_lib::hisysevent::function!(),
+                )),
                 reply,
             );
             return IPC_SUCCESS as i32;
         }
         let _counter_user = AutoCounter::new();
-        if !self.system_ability.cancel_idle() {
+        if !self.system_ability.as_ref().map_or(true, |handler| handler.cancel_idle()) {
             loge!("[FATAL]Cancel idle failed. Service is stop.");
             let _ = reply_handle(
-                Err(AssetError { 
-                    code: ErrCode::ServiceUnavailable, 
-                    msg: "service stop".to_string(),
-                    call_chain: AssetError::shorten_func_name(macros_lib::hisysevent::function!()).to_string(),
-                }),
+                Err(AssetError::new(
+                    ErrCode::ServiceUnavailable,
+                    "service stop".to_string(),
+                    macros_lib::hisysevent::function!(),
+                )),
                 reply,
             );
             return IPC_SUCCESS as i32;
@@ -77,7 +78,7 @@ impl RemoteStub for AssetService {
             return on_extension_request(self, code, data, reply);
         }
 
-        match on_remote_request(self, code, data, reply) {
+        match on_remote_request(self, code, data, reply, None) {
             Ok(_) => IPC_SUCCESS as i32,
             Err(e) => e as i32,
         }
@@ -89,7 +90,8 @@ impl RemoteStub for AssetService {
 }
 
 fn on_app_request(code: IpcCode, process_info: &ProcessInfo, calling_info: &CallingInfo) -> Result<()> {
-    if code as u32 > IpcCode::PostQuery as u32 {
+    let is_query = matches!(code, IpcCode::QueryPage | IpcCode::Count | IpcCode::Exists);
+    if code as u32 > IpcCode::PostQuery as u32 && !is_query {
         // No need to process upgrade event.
         return Ok(());
     }
@@ -118,52 +120,104 @@ fn on_app_request(code: IpcCode, process_info: &ProcessInfo, calling_info: &Call
     Ok(())
 }
 
+fn reply_batch_result(result: Result<Vec<BatchItemResult>>, reply: &mut MsgParcel) -> IpcResult<()> {
+    match result {
+        Ok(res) => {
+            reply_handle(Ok(()), reply)?;
+            serialize_batch_result(&res, reply).map_err(asset_err_handle)
+        },
+        Err(e) => reply_handle(Err(e), reply),
+    }
+}
+
+fn reply_batch_query_result(
+    result: Result<Vec<(BatchItemResult, AssetWithSecret)>>,
+    reply: &mut MsgParcel,
+) -> IpcResult<()> {
+    match result {
+        Ok(res) => {
+            reply_handle(Ok(()), reply)?;
+            serialize_batch_query_result(&res, reply).map_err(asset_err_handle)
+        },
+        Err(e) => reply_handle(Err(e), reply),
+    }
+}
+
 fn process_batch_data(
     stub: &AssetService,
     data: &mut MsgParcel,
     reply: &mut MsgParcel,
-    ipc_code: &IpcCode
+    ipc_code: &IpcCode,
+    injected_calling_info: Option<&CallingInfo>,
 ) -> IpcResult<()> {
-    let attributes_array = deserialize_maps(data).map_err(asset_err_handle)?;
+    let mut attributes_array = deserialize_maps(data).map_err(asset_err_handle)?;
     if attributes_array.is_empty() {
         match ipc_code {
             IpcCode::BatchUpdate | IpcCode::BatchAdd => return reply_handle(
                 macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                    ErrCode::InvalidArgument, "[FATAL]The array is empty."), reply),
-            _ => {return reply_handle(Ok(()), reply);}
+                    ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange, "[FATAL]The array is empty."), reply),
+            IpcCode::BatchQuery => return reply_batch_query_result(Ok(vec![]), reply),
+            _ => {return reply_batch_result(Ok(vec![]), reply);}
         }
     }
-    let map = &attributes_array[0];
-    let process_info = ProcessInfo::build(map.get(&Tag::GroupId), None, false).map_err(asset_err_handle)?;
-    let calling_info = CallingInfo::build(map.get(&Tag::UserId).cloned(), &process_info);
-    match ipc_code {
-        IpcCode::BatchAdd => {
-            match stub.batch_add(&calling_info, &attributes_array) {
-                Ok(res) => {
-                    reply_handle(Ok(()), reply)?;
-                    serialize_batch_result(&res, reply).map_err(asset_err_handle)
-                },
-                Err(e) => reply_handle(Err(e), reply),
-            }
-        },
-        IpcCode::BatchRemove => {
-            reply_handle(stub.batch_remove(&calling_info, &attributes_array), reply)
+    if attributes_array.len() as u32 > MAX_BATCH_CHUNK_SIZE {
+        return reply_handle(macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL]The array size[{}] exceeds the chunk size[{}].", attributes_array.len(), MAX_BATCH_CHUNK_SIZE),
+            reply);
+    }
+    let calling_info = match injected_calling_info {
+        Some(calling_info) => calling_info.clone(),
+        None => {
+            let map = &attributes_array[0];
+            let process_info = ProcessInfo::build(map.get(&Tag::GroupId), None, false).map_err(asset_err_handle)?;
+            CallingInfo::build(map.get(&Tag::UserId).cloned(), &process_info)
         },
+    };
+    match ipc_code {
+        IpcCode::BatchAdd => reply_batch_result(stub.batch_add(&calling_info, &mut attributes_array), reply),
+        IpcCode::BatchRemove => reply_batch_result(stub.batch_remove(&calling_info, &attributes_array), reply),
+        IpcCode::BatchQuery => reply_batch_query_result(stub.batch_query(&calling_info, &attributes_array), reply),
         IpcCode::BatchUpdate => {
-            let attributes_to_update_array = deserialize_maps(data).map_err(asset_err_handle)?;
-            match stub.batch_update(&calling_info, &attributes_array, &attributes_to_update_array) {
-                Ok(res) => {
-                    reply_handle(Ok(()), reply)?;
-                    serialize_batch_result(&res, reply).map_err(asset_err_handle)
-                },
-                Err(e) => reply_handle(Err(e), reply),
-            }
+            let mut attributes_to_update_array = deserialize_maps(data).map_err(asset_err_handle)?;
+            let results = stub.batch_update(&calling_info, &attributes_array, &mut attributes_to_update_array);
+            reply_batch_result(results, reply)
         },
         _ => {reply_handle(Ok(()), reply)}
     }
 }
 
-fn on_remote_request(stub: &AssetService, code: u32, data: &mut MsgParcel, reply: &mut MsgParcel) -> IpcResult<()> {
+fn process_transaction(
+    stub: &AssetService,
+    data: &mut MsgParcel,
+    reply: &mut MsgParcel,
+    injected_calling_info: Option<&CallingInfo>,
+) -> IpcResult<()> {
+    let mut operations = deserialize_operations(data).map_err(asset_err_handle)?;
+    if operations.is_empty() {
+        return reply_handle(macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL]The operation array is empty."), reply);
+    }
+    let calling_info = match injected_calling_info {
+        Some(calling_info) => calling_info.clone(),
+        None => {
+            let map = operations[0].attributes();
+            let process_info = ProcessInfo::build(map.get(&Tag::GroupId), None, false).map_err(asset_err_handle)?;
+            CallingInfo::build(map.get(&Tag::UserId).cloned(), &process_info)
+        },
+    };
+    reply_batch_result(stub.transact(&calling_info, &mut operations), reply)
+}
+
+// The calling info is injected by the loopback transport, otherwise it is built from the IPC caller.
+pub(crate) fn on_remote_request(
+    stub: &AssetService,
+    code: u32,
+    data: &mut MsgParcel,
+    reply: &mut MsgParcel,
+    injected_calling_info: Option<&CallingInfo>,
+) -> IpcResult<()> {
     match data.read_interface_token() {
         Ok(interface_token) if interface_token == stub.descriptor() => {},
         _ => {
@@ -173,26 +227,58 @@ fn on_remote_request(stub: &AssetService, code: u32, data: &mut MsgParcel, reply
     }
     let ipc_code = IpcCode::try_from(code).map_err(asset_err_handle)?;
     match ipc_code {
-        IpcCode::BatchAdd | IpcCode::BatchRemove | IpcCode::BatchUpdate => {
-            return process_batch_data(stub, data, reply, &ipc_code);
+        IpcCode::BatchAdd | IpcCode::BatchRemove | IpcCode::BatchUpdate | IpcCode::BatchQuery => {
+            return process_batch_data(stub, data, reply, &ipc_code, injected_calling_info);
+        },
+        IpcCode::Transact => return process_transaction(stub, data, reply, injected_calling_info),
+        IpcCode::GetCapabilities => {
+            reply_handle(Ok(()), reply)?;
+            return serialize_capabilities(&stub.get_capabilities(), reply).map_err(asset_err_handle);
         },
         _ => {}
     }
 
-    let map = deserialize_map(data).map_err(asset_err_handle)?;
-    let process_info = ProcessInfo::build(map.get(&Tag::GroupId), None, false).map_err(asset_err_handle)?;
-    let calling_info = CallingInfo::build(map.get(&Tag::UserId).cloned(), &process_info);
-    on_app_request(ipc_code, &process_info, &calling_info).map_err(asset_err_handle)?;
+    let mut map = deserialize_map(data).map_err(asset_err_handle)?;
+    let calling_info = match injected_calling_info {
+        Some(calling_info) => calling_info.clone(),
+        None => {
+            let process_info = ProcessInfo::build(map.get(&Tag::GroupId), None, false).map_err(asset_err_handle)?;
+            let calling_info = CallingInfo::build(map.get(&Tag::UserId).cloned(), &process_info);
+            on_app_request(ipc_code, &process_info, &calling_info).map_err(asset_err_handle)?;
 
-    let hap_info = construct_splited_db_name(&calling_info, false).map_err(asset_err_handle)?;
-    let _ = upgrade_single_clone_app_data(calling_info.user_id(), hap_info).map_err(asset_err_handle);
+            let hap_info = construct_splited_db_name(&calling_info, false).map_err(asset_err_handle)?;
+            let _ = upgrade_single_clone_app_data(calling_info.user_id(), hap_info).map_err(asset_err_handle);
+            calling_info
+        },
+    };
 
     match ipc_code {
-        IpcCode::Add => reply_handle(stub.add(&calling_info, &map), reply),
+        IpcCode::Add => reply_handle(stub.add(&calling_info, &mut map), reply),
         IpcCode::Remove => reply_handle(stub.remove(&calling_info, &map), reply),
         IpcCode::Update => {
+            let mut update_map = deserialize_map(data).map_err(asset_err_handle)?;
+            reply_handle(stub.update(&calling_info, &map, &mut update_map), reply)
+        },
+        IpcCode::Rename => {
             let update_map = deserialize_map(data).map_err(asset_err_handle)?;
-            reply_handle(stub.update(&calling_info, &map, &update_map), reply)
+            reply_handle(stub.rename(&calling_info, &map, &update_map), reply)
+        },
+        IpcCode::Transfer => {
+            let target = deserialize_map(data).map_err(asset_err_handle)?;
+            let keep_source = data.read::<bool>()?;
+            let target_calling_info = match injected_calling_info {
+                Some(calling_info) => calling_info.with_group_id(match target.get(&Tag::GroupId) {
+                    Some(Value::Bytes(group_id)) => Some(group_id.clone()),
+                    _ => None,
+                }),
+                None => {
+                    // The membership of the target group is checked while building the process info.
+                    let process_info =
+                        ProcessInfo::build(target.get(&Tag::GroupId), None, false).map_err(asset_err_handle)?;
+                    CallingInfo::build(map.get(&Tag::UserId).cloned(), &process_info)
+                },
+            };
+            reply_handle(stub.transfer(&calling_info, &map, &target_calling_info, &target, keep_source), reply)
         },
         IpcCode::PreQuery => match stub.pre_query(&calling_info, &map) {
             Ok(res) => {
@@ -204,7 +290,14 @@ fn on_remote_request(stub: &AssetService, code: u32, data: &mut MsgParcel, reply
         IpcCode::Query => match stub.query(&calling_info, &map) {
             Ok(res) => {
                 reply_handle(Ok(()), reply)?;
-                serialize_maps(&res, reply).map_err(asset_err_handle)
+                serialize_assets_with_secret(&res, reply).map_err(asset_err_handle)
+            },
+            Err(e) => reply_handle(Err(e), reply),
+        },
+        IpcCode::QueryPage => match stub.query_page(&calling_info, &map) {
+            Ok(res) => {
+                reply_handle(Ok(()), reply)?;
+                serialize_page(&res, reply).map_err(asset_err_handle)
             },
             Err(e) => reply_handle(Err(e), reply),
         },
@@ -216,6 +309,20 @@ fn on_remote_request(stub: &AssetService, code: u32, data: &mut MsgParcel, reply
             },
             Err(e) => reply_handle(Err(e), reply),
         },
+        IpcCode::Count => match stub.count(&calling_info, &map) {
+            Ok(count) => {
+                reply_handle(Ok(()), reply)?;
+                reply.write::<u32>(&count)
+            },
+            Err(e) => reply_handle(Err(e), reply),
+        },
+        IpcCode::Exists => match stub.exists(&calling_info, &map) {
+            Ok(exists) => {
+                reply_handle(Ok(()), reply)?;
+                reply.write::<bool>(&exists)
+            },
+            Err(e) => reply_handle(Err(e), reply),
+        },
         _ => {reply_handle(Ok(()), reply)}
     }
 }
@@ -246,7 +353,8 @@ fn reply_handle(ret: Result<()>, reply: &mut MsgParcel) -> IpcResult<()> {
         Ok(_) => reply.write::<u32>(&IPC_SUCCESS),
         Err(e) => {
             reply.write::<u32>(&(e.code as u32))?;
-            reply.write::<String>(&e.msg)
+            reply.write::<String>(&e.msg)?;
+            reply.write::<u32>(&(e.reason as u32))
         },
     }
 }
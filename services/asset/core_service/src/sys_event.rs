@@ -259,7 +259,7 @@ pub(crate) fn upload_fault_system_event(
     params.insert(PARAM_NAME_USER_ID, Value::Number(calling_info.user_id() as u32));
     params.insert(PARAM_NAME_CALLER, Value::Bytes(owner_info.as_bytes().to_vec()));
     params.insert(PARAM_NAME_ERROR_CODE, Value::Bytes(e.code.to_string().as_bytes().to_vec()));
-    params.insert(PARAM_NAME_CALL_STACK, Value::Bytes(e.call_chain.as_bytes().to_vec()));
+    params.insert(PARAM_NAME_CALL_STACK, Value::Bytes(e.call_chain().as_bytes().to_vec()));
     report_ha_event(params);
 
     loge!(
@@ -299,12 +299,12 @@ pub(crate) fn upload_system_event<T: IsArray>(
     result
 }
 
-pub(crate) fn upload_batch_system_event<T: IsArray>(
+pub(crate) fn upload_batch_system_event<T: IsArray, A>(
     result: Result<T>,
     calling_info: &CallingInfo,
     start_time: Instant,
     func_name: &str,
-    attributes: &[AssetMap],
+    attributes: &[A],
 ) -> Result<T> {
     let mut ext_info = format!("batch count: {}", attributes.len());
     match &result {
@@ -64,11 +64,11 @@ fn remove_db(path: &str) -> Result<()> {
             Err(e) if e.kind() == ErrorKind::NotFound => (),
             Err(e) => {
                 logw!("[WARNING]Remove db:[{}] failed, error code:[{}]", path, e);
-                res = Err(AssetError { 
-                    code: ErrCode::DatabaseError, 
-                    msg: "rmove file failed".to_string(),
-                    call_chain: AssetError::shorten_func_name(macros_lib::hisysevent::function!()).to_string(),
-                })
+                res = Err(AssetError::new(
+                    ErrCode::DatabaseError,
+                    "rmove file failed".to_string(),
+                    macros_lib::hisysevent::function!(),
+                ).with_source(e))
             },
         };
     }
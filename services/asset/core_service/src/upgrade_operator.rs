@@ -20,8 +20,7 @@ use std::{ffi::CString, collections::HashSet};
 use std::os::raw::c_char;
 
 use asset_common::{CallingInfo, OwnerType, SUCCESS};
-use asset_crypto_manager::secret_key::SecretKey;
-use asset_definition::{macros_lib, Accessibility, AuthType, ErrCode, Extension, Result, Value};
+use asset_definition::{macros_lib, Accessibility, ErrCode, Extension, Result, Value};
 use asset_plugin::asset_plugin::AssetPlugin;
 use asset_plugin_interface::plugin_interface::{
     EventType, ExtDbMap, PARAM_NAME_AAD, PARAM_NAME_ACCESSIBILITY, PARAM_NAME_APP_INDEX, PARAM_NAME_CIPHER,
@@ -222,12 +221,9 @@ fn clone_single_app(user_id: i32, app_name: &str, app_index: i32, datas: &mut Ve
 }
 
 fn unwrap_and_insert(user_id: i32, unwrap_info: UnwrapInfo, db_clone: &mut Database) -> Result<()> {
-    let auth_type = unwrap_info.data.get_enum_attr::<AuthType>(&column::AUTH_TYPE)?;
     let accessibility = unwrap_info.data.get_enum_attr::<Accessibility>(&column::ACCESSIBILITY)?;
-    let required_password_set = unwrap_info.data.get_bool_attr(&column::REQUIRE_PASSWORD_SET)?;
-    let secret_key = SecretKey::new_without_alias(unwrap_info.calling_info, auth_type, accessibility, required_password_set)?;
-    let new_secret_key =
-        SecretKey::new_without_alias(unwrap_info.calling_info_new, auth_type, accessibility, required_password_set)?;
+    let secret_key = common::build_secret_key(unwrap_info.calling_info, unwrap_info.data)?;
+    let new_secret_key = common::build_secret_key(unwrap_info.calling_info_new, unwrap_info.data)?;
     let _ = generate_secret_key_if_needed(&new_secret_key);
     let _ = generate_secret_key_if_needed(&secret_key);
     let secret = unwrap_info.data.get_bytes_attr(&column::SECRET)?;
@@ -18,14 +18,20 @@
 use std::time::Instant;
 
 use asset_common::{transfer_error_code, CallingInfo, SUCCESS};
-use asset_definition::{macros_lib, ErrCode, Result};
+use asset_definition::{macros_lib, ErrCode, ErrReason, Result, SecretBytes};
 
 use crate::{secret_key::SecretKey, HksBlob, KeyId, OutBlob};
 
 extern "C" {
     fn EncryptData(keyId: *const KeyId, aad: *const HksBlob, in_data: *const HksBlob, out_data: *mut OutBlob) -> i32;
     fn DecryptData(keyId: *const KeyId, aad: *const HksBlob, in_data: *const HksBlob, out_data: *mut OutBlob) -> i32;
-    fn InitKey(keyId: *const KeyId, valid_time: u32, challenge: *mut OutBlob, handle: *mut OutBlob) -> i32;
+    fn InitKey(
+        keyId: *const KeyId,
+        auth_type: u32,
+        valid_time: u32,
+        challenge: *mut OutBlob,
+        handle: *mut OutBlob,
+    ) -> i32;
     fn ExecCrypt(
         handle: *const HksBlob,
         aad: *const HksBlob,
@@ -74,6 +80,7 @@ impl Crypto {
         let ret = unsafe {
             InitKey(
                 &key_id as *const KeyId,
+                self.key.auth_type() as u32,
                 self.valid_time,
                 &mut challenge as *mut OutBlob,
                 &mut handle as *mut OutBlob,
@@ -86,18 +93,18 @@ impl Crypto {
     }
 
     /// Decrypt data that requires user authentication.
-    pub fn exec_crypt(&self, cipher: &Vec<u8>, aad: &Vec<u8>, auth_token: &Vec<u8>) -> Result<Vec<u8>> {
+    pub fn exec_crypt(&self, cipher: &Vec<u8>, aad: &Vec<u8>, auth_token: &Vec<u8>) -> Result<SecretBytes> {
         if cipher.len() <= (TAG_SIZE + NONCE_SIZE) {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]The cipher length is too short.");
+                ErrCode::InvalidArgument, reason = ErrReason::MalformedValue, "[FATAL]The cipher length is too short.");
         }
 
         let aad = HksBlob { size: aad.len() as u32, data: aad.as_ptr() };
         let auth_token = HksBlob { size: auth_token.len() as u32, data: auth_token.as_ptr() };
         let handle = HksBlob { size: self.handle.len() as u32, data: self.handle.as_ptr() };
         let in_data = HksBlob { size: cipher.len() as u32, data: cipher.as_ptr() };
-        let mut msg: Vec<u8> = vec![0; cipher.len() - TAG_SIZE - NONCE_SIZE];
-        let mut out_data = OutBlob { size: msg.len() as u32, data: msg.as_mut_ptr() };
+        let mut msg = SecretBytes::zeroed(cipher.len() - TAG_SIZE - NONCE_SIZE);
+        let mut out_data = OutBlob { size: msg.len() as u32, data: msg.as_mut_slice().as_mut_ptr() };
 
         let ret = unsafe {
             ExecCrypt(
@@ -115,7 +122,7 @@ impl Crypto {
     }
 
     /// Encrypt data at one-time.
-    pub fn encrypt(key: &SecretKey, msg: &Vec<u8>, aad: &Vec<u8>) -> Result<Vec<u8>> {
+    pub fn encrypt(key: &SecretKey, msg: &[u8], aad: &Vec<u8>) -> Result<Vec<u8>> {
         let mut cipher: Vec<u8> = vec![0; msg.len() + TAG_SIZE + NONCE_SIZE];
         let key_alias = HksBlob { size: key.alias().len() as u32, data: key.alias().as_ptr() };
         let aad_data = HksBlob { size: aad.len() as u32, data: aad.as_ptr() };
@@ -138,17 +145,17 @@ impl Crypto {
     }
 
     /// Decrypt data at one-time.
-    pub fn decrypt(key: &SecretKey, cipher: &Vec<u8>, aad: &Vec<u8>) -> Result<Vec<u8>> {
+    pub fn decrypt(key: &SecretKey, cipher: &Vec<u8>, aad: &Vec<u8>) -> Result<SecretBytes> {
         if cipher.len() <= (TAG_SIZE + NONCE_SIZE) {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::DataCorrupted, "[FATAL]The cipher length is too short.");
+                ErrCode::DataCorrupted, reason = ErrReason::MalformedValue, "[FATAL]The cipher length is too short.");
         }
 
-        let mut plain: Vec<u8> = vec![0; cipher.len() - TAG_SIZE - NONCE_SIZE];
+        let mut plain = SecretBytes::zeroed(cipher.len() - TAG_SIZE - NONCE_SIZE);
         let key_alias = HksBlob { size: key.alias().len() as u32, data: key.alias().as_ptr() };
         let aad_data = HksBlob { size: aad.len() as u32, data: aad.as_ptr() };
         let in_data = HksBlob { size: cipher.len() as u32, data: cipher.as_ptr() };
-        let mut out_data = OutBlob { size: plain.len() as u32, data: plain.as_mut_ptr() };
+        let mut out_data = OutBlob { size: plain.len() as u32, data: plain.as_mut_slice().as_mut_ptr() };
         let key_id = KeyId::new(key.user_id(), key_alias, key.access_type());
 
         let ret = unsafe {
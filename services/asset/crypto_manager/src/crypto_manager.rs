@@ -22,7 +22,7 @@ use std::{
 };
 
 use asset_common::CallingInfo;
-use asset_definition::{macros_lib, ErrCode, Result};
+use asset_definition::{macros_lib, ErrCode, ErrReason, Result};
 use asset_log::logw;
 
 use crate::crypto::Crypto;
@@ -60,13 +60,15 @@ impl CryptoManager {
         let total: usize = self.cryptos.values().map(Vec::len).sum();
         if total >= CRYPTO_TOTAL_CAPACITY {
             macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::LimitExceeded, "The total number of cryptos exceeds the upper limit.")
+                ErrCode::LimitExceeded, reason = ErrReason::ChallengeLimitExceeded,
+                "The total number of cryptos exceeds the upper limit.")
         } else {
             let owner_info = crypto.calling_info().owner_info().clone();
             let bucket_len = self.cryptos.get(&owner_info).map_or(0, Vec::len);
             if bucket_len >= CRYPTO_CAPACITY {
                 macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                    ErrCode::LimitExceeded, "The number of cryptos per application exceeds the upper limit.")
+                    ErrCode::LimitExceeded, reason = ErrReason::ChallengeLimitExceeded,
+                    "The number of cryptos per application exceeds the upper limit.")
             } else {
                 self.cryptos.entry(owner_info).or_default().push(crypto);
                 Ok(())
@@ -85,10 +87,12 @@ impl CryptoManager {
                     }
                 }
                 macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                    ErrCode::NotFound, "The crypto expires or does not exist. Call the preQuery first.")
+                    ErrCode::NotFound, reason = ErrReason::ChallengeNotFound,
+                    "The crypto expires or does not exist. Call the preQuery first.")
             },
             None => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::NotFound, "The crypto expires or does not exist. Call the preQuery first."),
+                ErrCode::NotFound, reason = ErrReason::ChallengeNotFound,
+                "The crypto expires or does not exist. Call the preQuery first."),
         }
     }
 
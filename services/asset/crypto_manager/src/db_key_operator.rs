@@ -114,7 +114,7 @@ impl DbKey {
         let aad: Vec<u8> = TRIVIAL_AAD_FOR_DB_KEY.as_bytes().to_vec();
         let db_key = Crypto::decrypt(&secret_key, db_key_cipher, &aad)
             .map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))?;
-        Ok(Self { db_key })
+        Ok(Self { db_key: db_key.to_vec() })
     }
 
     fn generate_db_key() -> Result<DbKey> {
@@ -34,6 +34,21 @@ static enum HksAuthStorageLevel AccessibilityToHksAuthStorageLevel(enum Accessib
     }
 }
 
+static uint32_t AuthTypeToHksUserAuthType(uint32_t authType)
+{
+    uint32_t hksAuthType = 0;
+    if ((authType & AUTH_TYPE_PIN) != 0) {
+        hksAuthType |= HKS_USER_AUTH_TYPE_PIN;
+    }
+    if ((authType & AUTH_TYPE_FINGERPRINT) != 0) {
+        hksAuthType |= HKS_USER_AUTH_TYPE_FINGERPRINT;
+    }
+    if ((authType & AUTH_TYPE_FACE) != 0) {
+        hksAuthType |= HKS_USER_AUTH_TYPE_FACE;
+    }
+    return hksAuthType;
+}
+
 static int32_t HuksErrorTransfer(int32_t ret)
 {
     switch (ret) {
@@ -52,6 +67,8 @@ static int32_t HuksErrorTransfer(int32_t ret)
             return ASSET_ACCESS_DENIED;
         case HKS_ERROR_CRYPTO_ENGINE_ERROR:
             return ASSET_DATA_CORRUPTED;
+        case HKS_ERROR_KEY_AUTH_PERMANENTLY_INVALIDATED:
+            return ASSET_KEY_INVALIDATED;
         default:
             return ASSET_CRYPTO_ERROR;
     }
@@ -114,20 +131,21 @@ static int32_t AddCommonGenParams(struct HksParamSet *paramSet, const struct Key
     return HksAddParams(paramSet, commonParams, ARRAY_SIZE(commonParams));
 }
 
-static int32_t AddAuthGenParams(struct HksParamSet *paramSet)
+static int32_t AddAuthGenParams(struct HksParamSet *paramSet, uint32_t authType, bool invalidateOnBioChange)
 {
+    uint32_t accessType = invalidateOnBioChange ? HKS_AUTH_ACCESS_INVALID_NEW_BIO_ENROLL : HKS_AUTH_ACCESS_ALWAYS_VALID;
     struct HksParam authParams[] = {
         { .tag = HKS_TAG_KEY_AUTH_PURPOSE, .uint32Param = HKS_KEY_PURPOSE_DECRYPT },
-        { .tag = HKS_TAG_KEY_AUTH_ACCESS_TYPE, .uint32Param = HKS_AUTH_ACCESS_ALWAYS_VALID },
+        { .tag = HKS_TAG_KEY_AUTH_ACCESS_TYPE, .uint32Param = accessType },
         { .tag = HKS_TAG_BATCH_PURPOSE, .uint32Param = HKS_KEY_PURPOSE_DECRYPT },
         { .tag = HKS_TAG_CHALLENGE_TYPE, .uint32Param = HKS_CHALLENGE_TYPE_NORMAL },
-        { .tag = HKS_TAG_USER_AUTH_TYPE, .uint32Param =
-            HKS_USER_AUTH_TYPE_FINGERPRINT | HKS_USER_AUTH_TYPE_FACE | HKS_USER_AUTH_TYPE_PIN }
+        { .tag = HKS_TAG_USER_AUTH_TYPE, .uint32Param = AuthTypeToHksUserAuthType(authType) }
     };
     return HksAddParams(paramSet, authParams, ARRAY_SIZE(authParams));
 }
 
-int32_t GenerateKey(const struct KeyId *keyId, bool needAuth, bool requirePasswordSet)
+int32_t GenerateKey(const struct KeyId *keyId, uint32_t authType, bool requirePasswordSet,
+    bool invalidateOnBioChange)
 {
     struct HksParamSet *paramSet = NULL;
     int32_t ret = HKS_SUCCESS;
@@ -152,8 +170,8 @@ int32_t GenerateKey(const struct KeyId *keyId, bool needAuth, bool requirePasswo
             }
         }
 
-        if (needAuth) {
-            ret = AddAuthGenParams(paramSet);
+        if (authType != AUTH_TYPE_NONE) {
+            ret = AddAuthGenParams(paramSet, authType, invalidateOnBioChange);
             if (ret != HKS_SUCCESS) {
                 LOGE("[FATAL]HUKS add auth params failed. error=%{public}d", ret);
                 break;
@@ -217,6 +235,36 @@ int32_t IsKeyExist(const struct KeyId *keyId)
     return HuksErrorTransfer(ret);
 }
 
+int32_t CheckKeyValidity(const struct KeyId *keyId, uint32_t authType)
+{
+    struct HksParam initParams[] = {
+        { .tag = HKS_TAG_ALGORITHM, .uint32Param = HKS_ALG_AES},
+        { .tag = HKS_TAG_PURPOSE, .uint32Param = HKS_KEY_PURPOSE_DECRYPT },
+        { .tag = HKS_TAG_KEY_SIZE, .uint32Param = HKS_AES_KEY_SIZE_256 },
+        { .tag = HKS_TAG_IS_BATCH_OPERATION, .boolParam = true },
+        { .tag = HKS_TAG_USER_AUTH_TYPE, .uint32Param = AuthTypeToHksUserAuthType(authType) },
+        { .tag = HKS_TAG_AUTH_STORAGE_LEVEL, .uint32Param = AccessibilityToHksAuthStorageLevel(keyId->accessibility) },
+    };
+    struct HksParamSet *paramSet = NULL;
+    int32_t ret = BuildParamSet(&paramSet, initParams, ARRAY_SIZE(initParams), keyId->userId);
+    if (ret != HKS_SUCCESS) {
+        return HuksErrorTransfer(ret);
+    }
+
+    uint8_t challenge[CHALLENGE_SIZE] = { 0 };
+    struct HksBlob challengeBlob = { CHALLENGE_SIZE, challenge };
+    uint8_t handle[sizeof(uint64_t)] = { 0 };
+    struct HksBlob handleBlob = { sizeof(uint64_t), handle };
+    ret = HksInit(&keyId->alias, paramSet, &handleBlob, &challengeBlob);
+    if (ret == HKS_SUCCESS) {
+        (void)HksAbort(&handleBlob, paramSet);
+    } else {
+        LOGE("[FATAL]HUKS check key validity failed. error=%{public}d", ret);
+    }
+    HksFreeParamSet(&paramSet);
+    return HuksErrorTransfer(ret);
+}
+
 int32_t EncryptData(const struct KeyId *keyId, const struct HksBlob *aad, const struct HksBlob *inData,
     struct HksBlob *outData)
 {
@@ -294,7 +342,8 @@ int32_t DecryptData(const struct KeyId *keyId, const struct HksBlob *aad, const
     return HuksErrorTransfer(ret);
 }
 
-int32_t InitKey(const struct KeyId *keyId, uint32_t validTime, struct HksBlob *challenge, struct HksBlob *handle)
+int32_t InitKey(const struct KeyId *keyId, uint32_t authType, uint32_t validTime, struct HksBlob *challenge,
+    struct HksBlob *handle)
 {
     struct HksParam initParams[] = {
         { .tag = HKS_TAG_ALGORITHM, .uint32Param = HKS_ALG_AES},
@@ -302,6 +351,7 @@ int32_t InitKey(const struct KeyId *keyId, uint32_t validTime, struct HksBlob *c
         { .tag = HKS_TAG_KEY_SIZE, .uint32Param = HKS_AES_KEY_SIZE_256 },
         { .tag = HKS_TAG_IS_BATCH_OPERATION, .boolParam = true },
         { .tag = HKS_TAG_BATCH_OPERATION_TIMEOUT, .uint32Param = validTime },
+        { .tag = HKS_TAG_USER_AUTH_TYPE, .uint32Param = AuthTypeToHksUserAuthType(authType) },
         { .tag = HKS_TAG_AUTH_STORAGE_LEVEL, .uint32Param = AccessibilityToHksAuthStorageLevel(keyId->accessibility) },
     };
     struct HksParamSet *paramSet = NULL;
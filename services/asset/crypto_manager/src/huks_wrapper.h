@@ -29,6 +29,8 @@ static const uint32_t NONCE_SIZE = 12;
 
 #define ARRAY_SIZE(arr) ((sizeof(arr)) / (sizeof((arr)[0])))
 #define ASSET_ROOT_USER_UPPERBOUND 99
+#define ASSET_KEY_INVALIDATED 24000021
+#define CHALLENGE_SIZE 32
 
 enum Accessibility {
     DEVICE_POWERED_ON = 0,
@@ -36,20 +38,30 @@ enum Accessibility {
     DEVICE_UNLOCKED = 2,
 };
 
+enum AuthType {
+    AUTH_TYPE_NONE = 0x00,
+    AUTH_TYPE_PIN = 0x01,
+    AUTH_TYPE_FINGERPRINT = 0x02,
+    AUTH_TYPE_FACE = 0x04,
+};
+
 struct KeyId {
     int32_t userId;
     struct HksBlob alias;
     enum Accessibility accessibility;
 };
 
-int32_t GenerateKey(const struct KeyId *keyId, bool needAuth, bool requirePasswordSet);
+int32_t GenerateKey(const struct KeyId *keyId, uint32_t authType, bool requirePasswordSet,
+    bool invalidateOnBioChange);
 int32_t DeleteKey(const struct KeyId *keyId);
 int32_t IsKeyExist(const struct KeyId *keyId);
+int32_t CheckKeyValidity(const struct KeyId *keyId, uint32_t authType);
 int32_t EncryptData(const struct KeyId *keyId, const struct HksBlob *aad, const struct HksBlob *inData,
     struct HksBlob *outData);
 int32_t DecryptData(const struct KeyId *keyId, const struct HksBlob *aad, const struct HksBlob *inData,
     struct HksBlob *outData);
-int32_t InitKey(const struct KeyId *keyId, uint32_t validTime, struct HksBlob *challenge, struct HksBlob *handle);
+int32_t InitKey(const struct KeyId *keyId, uint32_t authType, uint32_t validTime, struct HksBlob *challenge,
+    struct HksBlob *handle);
 int32_t ExecCrypt(const struct HksBlob *handle, const struct HksBlob *aad, const struct HksBlob *authToken,
     const struct HksBlob *inData, struct HksBlob *outData);
 int32_t Drop(const struct HksBlob *handle);
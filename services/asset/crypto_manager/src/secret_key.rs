@@ -16,7 +16,7 @@
 //! This module is used to implement cryptographic algorithm operations, including key generation.
 
 use asset_common::{transfer_error_code, CallingInfo, SUCCESS};
-use asset_definition::{macros_lib, Accessibility, AuthType, ErrCode, Result};
+use asset_definition::{macros_lib, Accessibility, AuthType, ErrCode, KeyStatus, Result};
 use asset_log::{loge, logi};
 use asset_utils::hasher;
 
@@ -28,6 +28,7 @@ pub struct SecretKey {
     auth_type: AuthType,
     access_type: Accessibility,
     require_password_set: bool,
+    invalidate_on_bio_change: bool,
     alias: Vec<u8>,
     user_id: i32,
 }
@@ -40,14 +41,18 @@ enum KeyAliasVersion {
 }
 
 extern "C" {
-    fn GenerateKey(keyId: *const KeyId, need_auth: bool, require_password_set: bool) -> i32;
+    fn GenerateKey(keyId: *const KeyId, auth_type: u32, require_password_set: bool, invalidate_on_bio_change: bool)
+        -> i32;
     fn DeleteKey(keyId: *const KeyId) -> i32;
     fn IsKeyExist(keyId: *const KeyId) -> i32;
+    fn CheckKeyValidity(keyId: *const KeyId, auth_type: u32) -> i32;
     fn RenameKeyAlias(keyId: *const KeyId, newKeyAlias: *const HksBlob) -> i32;
 }
 
 const MAX_ALIAS_SIZE: usize = 64;
 const ALIAS_PREFIX: [u8; 2] = [b'1', b'_'];
+const AUTH_TYPES: [AuthType; 6] =
+    [AuthType::None, AuthType::Pin, AuthType::Fingerprint, AuthType::Face, AuthType::Biometric, AuthType::Any];
 
 fn append_attr<T>(tag: &str, value: T, vec: &mut Vec<u8>)
 where
@@ -67,6 +72,7 @@ fn calculate_key_alias(
     auth_type: AuthType,
     access_type: Accessibility,
     require_password_set: bool,
+    invalidate_on_bio_change: bool,
     standard: bool,
 ) -> Vec<u8> {
     let mut alias: Vec<u8> = Vec::with_capacity(MAX_ALIAS_SIZE);
@@ -83,6 +89,7 @@ fn calculate_key_alias(
     append_attr::<AuthType>("AuthType", auth_type, &mut alias);
     append_attr::<Accessibility>("Accessibility", access_type, &mut alias);
     append_attr::<bool>("RequirePasswordSet", require_password_set, &mut alias);
+    append_attr::<bool>("InvalidateOnBiometricChange", invalidate_on_bio_change, &mut alias);
     hasher::sha256(standard, &alias)
 }
 
@@ -92,13 +99,14 @@ fn get_existing_key_alias(
     access_type: Accessibility,
     require_password_set: bool,
 ) -> Result<KeyAliasVersion> {
-    let new_alias = calculate_key_alias(calling_info, auth_type, access_type, require_password_set, true);
+    let new_alias = calculate_key_alias(calling_info, auth_type, access_type, require_password_set, false, true);
     let prefixed_new_alias = [ALIAS_PREFIX.to_vec(), new_alias.clone()].concat();
     let key = SecretKey {
         user_id: calling_info.user_id(),
         auth_type,
         access_type,
         require_password_set,
+        invalidate_on_bio_change: false,
         alias: prefixed_new_alias.clone(),
     };
     if key.exists().map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))? {
@@ -111,6 +119,7 @@ fn get_existing_key_alias(
         auth_type,
         access_type,
         require_password_set,
+        invalidate_on_bio_change: false,
         alias: new_alias.clone(),
     };
     if key.exists().map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))? {
@@ -118,12 +127,13 @@ fn get_existing_key_alias(
         return Ok(KeyAliasVersion::V2(new_alias));
     }
 
-    let old_alias = calculate_key_alias(calling_info, auth_type, access_type, require_password_set, false);
+    let old_alias = calculate_key_alias(calling_info, auth_type, access_type, require_password_set, false, false);
     let key = SecretKey {
         user_id: calling_info.user_id(),
         auth_type,
         access_type,
         require_password_set,
+        invalidate_on_bio_change: false,
         alias: old_alias.clone(),
     };
     if key.exists().map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))? {
@@ -147,7 +157,7 @@ fn huks_rename_key_alias(
     let key_id = KeyId::new(calling_info.user_id(), alias_blob, access_type);
 
     // Prepare secret key alias to be replaced in.
-    let new_alias = calculate_key_alias(calling_info, auth_type, access_type, require_password_set, true);
+    let new_alias = calculate_key_alias(calling_info, auth_type, access_type, require_password_set, false, true);
     let prefixed_new_alias = [ALIAS_PREFIX.to_vec(), new_alias].concat();
     let prefixed_new_alias_ref = &prefixed_new_alias;
     let prefixed_new_alias_blob =
@@ -198,7 +208,7 @@ impl SecretKey {
         require_password_set: bool,
         alias: Vec<u8>,
     ) -> Result<Self> {
-        Ok(Self { user_id, auth_type, access_type, require_password_set, alias })
+        Ok(Self { user_id, auth_type, access_type, require_password_set, invalidate_on_bio_change: false, alias })
     }
 
     /// Calculate key alias and then new a secret key.
@@ -208,13 +218,32 @@ impl SecretKey {
         access_type: Accessibility,
         require_password_set: bool,
     ) -> Result<Self> {
-        let new_alias = calculate_key_alias(calling_info, auth_type, access_type, require_password_set, true);
+        Self::new_with_bio_policy(calling_info, auth_type, access_type, require_password_set, false)
+    }
+
+    /// Calculate key alias and then new a secret key, which may be invalidated when the biometric enrollment changes.
+    pub fn new_with_bio_policy(
+        calling_info: &CallingInfo,
+        auth_type: AuthType,
+        access_type: Accessibility,
+        require_password_set: bool,
+        invalidate_on_bio_change: bool,
+    ) -> Result<Self> {
+        let new_alias = calculate_key_alias(
+            calling_info,
+            auth_type,
+            access_type,
+            require_password_set,
+            invalidate_on_bio_change,
+            true,
+        );
         let prefixed_new_alias = [ALIAS_PREFIX.to_vec(), new_alias.clone()].concat();
         let key = Self {
             user_id: calling_info.user_id(),
             auth_type,
             access_type,
             require_password_set,
+            invalidate_on_bio_change,
             alias: prefixed_new_alias,
         };
         Ok(key)
@@ -236,13 +265,39 @@ impl SecretKey {
     pub fn generate(&self) -> Result<()> {
         let key_alias = HksBlob { size: self.alias.len() as u32, data: self.alias.as_ptr() };
         let key_id = KeyId::new(self.user_id, key_alias, self.access_type);
-        let ret = unsafe { GenerateKey(&key_id as *const KeyId, self.need_user_auth(), self.require_password_set) };
+        let ret = unsafe {
+            GenerateKey(
+                &key_id as *const KeyId,
+                self.auth_type as u32,
+                self.require_password_set,
+                self.invalidate_on_bio_change,
+            )
+        };
         match ret {
             SUCCESS => Ok(()),
             _ => Err(transfer_error_code(ErrCode::try_from(ret as u32)?)),
         }
     }
 
+    /// Get the status of the secret key, the auth-bound key is checked by initializing a decryption session.
+    pub fn status(&self) -> Result<KeyStatus> {
+        if !self.exists().map_err(|e| macros_lib::track_error!(e, macros_lib::hisysevent::function!()))? {
+            return Ok(KeyStatus::Missing);
+        }
+        if self.auth_type == AuthType::None {
+            return Ok(KeyStatus::Valid);
+        }
+
+        let key_alias = HksBlob { size: self.alias.len() as u32, data: self.alias.as_ptr() };
+        let key_id = KeyId::new(self.user_id, key_alias, self.access_type);
+        let ret = unsafe { CheckKeyValidity(&key_id as *const KeyId, self.auth_type as u32) };
+        match ret {
+            SUCCESS => Ok(KeyStatus::Valid),
+            ret if ret == ErrCode::KeyInvalidated as i32 => Ok(KeyStatus::Invalidated),
+            _ => Err(transfer_error_code(ErrCode::try_from(ret as u32)?)),
+        }
+    }
+
     /// Delete the secret key.
     pub fn delete(&self) -> Result<()> {
         let key_alias = HksBlob { size: self.alias.len() as u32, data: self.alias.as_ptr() };
@@ -260,31 +315,28 @@ impl SecretKey {
         let accessibilitys =
             [Accessibility::DevicePowerOn, Accessibility::DeviceFirstUnlocked, Accessibility::DeviceUnlocked];
         for accessibility in accessibilitys.into_iter() {
-            let secret_key = SecretKey::new_without_alias(calling_info, AuthType::None, accessibility, true)?;
-            let tmp = secret_key.delete();
-            res = if tmp.is_err() { tmp } else { res };
-
-            let secret_key = SecretKey::new_without_alias(calling_info, AuthType::Any, accessibility, true)?;
-            let tmp = secret_key.delete();
-            res = if tmp.is_err() { tmp } else { res };
-
-            let secret_key = SecretKey::new_without_alias(calling_info, AuthType::None, accessibility, false)?;
-            let tmp = secret_key.delete();
-            res = if tmp.is_err() { tmp } else { res };
-
-            let secret_key = SecretKey::new_without_alias(calling_info, AuthType::Any, accessibility, false)?;
-            let tmp = secret_key.delete();
-            res = if tmp.is_err() { tmp } else { res };
+            for auth_type in AUTH_TYPES.into_iter() {
+                let bio_policies: &[bool] =
+                    if (auth_type as u32 & AuthType::Biometric as u32) != 0 { &[false, true] } else { &[false] };
+                for invalidate_on_bio_change in bio_policies {
+                    for require_password_set in [true, false] {
+                        let secret_key = SecretKey::new_with_bio_policy(
+                            calling_info,
+                            auth_type,
+                            accessibility,
+                            require_password_set,
+                            *invalidate_on_bio_change,
+                        )?;
+                        let tmp = secret_key.delete();
+                        res = if tmp.is_err() { tmp } else { res };
+                    }
+                }
+            }
         }
 
         res
     }
 
-    /// Determine whether user auth is required.
-    pub(crate) fn need_user_auth(&self) -> bool {
-        self.auth_type == AuthType::Any
-    }
-
     /// Determine whether device unlock is required.
     pub(crate) fn need_device_unlock(&self) -> bool {
         self.access_type == Accessibility::DeviceUnlocked
@@ -300,6 +352,11 @@ impl SecretKey {
         self.access_type
     }
 
+    /// Get the key auth type.
+    pub(crate) fn auth_type(&self) -> AuthType {
+        self.auth_type
+    }
+
     /// Get the key user id.
     pub(crate) fn user_id(&self) -> i32 {
         self.user_id
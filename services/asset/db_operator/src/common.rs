@@ -20,7 +20,8 @@ mod permission_check;
 mod operation_add_common;
 
 pub use argument_check::{
-    check_group_validity, check_required_tags, check_tag_validity, check_value_validity, MAX_LABEL_SIZE,
+    check_group_validity, check_required_tags, check_tag_validity, check_value_validity, ENUM_TAG_VALUES,
+    MAX_LABEL_SIZE, MAX_RETURN_LIMIT, MAX_SECRET_SIZE,
 };
 pub use permission_check::check_system_permission;
 pub use operation_add_common::*;
@@ -28,12 +29,14 @@ pub use operation_add_common::*;
 use asset_common::CallingInfo;
 use asset_crypto_manager::secret_key::SecretKey;
 use asset_definition::{
-    macros_lib, Accessibility, AssetMap, AuthType, ErrCode, Extension, Result, Tag, Value, WrapType,
+    macros_lib, Accessibility, AssetMap, AuthType, CustomLabel, ErrCode, ErrReason, Extension, Result, SecretBytes, Tag,
+    Value, WrapType,
 };
+use asset_utils::time;
 
 use crate::types::{column, DbMap, DB_DATA_VERSION};
 
-pub(crate) const TAG_COLUMN_TABLE: [(Tag, &str); 21] = [
+pub(crate) const TAG_COLUMN_TABLE: [(Tag, &str); 31] = [
     (Tag::Secret, column::SECRET),
     (Tag::Alias, column::ALIAS),
     (Tag::Accessibility, column::ACCESSIBILITY),
@@ -55,9 +58,19 @@ pub(crate) const TAG_COLUMN_TABLE: [(Tag, &str); 21] = [
     (Tag::DataLabelNormalLocal3, column::NORMAL_LOCAL3),
     (Tag::DataLabelNormalLocal4, column::NORMAL_LOCAL4),
     (Tag::WrapType, column::WRAP_TYPE),
+    (Tag::ExpireTime, column::EXPIRE_TIME),
+    (Tag::MaxAccessCount, column::MAX_ACCESS_COUNT),
+    (Tag::RemainingAccessCount, column::REMAINING_ACCESS_COUNT),
+    (Tag::DataLabelCustomCritical, column::CUSTOM_CRITICAL),
+    (Tag::DataLabelCustomNormal, column::CUSTOM_NORMAL),
+    (Tag::DataLabelCustomNormalLocal, column::CUSTOM_NORMAL_LOCAL),
+    (Tag::InvalidateOnBiometricChange, column::INVALIDATE_ON_BIO_CHANGE),
+    (Tag::Revision, column::REVISION),
+    (Tag::CreateTime, column::CREATE_TIME),
+    (Tag::QueryFilter, column::QUERY_FILTER),
 ];
 
-const AAD_ATTR: [&str; 15] = [
+const AAD_ATTR: [&str; 17] = [
     column::ALIAS,
     column::OWNER,
     column::OWNER_TYPE,
@@ -73,6 +86,8 @@ const AAD_ATTR: [&str; 15] = [
     column::CRITICAL3,
     column::CRITICAL4,
     column::WRAP_TYPE,
+    column::CUSTOM_CRITICAL,
+    column::INVALIDATE_ON_BIO_CHANGE,
 ];
 
 /// Defines the critical label attributes.
@@ -87,6 +102,13 @@ pub const NORMAL_LABEL_ATTRS: [Tag; 4] =
 pub const NORMAL_LOCAL_LABEL_ATTRS: [Tag; 4] =
     [Tag::DataLabelNormalLocal1, Tag::DataLabelNormalLocal2, Tag::DataLabelNormalLocal3, Tag::DataLabelNormalLocal4];
 
+/// Defines the custom label attributes.
+pub const CUSTOM_LABEL_ATTRS: [Tag; 3] =
+    [Tag::DataLabelCustomCritical, Tag::DataLabelCustomNormal, Tag::DataLabelCustomNormalLocal];
+
+/// Defines the custom label attributes that are allowed to be updated.
+pub const NORMAL_CUSTOM_LABEL_ATTRS: [Tag; 2] = [Tag::DataLabelCustomNormal, Tag::DataLabelCustomNormalLocal];
+
 /// Defines the access control attributes.
 pub const ACCESS_CONTROL_ATTRS: [Tag; 10] = [
     Tag::Alias,
@@ -128,13 +150,21 @@ pub fn into_db_map(attrs: &AssetMap) -> DbMap {
     db_data
 }
 
-/// Transfer the db map into an asset map.
-pub fn into_asset_map(db_data: &DbMap) -> AssetMap {
+/// Move the secret out of the asset map if present, so that it is not copied along with the other attributes.
+pub fn take_secret(attrs: &mut AssetMap) -> Result<Option<SecretBytes>> {
+    match attrs.contains_key(&Tag::Secret) {
+        true => attrs.take_secret_attr(&Tag::Secret).map(Some),
+        false => Ok(None),
+    }
+}
+
+/// Transfer the db map into an asset map, the values are moved rather than copied.
+pub fn into_asset_map(db_data: DbMap) -> AssetMap {
     let mut map = AssetMap::new();
-    for (column, data) in db_data.iter() {
+    for (column, data) in db_data.into_iter() {
         for (table_tag, table_column) in TAG_COLUMN_TABLE {
-            if (*column).eq(table_column) {
-                map.insert(table_tag, data.clone());
+            if column.eq(table_column) {
+                map.insert(table_tag, data);
                 break;
             }
         }
@@ -166,7 +196,8 @@ pub fn build_secret_key(calling: &CallingInfo, attrs: &DbMap) -> Result<SecretKe
     let auth_type = attrs.get_enum_attr::<AuthType>(&column::AUTH_TYPE)?;
     let access_type = attrs.get_enum_attr::<Accessibility>(&column::ACCESSIBILITY)?;
     let require_password_set = attrs.get_bool_attr(&column::REQUIRE_PASSWORD_SET)?;
-    SecretKey::new_without_alias(calling, auth_type, access_type, require_password_set)
+    let invalidate_on_bio_change = attrs.get_bool_attr(&column::INVALIDATE_ON_BIO_CHANGE).unwrap_or(false);
+    SecretKey::new_with_bio_policy(calling, auth_type, access_type, require_password_set, invalidate_on_bio_change)
 }
 
 fn check_if_need_addition_aad(attr: &str, map: &DbMap) -> bool {
@@ -175,6 +206,8 @@ fn check_if_need_addition_aad(attr: &str, map: &DbMap) -> bool {
             Ok(v) => v != WrapType::default(),
             Err(_) => false,
         },
+        column::CUSTOM_CRITICAL => map.contains_key(attr),
+        column::INVALIDATE_ON_BIO_CHANGE => map.get_bool_attr(&attr).unwrap_or(false),
         _ => true,
     }
 }
@@ -194,6 +227,15 @@ fn to_hex(bytes: &Vec<u8>) -> Result<Vec<u8>> {
     Ok(hex_vec)
 }
 
+// The custom labels are encoded in sorted order, so the aad does not depend on the order they are stored in.
+fn to_canonical_custom_labels(bytes: &[u8]) -> Result<Vec<u8>> {
+    match CustomLabel::decode_list(bytes) {
+        Ok(labels) => Ok(CustomLabel::encode_list(&labels)),
+        Err(_) => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::DataCorrupted, "The data in DB has been tampered with."),
+    }
+}
+
 /// Build aad.
 pub fn build_aad(attrs: &DbMap) -> Result<Vec<u8>> {
     let mut aad = Vec::new();
@@ -203,6 +245,10 @@ pub fn build_aad(attrs: &DbMap) -> Result<Vec<u8>> {
         }
         aad.extend(format!("{}:", column).as_bytes());
         match attrs.get(column) {
+            Some(Value::Bytes(bytes)) if *column == column::CUSTOM_CRITICAL => {
+                aad.extend(to_hex(&to_canonical_custom_labels(bytes)?).map_err(|e| macros_lib::track_error!(e,
+                    macros_lib::hisysevent::function!()))?)
+            },
             Some(Value::Bytes(bytes)) => aad.extend(to_hex(bytes).map_err(|e| macros_lib::track_error!(e,
                 macros_lib::hisysevent::function!()))?),
             Some(Value::Number(num)) => aad.extend(num.to_le_bytes()),
@@ -220,3 +266,45 @@ pub fn need_upgrade(db_data: &DbMap) -> Result<bool> {
     Ok(version != DB_DATA_VERSION)
 }
 
+/// Build the extra sql condition to filter out the expired data, which is appended to the where clause.
+pub fn build_unexpired_condition() -> Result<String> {
+    let now = time::system_time_in_seconds()?;
+    Ok(format!(" and ({0} is null or {0} > {1})", column::EXPIRE_TIME, now))
+}
+
+
+/// Get the revision of the Asset, the Asset stored before the revision is introduced is regarded as revision 0.
+pub fn get_revision(db_data: &DbMap) -> u32 {
+    match db_data.get(column::REVISION) {
+        Some(Value::Number(revision)) => *revision,
+        _ => 0,
+    }
+}
+
+/// Check whether the Asset still has the revision expected by the caller.
+pub fn check_revision(expected: Option<&Value>, db_data: &DbMap) -> Result<()> {
+    match expected {
+        Some(Value::Number(revision)) if *revision != get_revision(db_data) => {
+            macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::RevisionConflict, reason = ErrReason::RevisionMismatch,
+                "[FATAL]The expected revision[{}] mismatches the current revision[{}].",
+                revision, get_revision(db_data))
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Add the revision read from the db into the update condition, so the update fails if the Asset has been modified
+/// in the meantime. The Asset without revision is identified by its update time instead.
+pub fn add_revision_condition(db_data: &DbMap, condition: &mut DbMap) {
+    if let Some(revision) = db_data.get(column::REVISION) {
+        condition.insert(column::REVISION, revision.clone());
+    } else if let Some(update_time) = db_data.get(column::UPDATE_TIME) {
+        condition.insert(column::UPDATE_TIME, update_time.clone());
+    }
+}
+
+/// Set the revision of the Asset to be updated to the next one.
+pub fn add_next_revision(db_data: &DbMap, update_db_data: &mut DbMap) {
+    update_db_data.insert(column::REVISION, Value::Number(get_revision(db_data).wrapping_add(1)));
+}
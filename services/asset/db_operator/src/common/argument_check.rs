@@ -17,18 +17,20 @@
 
 use asset_common::{is_user_id_exist, CallingInfo, OwnerType, ROOT_USER_UPPERBOUND};
 use asset_definition::{
-    macros_lib, Accessibility, AssetMap, AuthType, ConflictResolution, Conversion, ErrCode, OperationType, Result,
-    ReturnType, Tag, Value, WrapType
+    macros_lib, Accessibility, AssetMap, AuthType, ConflictResolution, Conversion, CustomLabel, ErrCode, ErrReason,
+    KeyStatus, OperationType, OrderKey, Predicate, Result, ReturnOrder, ReturnType, Tag, Value, WrapType
 };
 
 use crate::common::{CRITICAL_LABEL_ATTRS, NORMAL_LABEL_ATTRS, NORMAL_LOCAL_LABEL_ATTRS};
 
 const MIN_NUMBER_VALUE: u32 = 0;
-const MAX_RETURN_LIMIT: u32 = 0x10000; // 65536
+/// The maximum number of Assets returned in a query is 65536.
+pub const MAX_RETURN_LIMIT: u32 = 0x10000;
 const MAX_AUTH_VALID_PERIOD: u32 = 600; // 10min
 
 const MIN_ARRAY_SIZE: usize = 0;
-const MAX_SECRET_SIZE: usize = 1024;
+/// The maximum size for a secret is 1024.
+pub const MAX_SECRET_SIZE: usize = 1024;
 const MAX_TIME_SIZE: usize = 1024;
 
 const MAX_ALIAS_SIZE: usize = 256;
@@ -40,13 +42,20 @@ const MIN_GROUP_ID_LEN: usize = 6;
 
 const MAX_AUTH_TOKEN_SIZE: usize = 1024;
 const CHALLENGE_SIZE: usize = 32;
+const MAX_PAGE_TOKEN_SIZE: usize = 256;
 const SYNC_TYPE_MIN_BITS: u32 = 0;
 const SYNC_TYPE_MAX_BITS: u32 = 3;
+const MAX_QUERY_FILTER_SIZE: usize = 0x8000;
+const MAX_IN_LIST_SIZE: usize = 100;
+const MAX_ORDER_KEYS: usize = 4;
+const TIME_TAGS: [Tag; 2] = [Tag::CreateTime, Tag::UpdateTime];
+
+pub use asset_definition::ENUM_TAG_VALUES;
 
 fn check_data_type(tag: &Tag, value: &Value) -> Result<()> {
     if tag.data_type() != value.data_type() {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument,
+            ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch,
             "[FATAL]The data type[{}] of the tag[{}] does not match that of the value.", value.data_type(), tag);
     }
     Ok(())
@@ -55,11 +64,12 @@ fn check_data_type(tag: &Tag, value: &Value) -> Result<()> {
 fn check_array_size(tag: &Tag, value: &Value, min: usize, max: usize) -> Result<()> {
     let Value::Bytes(v) = value else {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][{}] is not a bytes.", tag);
+            ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch, "[FATAL][{}] is not a bytes.", tag);
     };
     if v.len() > max || v.len() <= min {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL]The array length[{}] of Tag[{}], exceeds the valid range.", v.len(), tag);
+            ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange,
+            "[FATAL]The array length[{}] of Tag[{}], exceeds the valid range.", v.len(), tag);
     }
     Ok(())
 }
@@ -67,11 +77,12 @@ fn check_array_size(tag: &Tag, value: &Value, min: usize, max: usize) -> Result<
 fn check_enum_variant<T: TryFrom<u32>>(tag: &Tag, value: &Value) -> Result<()> {
     let Value::Number(n) = value else {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][{}] is not a number.", tag);
+            ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch, "[FATAL][{}] is not a number.", tag);
     };
     if T::try_from(*n).is_err() {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL]The value[{}] of Tag[{}] is not a legal enumeration variant", *n, tag);
+            ErrCode::InvalidArgument, reason = ErrReason::InvalidEnumVariant,
+            "[FATAL]The value[{}] of Tag[{}] is not a legal enumeration variant", *n, tag);
     }
     Ok(())
 }
@@ -79,12 +90,13 @@ fn check_enum_variant<T: TryFrom<u32>>(tag: &Tag, value: &Value) -> Result<()> {
 fn check_valid_bits(tag: &Tag, value: &Value, min_bits: u32, max_bits: u32) -> Result<()> {
     let Value::Number(n) = value else {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][{}] is not a number.", tag);
+            ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch, "[FATAL][{}] is not a number.", tag);
     };
     if *n >= 2_u32.pow(max_bits) || *n < (2_u32.pow(min_bits) - 1) {
         // 2: binary system
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL]The value[{}] of Tag[{}] is not in the valid bit number.", *n, tag);
+            ErrCode::InvalidArgument, reason = ErrReason::BitsOutOfRange,
+            "[FATAL]The value[{}] of Tag[{}] is not in the valid bit number.", *n, tag);
     }
     Ok(())
 }
@@ -92,11 +104,12 @@ fn check_valid_bits(tag: &Tag, value: &Value, min_bits: u32, max_bits: u32) -> R
 fn check_number_range(tag: &Tag, value: &Value, min: u32, max: u32) -> Result<()> {
     let Value::Number(n) = value else {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][{}] is not a number.", tag);
+            ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch, "[FATAL][{}] is not a number.", tag);
     };
     if *n <= min || *n > max {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL]The value[{}] of Tag[{}] is not in the valid number range.", *n, tag);
+            ErrCode::InvalidArgument, reason = ErrReason::NumberOutOfRange,
+            "[FATAL]The value[{}] of Tag[{}] is not in the valid number range.", *n, tag);
     }
     Ok(())
 }
@@ -104,13 +117,14 @@ fn check_number_range(tag: &Tag, value: &Value, min: u32, max: u32) -> Result<()
 fn check_tag_range(tag: &Tag, value: &Value, tags: &[Tag]) -> Result<()> {
     let Value::Number(n) = value else {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][{}] is not a number.", tag);
+            ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch, "[FATAL][{}] is not a number.", tag);
     };
     match Tag::try_from(*n) {
         Ok(value) if tags.contains(&value) => Ok(()),
         _ => {
             macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]The value[{}] of Tag[{}] is not in the valid tag range.", *n, tag)
+                ErrCode::InvalidArgument, reason = ErrReason::TagOutOfRange,
+                "[FATAL]The value[{}] of Tag[{}] is not in the valid tag range.", *n, tag)
         },
     }
 }
@@ -119,22 +133,108 @@ fn check_user_id(tag: &Tag, value: &Value) -> Result<()> {
     check_number_range(tag, value, ROOT_USER_UPPERBOUND, i32::MAX as u32)?;
     let Value::Number(n) = value else {
         return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL][{}] is not a number.", tag);
+            ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch, "[FATAL][{}] is not a number.", tag);
     };
     match is_user_id_exist(*n as i32) {
         Ok(res) if res => Ok(()),
         Ok(_) => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::InvalidArgument, "[FATAL]The user id [{}] is not exist.", *n),
+            ErrCode::InvalidArgument, reason = ErrReason::UserIdNotExist, "[FATAL]The user id [{}] is not exist.", *n),
         Err(e) => Err(e),
     }
 }
 
+fn check_custom_labels(tag: &Tag, value: &Value) -> Result<()> {
+    check_array_size(tag, value, MIN_ARRAY_SIZE, MAX_LABEL_SIZE)?;
+    let Value::Bytes(v) = value else {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch, "[FATAL][{}] is not a bytes.", tag);
+    };
+    let labels = CustomLabel::split_list(v)?;
+    if labels.is_empty() || labels.iter().any(|(name, _)| name.is_empty()) {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+            "[FATAL]The custom labels of Tag[{}] contain an empty name.", tag);
+    }
+    for (i, (name, _)) in labels.iter().enumerate() {
+        if labels[..i].iter().any(|(other, _)| other == name) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+                "[FATAL]The custom labels of Tag[{}] contain duplicate names.", tag);
+        }
+    }
+    Ok(())
+}
+
+fn check_predicate(predicate: &Predicate) -> Result<()> {
+    let tag = predicate.tag();
+    let is_valid = match predicate {
+        Predicate::Prefix(_, prefix) => {
+            let is_label = [CRITICAL_LABEL_ATTRS, NORMAL_LABEL_ATTRS, NORMAL_LOCAL_LABEL_ATTRS].concat().contains(&tag);
+            let max_size = if tag == Tag::Alias { MAX_ALIAS_SIZE } else { MAX_LABEL_SIZE };
+            (tag == Tag::Alias || is_label) && !prefix.is_empty() && prefix.len() <= max_size
+        },
+        Predicate::Range(_, from, to) => {
+            let in_range = |bound: &Option<u64>| !matches!(bound, Some(bound) if *bound > i64::MAX as u64);
+            TIME_TAGS.contains(&tag) && (from.is_some() || to.is_some()) && in_range(from) && in_range(to)
+                && !matches!((from, to), (Some(from), Some(to)) if from > to)
+        },
+        Predicate::In(_, values) => {
+            tag == Tag::Alias && !values.is_empty() && values.len() <= MAX_IN_LIST_SIZE
+                && values.iter().all(|value| !value.is_empty() && value.len() <= MAX_ALIAS_SIZE)
+        },
+    };
+    if !is_valid {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::MalformedValue,
+            "[FATAL]The predicate on Tag[{}] of Tag[{}] is invalid.", tag, Tag::QueryFilter);
+    }
+    Ok(())
+}
+
+fn check_query_filter(tag: &Tag, value: &Value) -> Result<()> {
+    check_array_size(tag, value, MIN_ARRAY_SIZE, MAX_QUERY_FILTER_SIZE)?;
+    let Value::Bytes(v) = value else {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch, "[FATAL][{}] is not a bytes.", tag);
+    };
+    for predicate in Predicate::decode_list(v)? {
+        check_predicate(&predicate)?;
+    }
+    Ok(())
+}
+
+// The query results can be sorted by the labels and the times of Assets.
+fn order_by_tags() -> Vec<Tag> {
+    [&CRITICAL_LABEL_ATTRS[..], &NORMAL_LABEL_ATTRS, &NORMAL_LOCAL_LABEL_ATTRS, &TIME_TAGS].concat()
+}
+
+fn check_order_keys(tag: &Tag, value: &Value) -> Result<()> {
+    // Each sort key is encoded as its tag followed by the direction of sorting.
+    check_array_size(tag, value, MIN_ARRAY_SIZE, MAX_ORDER_KEYS * 2 * std::mem::size_of::<u32>())?;
+    let Value::Bytes(v) = value else {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch, "[FATAL][{}] is not a bytes.", tag);
+    };
+    let keys: Vec<Tag> = OrderKey::decode_list(v)?.into_iter().map(|(key, _)| key).collect();
+    let valid_tags = order_by_tags();
+    for (i, key) in keys.iter().enumerate() {
+        if !valid_tags.contains(key) || keys[..i].contains(key) {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::InvalidArgument, reason = ErrReason::TagOutOfRange,
+                "[FATAL]The sort key[{}] of Tag[{}] is not in the valid tag range or duplicated.", key, tag);
+        }
+    }
+    Ok(())
+}
+
 fn check_data_value(tag: &Tag, value: &Value) -> Result<()> {
     match tag {
         Tag::Secret => check_array_size(tag, value, MIN_ARRAY_SIZE, MAX_SECRET_SIZE),
         Tag::Alias => check_array_size(tag, value, MIN_ARRAY_SIZE, MAX_ALIAS_SIZE),
         Tag::Accessibility => check_enum_variant::<Accessibility>(tag, value),
-        Tag::RequirePasswordSet | Tag::IsPersistent | Tag::RequireAttrEncrypted => Ok(()),
+        Tag::RequirePasswordSet | Tag::IsPersistent | Tag::RequireAttrEncrypted | Tag::InvalidateOnBiometricChange => {
+            Ok(())
+        },
         Tag::AuthType => check_enum_variant::<AuthType>(tag, value),
         Tag::AuthValidityPeriod => check_number_range(tag, value, MIN_NUMBER_VALUE, MAX_AUTH_VALID_PERIOD),
         Tag::AuthChallenge => check_array_size(tag, value, CHALLENGE_SIZE - 1, CHALLENGE_SIZE),
@@ -154,14 +254,25 @@ fn check_data_value(tag: &Tag, value: &Value) -> Result<()> {
         Tag::ReturnType => check_enum_variant::<ReturnType>(tag, value),
         Tag::ReturnLimit => check_number_range(tag, value, MIN_NUMBER_VALUE, MAX_RETURN_LIMIT),
         Tag::ReturnOffset => Ok(()),
-        Tag::ReturnOrderedBy => {
-            check_tag_range(tag, value, &[CRITICAL_LABEL_ATTRS, NORMAL_LABEL_ATTRS, NORMAL_LOCAL_LABEL_ATTRS].concat())
-        },
+        Tag::ReturnOrderedBy => check_tag_range(tag, value, &order_by_tags()),
         Tag::UserId => check_user_id(tag, value),
         Tag::UpdateTime => check_array_size(tag, value, MIN_ARRAY_SIZE, MAX_TIME_SIZE),
         Tag::OperationType => check_enum_variant::<OperationType>(tag, value),
         Tag::GroupId => check_array_size(tag, value, MIN_GROUP_ID_LEN, MAX_GROUP_ID_LEN),
         Tag::WrapType => check_enum_variant::<WrapType>(tag, value),
+        Tag::ExpireTime => check_number_range(tag, value, MIN_NUMBER_VALUE, u32::MAX),
+        Tag::MaxAccessCount => check_number_range(tag, value, MIN_NUMBER_VALUE, u32::MAX),
+        Tag::DataLabelCustomCritical | Tag::DataLabelCustomNormal | Tag::DataLabelCustomNormalLocal => {
+            check_custom_labels(tag, value)
+        },
+        Tag::KeyStatus => check_enum_variant::<KeyStatus>(tag, value),
+        Tag::PageToken => check_array_size(tag, value, MIN_ARRAY_SIZE, MAX_PAGE_TOKEN_SIZE),
+        Tag::Revision => Ok(()),
+        Tag::CreateTime => check_array_size(tag, value, MIN_ARRAY_SIZE, MAX_TIME_SIZE),
+        Tag::QueryFilter => check_query_filter(tag, value),
+        Tag::ReturnOrder => check_enum_variant::<ReturnOrder>(tag, value),
+        Tag::ReturnOrderedByKeys => check_order_keys(tag, value),
+        Tag::RemainingAccessCount => Ok(()),
     }
 }
 
@@ -179,7 +290,8 @@ pub fn check_required_tags(attrs: &AssetMap, required_tags: &[Tag]) -> Result<()
     for tag in required_tags {
         if !attrs.contains_key(tag) {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]The required tag [{}] is missing.", tag);
+                ErrCode::InvalidArgument, reason = ErrReason::MissingTag,
+                "[FATAL]The required tag [{}] is missing.", tag);
         }
     }
     Ok(())
@@ -190,7 +302,7 @@ pub fn check_tag_validity(attrs: &AssetMap, valid_tags: &[Tag]) -> Result<()> {
     for tag in attrs.keys() {
         if !valid_tags.contains(tag) {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]The tag [{}] is illegal.", tag);
+                ErrCode::InvalidArgument, reason = ErrReason::IllegalTag, "[FATAL]The tag [{}] is illegal.", tag);
         }
     }
     Ok(())
@@ -201,17 +313,17 @@ pub fn check_group_validity(attrs: &AssetMap, calling_info: &CallingInfo) -> Res
     if attrs.get(&Tag::GroupId).is_some() {
         if let Some(Value::Bool(true)) = attrs.get(&Tag::IsPersistent) {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument,
+                ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
                 "[FATAL]The value of the tag [{}] cannot be set to true when the tag [{}] is specified.", &Tag::IsPersistent, &Tag::GroupId);
         }
         if calling_info.owner_type_enum() == OwnerType::Native {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::Unsupported,
+                ErrCode::Unsupported, reason = ErrReason::GroupUnsupportedForNative,
                 "[FATAL]The tag [{}] is not yet supported for [{}] owner.", &Tag::GroupId, OwnerType::Native);
         }
         if calling_info.app_index() > 0 {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::Unsupported,
+                ErrCode::Unsupported, reason = ErrReason::GroupUnsupportedForCloneApp,
                 "[FATAL]The tag [{}] is not yet supported for clone or sandbox app.", &Tag::GroupId);
         }
     }
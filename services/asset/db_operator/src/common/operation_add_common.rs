@@ -19,8 +19,10 @@ use std::ffi::{c_char, CString};
 
 use asset_common::{CallingInfo, OwnerType};
 use asset_definition::{
-    macros_lib, Accessibility, AssetMap, ErrCode, SyncType, Tag, Value, WrapType, Extension, Result
+    macros_lib, Accessibility, AssetMap, AuthType, ErrCode, ErrReason, SyncType, Tag, Value, WrapType, Extension,
+    Result
 };
+use asset_utils::time;
 
 use crate::{
     common::{add_owner_info, add_group},
@@ -36,7 +38,14 @@ pub const SYSTEM_USER_ID_MAX: i32 = 99;
 /// Constant for required attributes.
 pub const REQUIRED_ATTRS: [Tag; 2] = [Tag::Secret, Tag::Alias];
 /// Constant for optional attributes.
-pub const OPTIONAL_ATTRS: [Tag; 3] = [Tag::Secret, Tag::ConflictResolution, Tag::WrapType];
+pub const OPTIONAL_ATTRS: [Tag; 6] = [
+    Tag::Secret,
+    Tag::ConflictResolution,
+    Tag::WrapType,
+    Tag::ExpireTime,
+    Tag::MaxAccessCount,
+    Tag::InvalidateOnBiometricChange,
+];
 
 /// Check the validity of accessibility.
 pub fn check_accessibility_validity(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
@@ -49,7 +58,8 @@ pub fn check_accessibility_validity(attributes: &AssetMap, calling_info: &Callin
         return Ok(());
     }
     macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-        ErrCode::InvalidArgument, "[FATAL][SA]System user data cannot be protected by the lock screen password.")
+        ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
+        "[FATAL][SA]System user data cannot be protected by the lock screen password.")
 }
 
 /// Check the permission of the persistent.
@@ -75,12 +85,14 @@ pub fn check_sync_permission(attributes: &AssetMap, calling_info: &CallingInfo)
         OwnerType::Hap => {
             if calling_info.app_index() > 0 {
                 return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                    ErrCode::Unsupported, "[FATAL]The caller does not support storing sync data.");
+                    ErrCode::Unsupported, reason = ErrReason::SyncDataUnsupported,
+                    "[FATAL]The caller does not support storing sync data.");
             }
         },
         OwnerType::HapGroup => {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::Unsupported, "[FATAL]The caller does not support storing sync data.");
+                ErrCode::Unsupported, reason = ErrReason::SyncDataUnsupported,
+                "[FATAL]The caller does not support storing sync data.");
         },
         OwnerType::Native => (),
     }
@@ -98,7 +110,8 @@ pub fn check_wrap_permission(attributes: &AssetMap, calling_info: &CallingInfo)
         OwnerType::Hap | OwnerType::HapGroup => {
             if calling_info.app_index() > 0 {
                 return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                    ErrCode::Unsupported, "[FATAL]The caller does not support storing wrap data.");
+                    ErrCode::Unsupported, reason = ErrReason::WrapDataUnsupported,
+                    "[FATAL]The caller does not support storing wrap data.");
             }
         },
         OwnerType::Native => (),
@@ -110,10 +123,39 @@ pub fn check_wrap_permission(attributes: &AssetMap, calling_info: &CallingInfo)
         Ok(())
     } else {
         macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-            ErrCode::Unsupported, "[FATAL]trusted account data can not be set need wrap data.")
+            ErrCode::Unsupported, reason = ErrReason::WrapDataUnsupported,
+            "[FATAL]trusted account data can not be set need wrap data.")
     }
 }
 
+/// Check the expiration time is later than the current time.
+pub fn check_expire_time_validity(attributes: &AssetMap) -> Result<()> {
+    let Some(Value::Number(expire_time)) = attributes.get(&Tag::ExpireTime) else {
+        return Ok(());
+    };
+    let now = time::system_time_in_seconds()?;
+    if u64::from(*expire_time) <= now {
+        return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+            ErrCode::InvalidArgument, reason = ErrReason::NumberOutOfRange,
+            "[FATAL]The expire time[{}] is earlier than the current time[{}].", expire_time, now);
+    }
+    Ok(())
+}
+
+/// Check the biometric invalidation policy is only set for the Asset protected by fingerprint or face.
+pub fn check_bio_policy_validity(attributes: &AssetMap) -> Result<()> {
+    if !attributes.get_bool_attr(&Tag::InvalidateOnBiometricChange).unwrap_or(false) {
+        return Ok(());
+    }
+    let auth_type = attributes.get_num_attr(&Tag::AuthType).unwrap_or(AuthType::None as u32);
+    if auth_type & AuthType::Biometric as u32 != 0 {
+        return Ok(());
+    }
+    macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+        ErrCode::InvalidArgument, reason = ErrReason::ConflictingAttributes,
+        "[FATAL]The biometric invalidation policy requires the auth type[{}] to contain biometrics.", auth_type)
+}
+
 /// Get query condition.
 pub fn get_query_condition(attrs: &AssetMap, calling_info: &CallingInfo) -> Result<DbMap> {
     let alias = attrs.get_bytes_attr(&Tag::Alias)?;
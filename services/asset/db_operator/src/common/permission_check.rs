@@ -20,7 +20,7 @@ use std::{ffi::CString, os::raw::c_char};
 use ipc::Skeleton;
 
 use asset_common::{get_user_id, ROOT_USER_UPPERBOUND};
-use asset_definition::{macros_lib, AssetMap, ErrCode, Result, Tag};
+use asset_definition::{macros_lib, AssetMap, ErrCode, ErrReason, Result, Tag};
 
 extern "C" {
     fn CheckPermission(permission: *const c_char) -> bool;
@@ -45,7 +45,8 @@ pub fn check_system_permission(attrs: &AssetMap) -> Result<()> {
         let user_id = get_user_id(uid)?;
         if user_id > ROOT_USER_UPPERBOUND {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::AccessDenied, "[FATAL]The caller user_id is: {}. Not in range[0, 99]", user_id);
+                ErrCode::AccessDenied, reason = ErrReason::PermissionOutOfScope,
+                "[FATAL]The caller user_id is: {}. Not in range[0, 99]", user_id);
         }
     }
     Ok(())
@@ -26,24 +26,30 @@ use asset_crypto_manager::{
     crypto::Crypto, db_key_operator::generate_secret_key_if_needed, secret_key::{SecretKey, rename_key_alias}
 };
 use asset_definition::{
-    macros_lib, ErrCode, Extension, Result, Value, AssetMap,
-    Tag, SyncType, SyncStatus, ConflictResolution
+    macros_lib, AssetError, BatchItemResult, ErrCode, ErrReason, Extension, Result, Value, AssetMap,
+    Tag, SyncType, SyncStatus, ConflictResolution, SecretBytes
 };
 use asset_log::{loge, logi};
 use asset_utils::time;
 use lazy_static::lazy_static;
 
 use crate::{
-    common::{build_secret_key, build_aad, get_query_condition},
+    common::{
+        add_next_revision, add_revision_condition, build_secret_key, build_aad, check_revision, get_query_condition,
+        take_secret,
+    },
     database_file_upgrade::{check_and_split_db, construct_splited_db_name},
     database_util::is_db_need_ce_unlock,
     statement::Statement,
-    table::Table,
+    table::{build_access_exhausted_data, Table},
     types::{
-        column, sqlite_err_handle, DbMap, QueryOptions, SQLITE_OK, TABLE_NAME,
+        column, sqlite_err_handle, DbMap, DbWrite, QueryOptions, SQLITE_OK, TABLE_NAME,
         ADAPT_CLOUD_COLUMN_INFO, ADAPT_CLOUD_TABLE, COLUMN_INFO, COMBINE_COLUMN_INFO,
         DB_UPGRADE_VERSION, DB_UPGRADE_VERSION_V0, DB_UPGRADE_VERSION_V1, DB_UPGRADE_VERSION_V2, DB_UPGRADE_VERSION_V3,
-        UPGRADE_COLUMN_INFO, UPGRADE_COLUMN_INFO_V2, UPGRADE_COLUMN_INFO_V3, UPGRADE_COLUMN_INFO_V4
+        DB_UPGRADE_VERSION_V4, DB_UPGRADE_VERSION_V5, DB_UPGRADE_VERSION_V6, DB_UPGRADE_VERSION_V7, UPGRADE_COLUMN_INFO,
+        UPGRADE_COLUMN_INFO_V2, UPGRADE_COLUMN_INFO_V3, UPGRADE_COLUMN_INFO_V4, UPGRADE_COLUMN_INFO_V5,
+        UPGRADE_COLUMN_INFO_V6, UPGRADE_COLUMN_INFO_V7, UPGRADE_COLUMN_INFO_V8,
+        ACCESS_EXHAUSTED_COLUMN_INFO, ACCESS_EXHAUSTED_TABLE
     },
     process_batch_data::{parse_attr_in_array, add_not_null_column, into_db_map_with_column_names, 
         add_default_batch_update_attrs, check_invalid_tags
@@ -66,7 +72,7 @@ pub(crate) struct UserDbLock {
 }
 
 struct AdditionalInfo<'a> {
-    attributes_array: &'a [AssetMap],
+    attributes_array: &'a mut [AssetMap],
     db_map: &'a DbMap,
     calling_info: &'a CallingInfo,
 }
@@ -403,6 +409,7 @@ impl Database {
         // when create db table always use newest version.
         self.restore_if_exec_fail(|e: &Table| e.create_with_version(COLUMN_INFO, DB_UPGRADE_VERSION))?;
         self.upgrade(user_id, DB_UPGRADE_VERSION, |_, _, _| Ok(()))?;
+        self.restore_if_exec_fail(|e: &Table| e.create_custom_label_table())?;
         Ok(())
     }
 
@@ -468,6 +475,22 @@ impl Database {
                     }
                 },
                 DB_UPGRADE_VERSION_V3 => {
+                    self.restore_if_exec_fail(|e: &Table| e.upgrade(DB_UPGRADE_VERSION_V4, UPGRADE_COLUMN_INFO_V5))?;
+                    current_ver += 1;
+                },
+                DB_UPGRADE_VERSION_V4 => {
+                    self.restore_if_exec_fail(|e: &Table| e.upgrade(DB_UPGRADE_VERSION_V5, UPGRADE_COLUMN_INFO_V6))?;
+                    current_ver += 1;
+                },
+                DB_UPGRADE_VERSION_V5 => {
+                    self.restore_if_exec_fail(|e: &Table| e.upgrade(DB_UPGRADE_VERSION_V6, UPGRADE_COLUMN_INFO_V7))?;
+                    current_ver += 1;
+                },
+                DB_UPGRADE_VERSION_V6 => {
+                    self.restore_if_exec_fail(|e: &Table| e.upgrade(DB_UPGRADE_VERSION_V7, UPGRADE_COLUMN_INFO_V8))?;
+                    current_ver += 1;
+                },
+                DB_UPGRADE_VERSION_V7 => {
                     self.restore_if_exec_fail(|e: &Table| e.upgrade(DB_UPGRADE_VERSION, UPGRADE_COLUMN_INFO))?;
                     current_ver += 1;
                 },
@@ -621,73 +644,98 @@ impl Database {
     }
 
     /// Insert datas in database with specific condition.
-    /// If the operation is successful, the array of indexes failed to insert and corresponding reason is returned.
+    /// If the operation is successful, the result of each item in the array is returned in the order of the items.
     #[inline(always)]
+    /// The secrets are moved out of the attributes rather than copied.
     pub fn insert_batch_datas(
         &mut self,
         db_map: &DbMap,
-        attributes_array: &[AssetMap],
+        attributes_array: &mut [AssetMap],
         calling_info: &CallingInfo,
-    ) -> Result<Vec<(u32, u32)>> {
+    ) -> Result<Vec<BatchItemResult>> {
         let mut db_datas = Vec::new();
         let mut err_info = Vec::new();
         let mut aliases = Vec::new();
-        let info = AdditionalInfo {
+        let count = attributes_array.len() as u32;
+        let mut info = AdditionalInfo {
             attributes_array,
             db_map,
             calling_info,
         };
 	    let _lock = self.db_lock.mtx.lock().unwrap();
-        let column_names = self.parse_attr_array(&mut db_datas, &mut err_info, &mut aliases, &info)?;
+        let column_names = self.parse_attr_array(&mut db_datas, &mut err_info, &mut aliases, &mut info)?;
         if db_datas.is_empty() {
             return Ok(err_info);
         }
 
         let column_names = Vec::from_iter(column_names);
         let closure = |e: &Table| e.local_insert_batch_datas(&db_datas, db_map, &aliases, &column_names);
-        self.restore_if_exec_fail(closure)?;
-        Ok(err_info)
+        let ids = self.restore_if_exec_fail(closure)?;
+
+        // The items are parsed in order, so the aliases of the inserted items follow the order of their indexes.
+        let failed_indexes: HashSet<u32> = err_info.iter().map(|item| item.index).collect();
+        let inserted_indexes = (0..count).filter(|index| !failed_indexes.contains(index));
+        let mut results = err_info;
+        for ((index, alias), id) in inserted_indexes.zip(aliases).zip(ids) {
+            results.push(BatchItemResult::success(index, alias, Some(id)));
+        }
+        results.sort_by_key(|item| item.index);
+        Ok(results)
     }
 
     /// Update datas in database with specific condition.
-    /// If the operation is successful, the array of indexes failed to insert and corresponding reason is returned.
+    /// If the operation is successful, the result of each item in the array is returned in the order of the items.
     #[inline(always)]
     pub fn update_batch_datas(
         &mut self,
         db_map: &DbMap,
         attributes_array: &[AssetMap],
-        attributes_to_update_array: &[AssetMap],
+        attributes_to_update_array: &mut [AssetMap],
         calling_info: &CallingInfo,
-    ) -> Result<Vec<(u32, u32)>> {
+    ) -> Result<Vec<BatchItemResult>> {
+        let mut results = Vec::with_capacity(attributes_array.len());
         let mut aliases = Vec::new();
+        let mut revision_conditions = Vec::new();
         let mut column_names: HashSet<String> = HashSet::new();
         let mut db_datas = Vec::new();
-        let mut err_info = Vec::new();
         let time = time::system_time_in_millis()?;
         if attributes_array.is_empty() || attributes_to_update_array.is_empty() { 
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]The data to update is empty.");
+                ErrCode::InvalidArgument, reason = ErrReason::SizeOutOfRange, "[FATAL]The data to update is empty.");
         }
 
         for (index, (attr, attr_to_update)) in attributes_array.iter()
-            .zip(attributes_to_update_array.iter())
+            .zip(attributes_to_update_array.iter_mut())
             .enumerate() 
         {
             check_invalid_tags(attr)?;
             let query = get_query_condition(attr, calling_info)?;
-            let mut results = self.query_datas(&vec![], &query, None, true)?;
-            if results.len() != 1 {
-                err_info.push((ErrCode::NotFound as u32, index as u32));
+            let alias = attr.get_bytes_attr(&Tag::Alias)?.to_vec();
+            let mut rows = self.query_datas(&vec![], &query, None, true)?;
+            if rows.len() != 1 {
+                results.push(BatchItemResult::failure(index as u32, alias, ErrCode::NotFound,
+                    "The data to update is not found.".to_string()));
                 continue;
             }
-            aliases.push(attr.get_bytes_attr(&Tag::Alias)?.to_vec());
-            let result = results.get_mut(0).unwrap();
+            let result = rows.get_mut(0).unwrap();
+            if let Err(e) = check_revision(attr.get(&Tag::Revision), result) {
+                results.push(BatchItemResult::failure(index as u32, alias, e.code, e.msg));
+                continue;
+            }
+            results.push(BatchItemResult::success(index as u32, alias.clone(), result.get_num_attr(&column::ID).ok()));
+            aliases.push(alias);
+            let mut revision_condition = DbMap::new();
+            add_revision_condition(result, &mut revision_condition);
+            revision_conditions.push(revision_condition);
 
-            let mut db_data = into_db_map_with_column_names(attr_to_update, &mut column_names);
+            let mut db_data = DbMap::new();
             add_default_batch_update_attrs(&mut db_data, time.clone(), attr_to_update);
-            if attr_to_update.contains_key(&Tag::Secret) {
-                result.insert(column::SECRET, attr_to_update[&Tag::Secret].clone());
-                let cipher = self.encrypt(calling_info, result)?;
+            // The secret is moved out after the attributes to update are inspected, rather than copied.
+            let secret = take_secret(attr_to_update)?;
+            db_data.extend(into_db_map_with_column_names(attr_to_update, &mut column_names));
+            add_next_revision(result, &mut db_data);
+            if let Some(secret) = secret {
+                let cipher = self.encrypt(calling_info, result, &secret)?;
                 db_data.insert(column::SECRET, Value::Bytes(cipher));
             }
             db_datas.push(db_data);
@@ -698,32 +746,35 @@ impl Database {
                 ErrCode::SystemError, "[FATAL]The system internal error.");
         }
         let _lock = self.db_lock.mtx.lock().unwrap();
-        let closure = |e: &Table| e.local_update_batch_datas(&db_datas, db_map, &aliases);
+        if db_datas.is_empty() {
+            return Ok(results);
+        }
+        let closure = |e: &Table| e.local_update_batch_datas(&db_datas, db_map, &aliases, &revision_conditions);
 
         self.restore_if_exec_fail(closure)?;
-        Ok(err_info)
+        Ok(results)
     }
 
-    fn encrypt(&mut self, calling_info: &CallingInfo, db_data: &DbMap) -> Result<Vec<u8>> {
+    fn encrypt(&mut self, calling_info: &CallingInfo, db_data: &DbMap, secret: &SecretBytes) -> Result<Vec<u8>> {
         let secret_key = build_secret_key(calling_info, db_data)?;
-        let secret = db_data.get_bytes_attr(&column::SECRET)?;
         let cipher = Crypto::encrypt(&secret_key, secret, &build_aad(db_data)?)?;
         Ok(cipher)
     }
 
     fn parse_attr_array(&mut self,
         db_datas: &mut Vec<DbMap>,
-        err_info: &mut Vec<(u32, u32)>,
+        err_info: &mut Vec<BatchItemResult>,
         aliases: &mut Vec<Vec<u8>>,
-        info: &AdditionalInfo,
+        info: &mut AdditionalInfo,
     ) -> Result<HashSet<String>> {
         let mut column_names = HashSet::new();
         add_not_null_column(&mut column_names);
-        for (index, attr) in info.attributes_array.iter().enumerate() {
-            let mut db_data = parse_attr_in_array(attr, info.calling_info, &mut column_names)?;
+        for (index, attr) in info.attributes_array.iter_mut().enumerate() {
+            let (mut db_data, secret) = parse_attr_in_array(attr, info.calling_info, &mut column_names)?;
             if aliases.contains(&db_data.get_bytes_attr(&column::ALIAS)?.to_vec()) {
                 return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                    ErrCode::InvalidArgument, "[FATAL]The array contains duplicated alias");
+                    ErrCode::InvalidArgument, reason = ErrReason::DuplicatedItem,
+                    "[FATAL]The array contains duplicated alias");
             }
             let query = get_query_condition(attr, info.calling_info)?;
             let mut condition = query.clone();
@@ -736,7 +787,9 @@ impl Database {
                     },
                     _ => {
                         if !self.is_data_exists_without_lock(&condition, false)? {
-                            err_info.push((ErrCode::Duplicated as u32, index as u32));
+                            let alias = attr.get_bytes_attr(&Tag::Alias)?.to_vec();
+                            err_info.push(BatchItemResult::failure(index as u32, alias, ErrCode::Duplicated,
+                                "The data with the specified alias already exists.".to_string()));
                             continue;
                         }
                         self.check_cloud_and_insert(&mut db_data, &condition, &mut column_names)?;
@@ -746,7 +799,7 @@ impl Database {
             db_data.extend(info.db_map.clone());
             let secret_key = build_secret_key(info.calling_info, &db_data)?;
             generate_secret_key_if_needed(&secret_key)?;
-            self.encrypt_single_data(&mut db_data, &secret_key, aliases)?;
+            self.encrypt_single_data(&mut db_data, &secret_key, &secret, aliases)?;
             db_datas.push(db_data);
         }
         Ok(column_names)
@@ -758,11 +811,13 @@ impl Database {
         condition: &DbMap,
         column_names: &mut HashSet<String>
     ) -> Result<()> {
-        let cols = vec![column::SYNC_TYPE, column::CLOUD_VERSION, column::GLOBAL_ID];
+        let cols = vec![column::SYNC_TYPE, column::CLOUD_VERSION, column::GLOBAL_ID, column::REVISION];
         let closure = |e: &Table| e.query_row(&cols, condition, None, false, COLUMN_INFO);
         if let Ok(rows) = self.restore_if_exec_fail(closure) {
             if !rows.is_empty() {
                 let old_row = rows.first().unwrap();
+                // The revision of the replaced data is continued, so that the stale revision no longer matches.
+                add_next_revision(old_row, datas);
                 let trusted_acc = SyncType::TrustedAccount as u32;
                 if (old_row.get_num_attr(&column::SYNC_TYPE)? & trusted_acc) == trusted_acc
                     && (datas.get_num_attr(&column::SYNC_TYPE)? & trusted_acc) == trusted_acc
@@ -785,9 +840,9 @@ impl Database {
         &mut self,
         db_data: &mut DbMap,
         secret_key: &SecretKey,
+        secret: &SecretBytes,
         aliases: &mut Vec<Vec<u8>>
     ) -> Result<()> {
-        let secret = db_data.get_bytes_attr(&column::SECRET)?;
         let cipher = Crypto::encrypt(secret_key, secret, &build_aad(db_data)?)?;
         db_data.insert(column::SECRET, Value::Bytes(cipher));
         aliases.push(db_data.get_bytes_attr(&column::ALIAS)?.to_vec());
@@ -859,15 +914,119 @@ impl Database {
         self.restore_if_exec_fail(closure)
     }
 
-    /// Delete datas from database with specific condition.
-    /// If the operation is successful, the number of deleted data is returned.
+    /// Delete the datas whose expiration time is not later than the specified time (in seconds).
+    /// The expired datas synchronized by trusted account are marked as deleted for the cloud synchronization.
+    /// If the operation is successful, the number of deleted data and marked data is returned.
     #[inline(always)]
-    pub fn delete_batch_datas(&mut self, condition: &DbMap, update_datas: &DbMap, aliases: &[Vec<u8>]) -> Result<i32> {
+    pub fn delete_expired_datas(&mut self, expire_time: u32, update_time: &Value) -> Result<(i32, i32)> {
+        let _lock = self.db_lock.mtx.lock().unwrap();
+        let closure = |e: &Table| e.delete_expired_rows(&DbMap::new(), expire_time, update_time);
+        self.restore_if_exec_fail(closure)
+    }
+
+    /// Delete the expired datas matching the condition, eg. before the datas are looked up to be written.
+    /// The expired datas synchronized by trusted account are marked as deleted for the cloud synchronization.
+    /// If the operation is successful, the number of deleted data and marked data is returned.
+    pub fn delete_expired_datas_by_condition(&mut self, condition: &DbMap) -> Result<(i32, i32)> {
+        let expire_time = u32::try_from(time::system_time_in_seconds()?).unwrap_or(u32::MAX);
+        let update_time = Value::Bytes(time::system_time_in_millis()?);
         let _lock = self.db_lock.mtx.lock().unwrap();
-        let closure = |e: &Table| e.local_delete_batch_datas(condition, update_datas, aliases, true);
+        let closure = |e: &Table| e.delete_expired_rows(condition, expire_time, &update_time);
         self.restore_if_exec_fail(closure)
     }
 
+    fn create_access_exhausted_table(&mut self) -> Result<()> {
+        let table = Table::new(ACCESS_EXHAUSTED_TABLE, self);
+        if table.exist()? {
+            return Ok(())
+        }
+        table.create(ACCESS_EXHAUSTED_COLUMN_INFO)
+    }
+
+    /// Consume one access of the data with the specified id, and run the callback (eg. decrypting the secret)
+    /// in the same transaction. The access is not consumed if the callback fails.
+    /// The data whose access count is exhausted is removed, and the later accesses to it are reported as exhausted.
+    /// If the operation is successful, the remaining access count is returned.
+    pub fn consume_access_count<F: FnOnce() -> Result<()>>(&mut self, id: u32, callback: F) -> Result<u32> {
+        let _lock = self.db_lock.mtx.lock().unwrap();
+        self.create_access_exhausted_table()?;
+        let table = Table::new(TABLE_NAME, self);
+        match table.consume_access_row(id, callback)? {
+            Some(remaining) => Ok(remaining),
+            None => macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound,
+                "[FATAL]The data with limited access count does not exist."),
+        }
+    }
+
+    /// Check whether the access count of the data matching the condition with alias has been exhausted.
+    pub fn is_access_exhausted(&mut self, condition: &DbMap) -> Result<bool> {
+        let _lock = self.db_lock.mtx.lock().unwrap();
+        if !condition.contains_key(column::ALIAS) {
+            return Ok(false);
+        }
+        let table = Table::new(ACCESS_EXHAUSTED_TABLE, self);
+        if !table.exist()? {
+            return Ok(false);
+        }
+        table.is_data_exists(&build_access_exhausted_data(condition), false)
+    }
+
+    /// Delete the records of exhausted access count matching the condition, eg. when the alias is added again.
+    /// If the operation is successful, the number of deleted records is returned.
+    pub fn delete_access_exhausted_datas(&mut self, condition: &DbMap) -> Result<i32> {
+        let _lock = self.db_lock.mtx.lock().unwrap();
+        let table = Table::new(ACCESS_EXHAUSTED_TABLE, self);
+        if !table.exist()? {
+            return Ok(0);
+        }
+        table.delete_row(&build_access_exhausted_data(condition), None, false)
+    }
+
+    /// Delete the records of exhausted access count of the aliases matching the condition, eg. when the aliases are
+    /// added again in batch. If the operation is successful, the number of deleted records is returned.
+    pub fn delete_access_exhausted_aliases(&mut self, condition: &DbMap, aliases: &[Vec<u8>]) -> Result<i32> {
+        let _lock = self.db_lock.mtx.lock().unwrap();
+        let table = Table::new(ACCESS_EXHAUSTED_TABLE, self);
+        if aliases.is_empty() || !table.exist()? {
+            return Ok(0);
+        }
+        table.delete_rows_by_aliases(&build_access_exhausted_data(condition), aliases)
+    }
+
+    /// Delete datas from database with specific condition.
+    /// If the operation is successful, the result of each alias is returned in the order of the aliases.
+    #[inline(always)]
+    pub fn delete_batch_datas(
+        &mut self,
+        condition: &DbMap,
+        update_datas: &DbMap,
+        aliases: &[Vec<u8>],
+    ) -> Result<Vec<BatchItemResult>> {
+        let _lock = self.db_lock.mtx.lock().unwrap();
+        let mut results = Vec::with_capacity(aliases.len());
+        let mut existing_aliases = Vec::with_capacity(aliases.len());
+        let mut alias_condition = condition.clone();
+        for (index, alias) in aliases.iter().enumerate() {
+            alias_condition.insert_attr(column::ALIAS, alias.clone());
+            if self.is_data_exists_without_lock(&alias_condition, true)? {
+                results.push(BatchItemResult::success(index as u32, alias.clone(), None));
+                existing_aliases.push(alias.clone());
+            } else {
+                results.push(BatchItemResult::failure(index as u32, alias.clone(), ErrCode::NotFound,
+                    "The data to remove is not found.".to_string()));
+            }
+        }
+        if existing_aliases.is_empty() {
+            return Ok(results);
+        }
+
+        let closure = |e: &Table| e.local_delete_batch_datas(condition, update_datas, &existing_aliases, true);
+        let count = self.restore_if_exec_fail(closure)?;
+        logi!("total removed count = {}", count);
+        Ok(results)
+    }
+
     /// Delete datas from database with specific condition.
     pub fn delete_adapt_data_without_lock(
         &mut self,
@@ -970,7 +1129,7 @@ impl Database {
         is_filter_sync: bool,
     ) -> Result<Vec<DbMap>> {
         let _lock = self.db_lock.mtx.lock().unwrap();
-        let closure = |e: &Table| e.query_row(columns, condition, query_options, is_filter_sync, COLUMN_INFO);
+        let closure = |e: &Table| e.query_row_with_custom_labels(columns, condition, query_options, is_filter_sync);
         self.restore_if_exec_fail(closure)
     }
 
@@ -1022,7 +1181,7 @@ impl Database {
         query_options: Option<&QueryOptions>,
         is_filter_sync: bool,
     ) -> Result<Vec<DbMap>> {
-        let closure = |e: &Table| e.query_row(columns, condition, query_options, is_filter_sync, COLUMN_INFO);
+        let closure = |e: &Table| e.query_row_with_custom_labels(columns, condition, query_options, is_filter_sync);
         self.restore_if_exec_fail(closure)
     }
 
@@ -1033,6 +1192,14 @@ impl Database {
         self.restore_if_exec_fail(closure)
     }
 
+    /// Count the data fitting the query condition which are not deleted by the synchronization, the amend is appended
+    /// to the where clause.
+    pub fn query_visible_data_count(&mut self, condition: &DbMap, amend: Option<&str>) -> Result<u32> {
+        let _lock = self.db_lock.mtx.lock().unwrap();
+        let closure = |e: &Table| e.count_datas_with_amend(condition, true, amend);
+        self.restore_if_exec_fail(closure)
+    }
+
     /// Delete old data and insert new data.
     pub fn replace_datas(&mut self, condition: &DbMap, is_filter_sync: bool, datas: &DbMap) -> Result<()> {
         let _lock = self.db_lock.mtx.lock().unwrap();
@@ -1040,6 +1207,25 @@ impl Database {
         self.restore_if_exec_fail(closure)
     }
 
+    /// Restore the datas matching the condition to the backup queried before they are written, eg. when a write
+    /// spanning several databases is withdrawn. The datas not in the backup are deleted.
+    pub fn restore_datas(&mut self, condition: &DbMap, backup: &[DbMap]) -> Result<()> {
+        let _lock = self.db_lock.mtx.lock().unwrap();
+        let closure = |e: &Table| e.restore_rows(condition, backup);
+        self.restore_if_exec_fail(closure)
+    }
+
+    /// Execute the writes in a single transaction. The write of each index is prepared right before it is executed, so
+    /// the preparation can query the rows written by the previous writes through the query function given.
+    /// If a write fails to be prepared or executed, all the writes are rolled back, and its index is returned.
+    pub fn exec_writes_in_transaction<F>(&mut self, count: usize, prepare: F) -> Result<Option<(usize, AssetError)>>
+    where
+        F: FnMut(usize, &dyn Fn(&DbMap) -> Result<Vec<DbMap>>) -> Result<DbWrite>,
+    {
+        let _lock = self.db_lock.mtx.lock().unwrap();
+        Table::new(TABLE_NAME, self).exec_writes_in_transaction(count, prepare)
+    }
+
     /// Get db lock.
     pub fn get_db_lock(&self) -> Result<Arc<Mutex<i32>>> {
         Ok(Arc::clone(&self.db_lock.mtx))
@@ -185,7 +185,7 @@ fn migrate_data(
 ) -> Result<()> {
     // 3.1 query data in old db
     let query_options =
-        QueryOptions { offset: None, limit: Some(MAX_BATCH_NUM), order_by: None, order: None, amend: None };
+        QueryOptions { offset: None, limit: Some(MAX_BATCH_NUM), order_by: None, amend: None };
 
     let old_data_vec = old_db.query_datas(&vec![], old_data_query_condition, Some(&query_options), false)?;
     // 3.2 insert data in new db
@@ -346,11 +346,11 @@ pub fn get_upgrade_version(user_id: i32) -> Result<OriginVersion> {
         version if version == OriginVersion::V1 as u32 => Ok(OriginVersion::V1),
         version if version == OriginVersion::V2 as u32 => Ok(OriginVersion::V2),
         version if version == OriginVersion::V3 as u32 => Ok(OriginVersion::V3),
-        _ => Err(AssetError { 
-            code: ErrCode::FileOperationError, 
-            msg: "Get upgrade version failed.".to_owned(),
-            call_chain: AssetError::shorten_func_name(macros_lib::hisysevent::function!()).to_string(),
-        }),
+        _ => Err(AssetError::new(
+            ErrCode::FileOperationError,
+            "Get upgrade version failed.".to_owned(),
+            macros_lib::hisysevent::function!(),
+        )),
     }
 }
 
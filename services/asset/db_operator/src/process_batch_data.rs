@@ -18,8 +18,8 @@ use std::collections::HashSet;
 
 use asset_common::CallingInfo;
 use asset_definition::{
-    AssetMap, Tag, Value, Result, macros_lib, ErrCode, WrapType, LocalStatus,
-    SyncType, SyncStatus,
+    AssetMap, Tag, Value, Result, macros_lib, ErrCode, ErrReason, Extension, WrapType, LocalStatus,
+    SyncType, SyncStatus, SecretBytes,
 };
 use asset_sdk::{Accessibility, AuthType};
 
@@ -28,8 +28,8 @@ use crate::{
     common::{
         TAG_COLUMN_TABLE, REQUIRED_ATTRS, CRITICAL_LABEL_ATTRS, NORMAL_LABEL_ATTRS, NORMAL_LOCAL_LABEL_ATTRS,
         ACCESS_CONTROL_ATTRS, ASSET_SYNC_ATTRS, OPTIONAL_ATTRS, check_accessibility_validity, check_value_validity,
-        check_sync_permission, check_wrap_permission, check_persistent_permission,
-        check_required_tags, check_group_validity,
+        check_sync_permission, check_wrap_permission, check_persistent_permission, check_expire_time_validity,
+        check_required_tags, check_group_validity, check_bio_policy_validity,
     }
 };
 
@@ -39,7 +39,7 @@ pub(crate) fn check_invalid_tags(attrs: &AssetMap) -> Result<()> {
     for tag in attrs.keys() {
         if INVALID_TAGS.contains(tag) {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
-                ErrCode::InvalidArgument, "[FATAL]The tag [{}] is illegal.", tag);
+                ErrCode::InvalidArgument, reason = ErrReason::IllegalTag, "[FATAL]The tag [{}] is illegal.", tag);
         }
     }
     Ok(())
@@ -58,6 +58,8 @@ fn check_array_arguments(attributes: &AssetMap, calling_info: &CallingInfo) -> R
     check_accessibility_validity(attributes, calling_info)?;
     check_sync_permission(attributes, calling_info)?;
     check_wrap_permission(attributes, calling_info)?;
+    check_expire_time_validity(attributes)?;
+    check_bio_policy_validity(attributes)?;
     check_persistent_permission(attributes)
 }
 
@@ -84,6 +86,7 @@ fn add_default_batch_attrs(db_data: &mut DbMap) {
     db_data.entry(column::LOCAL_STATUS).or_insert(Value::Number(LocalStatus::Local as u32));
     db_data.entry(column::SYNC_STATUS).or_insert(Value::Number(SyncStatus::SyncAdd as u32));
     db_data.entry(column::WRAP_TYPE).or_insert(Value::Number(WrapType::default() as u32));
+    db_data.entry(column::REVISION).or_insert(Value::Number(0));
 }
 
 fn is_only_change_local_labels(update: &AssetMap) -> bool {
@@ -121,14 +124,17 @@ pub(crate) fn add_not_null_column(column_names: &mut HashSet<String>) {
     column_names.insert(column::LOCAL_STATUS.to_string());
     column_names.insert(column::SYNC_STATUS.to_string());
     column_names.insert(column::WRAP_TYPE.to_string());
+    column_names.insert(column::REVISION.to_string());
 }
 
+// The secret is moved out of the attributes after they are checked, and it is returned apart from the DbMap.
 pub(crate) fn parse_attr_in_array(
-    attributes: &AssetMap,
+    attributes: &mut AssetMap,
     calling_info: &CallingInfo,
     column_names: &mut HashSet<String>,
-) -> Result<DbMap> {
+) -> Result<(DbMap, SecretBytes)> {
     check_array_arguments(attributes, calling_info)?;
+    let secret = attributes.take_secret_attr(&Tag::Secret)?;
     let mut db_data = into_db_map_with_column_names(attributes, column_names);
     if let Some(group) = calling_info.group() {
         column_names.insert((&column::GROUP_ID).to_string());
@@ -138,5 +144,10 @@ pub(crate) fn parse_attr_in_array(
         check_group_validity(&attr, calling_info)?;
     };
     add_default_batch_attrs(&mut db_data);
-    Ok(db_data)
+    // The maximum access count is kept as configured, while the remaining one is consumed by each read.
+    if let Some(max_access_count) = db_data.get(column::MAX_ACCESS_COUNT).cloned() {
+        column_names.insert(column::REMAINING_ACCESS_COUNT.to_string());
+        db_data.insert(column::REMAINING_ACCESS_COUNT, max_access_count);
+    }
+    Ok((db_data, secret))
 }
@@ -54,6 +54,11 @@ int SqliteChanges(void *db)
     return sqlite3_changes((sqlite3 *)db);
 }
 
+int64_t SqliteLastInsertRowId(void *db)
+{
+    return sqlite3_last_insert_rowid((sqlite3 *)db);
+}
+
 int SqlitePrepareV2(void *db, const char *zSql, void **ppStmt, const char **pzTail)
 {
     return sqlite3_prepare_v2((sqlite3 *)db, zSql, -1, (sqlite3_stmt **)ppStmt, pzTail);
@@ -107,7 +107,6 @@ impl<'b> Statement<'b> {
     }
 
     /// Reset statement before bind data for insert statement.
-    #[allow(dead_code)]
     pub(crate) fn reset(&self) -> Result<()> {
         let ret = unsafe { SqliteReset(self.handle as _) };
         if ret != SQLITE_OK {
@@ -135,6 +134,31 @@ impl<'b> Statement<'b> {
         }
     }
 
+    /// Bind byte array to prepared statement without copying, the bytes must live until the statement is finalized.
+    /// The index is start from 1.
+    pub(crate) fn bind_bytes(&self, index: i32, data: &[u8]) -> Result<()> {
+        let ret = unsafe { SqliteBindBlob(self.handle as _, index, data.as_ptr(), data.len() as _, None) };
+        if ret != SQLITE_OK {
+            self.db.print_db_msg();
+            macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                sqlite_err_handle(ret), "Bind bytes failed, index={}, err={}", index, ret)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Bind 64-bit integer to prepared statement. The index is start from 1.
+    pub(crate) fn bind_int64(&self, index: i32, data: i64) -> Result<()> {
+        let ret = unsafe { SqliteBindInt64(self.handle as _, index, data) };
+        if ret != SQLITE_OK {
+            self.db.print_db_msg();
+            macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                sqlite_err_handle(ret), "Bind int64 failed, index={}, err={}", index, ret)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Bind data to prepared statement. The index is start from 1.
     pub(crate) fn bind_data_or_none(&self, index: i32, data: Option<&Value>) -> Result<()> {
         let ret = match data {
@@ -17,25 +17,31 @@
 //! The managed data can be user input. Because we will prepare and bind data.
 
 use core::ffi::c_void;
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::HashMap};
 
-use asset_definition::{macros_lib, Conversion, DataType, ErrCode, Extension, Result, SyncType, Value, SyncStatus};
+use asset_definition::{
+    macros_lib, AssetError, Conversion, CustomLabel, DataType, ErrCode, ErrReason, Extension, Predicate, Result,
+    SyncType, Value, SyncStatus
+};
 use asset_log::logi;
 use asset_utils::time;
 use asset_common::OwnerType;
 
 use crate::{
+    common::get_column_name,
     database::Database,
     statement::Statement,
     transaction::Transaction,
     types::{
-        adapt_column, column, ColumnInfo, DbMap, QueryOptions, UpgradeColumnInfo,
-        ADAPT_CLOUD_TABLE, COLUMN_INFO, DB_UPGRADE_VERSION, SQLITE_ROW
+        adapt_column, column, label_column, ColumnInfo, DbMap, DbWrite, QueryOptions, UpgradeColumnInfo,
+        ACCESS_EXHAUSTED_COLUMN_INFO, ACCESS_EXHAUSTED_TABLE, ADAPT_CLOUD_TABLE, COLUMN_INFO, CUSTOM_LABEL_COLUMNS,
+        CUSTOM_LABEL_COLUMN_INFO, CUSTOM_LABEL_TABLE, DB_UPGRADE_VERSION, SQLITE_ROW
     },
 };
 
 extern "C" {
     fn SqliteChanges(db: *mut c_void) -> i32;
+    fn SqliteLastInsertRowId(db: *mut c_void) -> i64;
 }
 
 #[repr(C)]
@@ -62,8 +68,74 @@ fn bind_datas_array(datas: &DbMap, stmt: &Statement, index: &mut i32, column_nam
     Ok(())
 }
 
+fn get_custom_label_kind(column_name: &str) -> Option<u32> {
+    CUSTOM_LABEL_COLUMNS.iter().find(|(name, _)| *name == column_name).map(|(_, kind)| *kind)
+}
+
+fn split_custom_label_condition(value: &Value) -> Option<Vec<(&[u8], &[u8])>> {
+    match value {
+        Value::Bytes(bytes) => CustomLabel::split_list(bytes).ok().filter(|labels| !labels.is_empty()),
+        _ => None,
+    }
+}
+
+// The name and value of labels are bound without copying, which are borrowed from the condition.
+fn bind_custom_labels(kind: u32, value: &Value, stmt: &Statement, index: &mut i32) -> Result<()> {
+    for (name, label_value) in split_custom_label_condition(value).unwrap_or_default() {
+        stmt.bind_data(*index, &Value::Number(kind))?;
+        stmt.bind_bytes(*index + 1, name)?;
+        stmt.bind_bytes(*index + 2, label_value)?;
+        *index += 3;
+    }
+    Ok(())
+}
+
+fn split_query_filter_condition(value: &Value) -> Option<Vec<Predicate>> {
+    match value {
+        Value::Bytes(bytes) => Predicate::decode_list(bytes).ok().filter(|predicates| !predicates.is_empty()),
+        _ => None,
+    }
+}
+
+// The operands of predicates are bound without copying, which are borrowed from the condition.
+fn bind_query_filter(value: &Value, stmt: &Statement, index: &mut i32) -> Result<()> {
+    for predicate in split_query_filter_condition(value).unwrap_or_default() {
+        if get_column_name(predicate.tag()).is_none() {
+            continue;
+        }
+        match predicate {
+            Predicate::Prefix(_, prefix) => {
+                stmt.bind_data(*index, &Value::Number(prefix.len() as u32))?;
+                stmt.bind_bytes(*index + 1, prefix)?;
+                *index += 2;
+            },
+            Predicate::Range(_, from, to) => {
+                for bound in [from, to].into_iter().flatten() {
+                    stmt.bind_int64(*index, i64::try_from(bound).unwrap_or(i64::MAX))?;
+                    *index += 1;
+                }
+            },
+            Predicate::In(_, values) => {
+                for value in values {
+                    stmt.bind_bytes(*index, value)?;
+                    *index += 1;
+                }
+            },
+        }
+    }
+    Ok(())
+}
+
 fn bind_where_datas(datas: &DbMap, stmt: &Statement, index: &mut i32) -> Result<()> {
     for (key, value) in datas.iter() {
+        if let Some(kind) = get_custom_label_kind(key) {
+            bind_custom_labels(kind, value, stmt, index)?;
+            continue;
+        }
+        if *key == column::QUERY_FILTER {
+            bind_query_filter(value, stmt, index)?;
+            continue;
+        }
         if *key == "SyncType" {
             stmt.bind_data(*index, value)?;
             *index += 1;
@@ -138,6 +210,10 @@ fn build_sql_where(conditions: &DbMap, filter: bool, sql: &mut String) {
             for (i, column_name) in conditions.keys().enumerate() {
                 if *column_name == "SyncType" {
                     sql.push_str("(SyncType & ?) = ?");
+                } else if get_custom_label_kind(column_name).is_some() {
+                    build_sql_custom_labels(&conditions[column_name], sql);
+                } else if *column_name == column::QUERY_FILTER {
+                    build_sql_query_filter(&conditions[column_name], sql);
                 } else {
                     sql.push_str(column_name);
                     sql.push_str("=?");
@@ -150,6 +226,58 @@ fn build_sql_where(conditions: &DbMap, filter: bool, sql: &mut String) {
     }
 }
 
+// Each custom label in the condition is matched by a sub query on the custom label table.
+fn build_sql_custom_labels(value: &Value, sql: &mut String) {
+    let Some(labels) = split_custom_label_condition(value) else {
+        // The malformed or empty custom labels match nothing.
+        sql.push('0');
+        return;
+    };
+    for i in 0..labels.len() {
+        sql.push_str(&format!("{} in (select {} from {} where {}=? and {}=? and {}=?)", column::ID,
+            label_column::ASSET_ID, CUSTOM_LABEL_TABLE, label_column::KIND, label_column::NAME, label_column::VALUE));
+        if i != labels.len() - 1 {
+            sql.push_str(" and ");
+        }
+    }
+}
+
+// Each predicate in the condition is matched by the parameterized expression on its column.
+fn build_sql_query_filter(value: &Value, sql: &mut String) {
+    let Some(predicates) = split_query_filter_condition(value) else {
+        // The malformed or empty predicates match nothing.
+        sql.push('0');
+        return;
+    };
+    for (i, predicate) in predicates.iter().enumerate() {
+        match (get_column_name(predicate.tag()), predicate) {
+            (Some(column_name), Predicate::Prefix(..)) => {
+                sql.push_str(&format!("substr({},1,?)=?", column_name));
+            },
+            (Some(column_name), Predicate::Range(_, from, to)) => {
+                // The time is stored as the decimal string of milliseconds, so it is compared as an integer.
+                let mut bounds = vec![String::from("1")];
+                if from.is_some() {
+                    bounds.push(format!("cast({} as integer)>=?", column_name));
+                }
+                if to.is_some() {
+                    bounds.push(format!("cast({} as integer)<=?", column_name));
+                }
+                sql.push_str(&format!("({})", bounds.join(" and ")));
+            },
+            (Some(column_name), Predicate::In(_, values)) if !values.is_empty() => {
+                sql.push_str(&format!("{} in (", column_name));
+                build_sql_values(values.len(), sql);
+                sql.push(')');
+            },
+            _ => sql.push('0'),
+        }
+        if i != predicates.len() - 1 {
+            sql.push_str(" and ");
+        }
+    }
+}
+
 #[inline(always)]
 fn build_sql_alias_list(len: usize, sql: &mut String) {
     sql.push_str(" and Alias in (");
@@ -200,20 +328,16 @@ fn build_sql_query_options(query_options: Option<&QueryOptions>, sql: &mut Strin
         }
         if let Some(order_by) = &option.order_by {
             if !order_by.is_empty() {
+                // Each column is sorted in its own order, the earlier column takes precedence.
+                let columns: Vec<String> = order_by.iter().map(|(column, order)| match order {
+                    Ordering::Greater => format!("{} ASC", column),
+                    Ordering::Less => format!("{} DESC", column),
+                    Ordering::Equal => column.to_string(),
+                }).collect();
                 sql.push_str(" order by ");
-                build_sql_columns_not_empty(order_by, sql);
+                sql.push_str(&columns.join(","));
             }
         }
-        if let Some(order) = option.order {
-            let str = if order == Ordering::Greater {
-                "ASC"
-            } else if order == Ordering::Less {
-                "DESC"
-            } else {
-                ""
-            };
-            sql.push_str(format!(" {}", str).as_str());
-        }
         if let Some(limit) = option.limit {
             sql.push_str(format!(" limit {}", limit).as_str());
             if let Some(offset) = option.offset {
@@ -248,6 +372,61 @@ fn build_sql_reverse_condition(condition: &DbMap, reverse_condition: Option<&DbM
     }
 }
 
+/// Extract the columns that identify an Asset whose access count is exhausted.
+pub(crate) fn build_access_exhausted_data(datas: &DbMap) -> DbMap {
+    let mut exhausted_data = DbMap::new();
+    for column_info in ACCESS_EXHAUSTED_COLUMN_INFO.iter().filter(|info| !info.is_primary_key) {
+        if let Some(value) = datas.get(column_info.name) {
+            exhausted_data.insert(column_info.name, value.clone());
+        }
+    }
+    exhausted_data
+}
+
+// Query the custom labels of the asset with the prepared statement, which are grouped by label column.
+fn query_custom_labels(stmt: &Statement, asset_id: u32) -> Result<HashMap<&'static str, Vec<CustomLabel>>> {
+    stmt.reset()?;
+    stmt.bind_data(1, &Value::Number(asset_id))?;
+    let mut labels: HashMap<&'static str, Vec<CustomLabel>> = HashMap::new();
+    while stmt.step()? == SQLITE_ROW {
+        let row = (stmt.query_column_auto_type(0)?, stmt.query_column_auto_type(1)?, stmt.query_column_auto_type(2)?);
+        let (Some(Value::Number(kind)), Some(Value::Bytes(name)), Some(Value::Bytes(value))) = row else {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::DataCorrupted, "The data in DB has been tampered with.");
+        };
+        let Some((column_name, _)) = CUSTOM_LABEL_COLUMNS.iter().find(|(_, label_kind)| *label_kind == kind) else {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::DataCorrupted, "The data in DB has been tampered with.");
+        };
+        labels.entry(*column_name).or_default().push(CustomLabel { name, value });
+    }
+    Ok(labels)
+}
+
+fn has_custom_labels(datas: &DbMap) -> bool {
+    CUSTOM_LABEL_COLUMNS.iter().any(|(name, _)| datas.contains_key(name))
+}
+
+// Split the datas into the datas stored in the asset table and the custom labels with their kinds.
+fn split_custom_label_datas(datas: &DbMap) -> Result<(DbMap, Vec<(u32, CustomLabel)>)> {
+    let mut asset_datas = datas.clone();
+    let mut labels = vec![];
+    for (column_name, kind) in CUSTOM_LABEL_COLUMNS {
+        match asset_datas.remove(column_name) {
+            Some(Value::Bytes(bytes)) => {
+                labels.extend(CustomLabel::decode_list(&bytes)?.into_iter().map(|label| (kind, label)));
+            },
+            Some(_) => {
+                return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                    ErrCode::InvalidArgument, reason = ErrReason::DataTypeMismatch,
+                    "[FATAL]The custom labels of [{}] are not bytes.", column_name);
+            },
+            None => (),
+        }
+    }
+    Ok((asset_datas, labels))
+}
+
 fn get_column_info(columns: &'static [ColumnInfo], db_column: &str) -> Result<&'static ColumnInfo> {
     for column in columns.iter() {
         if column.name.eq(db_column) {
@@ -323,11 +502,68 @@ impl<'a> Table<'a> {
         self.create_with_version(columns, DB_UPGRADE_VERSION)
     }
 
+    /// Create the custom label table of the asset table,
+    /// and a trigger to delete the custom labels along with the asset they belong to.
+    pub(crate) fn create_custom_label_table(&self) -> Result<()> {
+        Table::new(CUSTOM_LABEL_TABLE, self.db).create(CUSTOM_LABEL_COLUMN_INFO)?;
+        let sql =
+            format!("CREATE INDEX IF NOT EXISTS {0}_index ON {0}({1})", CUSTOM_LABEL_TABLE, label_column::ASSET_ID);
+        self.db.exec(&sql)?;
+        let sql = format!(
+            "CREATE TRIGGER IF NOT EXISTS {0}_trigger AFTER DELETE ON {1} BEGIN DELETE FROM {0} WHERE {2}=old.{3}; END",
+            CUSTOM_LABEL_TABLE, self.table_name, label_column::ASSET_ID, column::ID
+        );
+        self.db.exec(&sql)
+    }
+
+    // Execute the function in a savepoint, which can be nested in a transaction.
+    fn exec_in_savepoint<T, F: FnOnce() -> Result<T>>(&self, func: F) -> Result<T> {
+        self.db.exec("savepoint custom_label")?;
+        match func() {
+            Ok(ret) => {
+                self.db.exec("release custom_label")?;
+                Ok(ret)
+            },
+            Err(e) => {
+                self.db.exec("rollback to custom_label")?;
+                self.db.exec("release custom_label")?;
+                Err(e)
+            },
+        }
+    }
+
+    fn insert_custom_labels(&self, asset_id: u32, labels: &[(u32, CustomLabel)]) -> Result<()> {
+        for (kind, label) in labels {
+            let datas = DbMap::from([
+                (label_column::ASSET_ID, Value::Number(asset_id)),
+                (label_column::KIND, Value::Number(*kind)),
+                (label_column::NAME, Value::Bytes(label.name.clone())),
+                (label_column::VALUE, Value::Bytes(label.value.clone())),
+            ]);
+            self.insert_row_with_table_name(&datas, CUSTOM_LABEL_TABLE)?;
+        }
+        Ok(())
+    }
+
+    // Replace the custom labels with the same names, and the label with an empty value is removed.
+    fn replace_custom_labels(&self, asset_id: u32, labels: &[(u32, CustomLabel)]) -> Result<()> {
+        for (kind, label) in labels {
+            let condition = DbMap::from([
+                (label_column::ASSET_ID, Value::Number(asset_id)),
+                (label_column::KIND, Value::Number(*kind)),
+                (label_column::NAME, Value::Bytes(label.name.clone())),
+            ]);
+            self.delete_row_with_table_name(&condition, None, false, CUSTOM_LABEL_TABLE)?;
+        }
+        let labels: Vec<(u32, CustomLabel)> =
+            labels.iter().filter(|(_, label)| !label.value.is_empty()).cloned().collect();
+        self.insert_custom_labels(asset_id, &labels)
+    }
+
     fn is_column_exist(&self, column: &'static str) -> bool {
         let query_option = QueryOptions {
             offset: None,
             limit: Some(1),
-            order: None,
             order_by: None,
             amend: None
         };
@@ -368,7 +604,16 @@ impl<'a> Table<'a> {
     /// let ret = table.insert_row(datas);
     /// ```
     pub(crate) fn insert_row(&self, datas: &DbMap) -> Result<i32> {
-        self.insert_row_with_table_name(datas, &self.table_name)
+        if !has_custom_labels(datas) {
+            return self.insert_row_with_table_name(datas, &self.table_name);
+        }
+        let (datas, labels) = split_custom_label_datas(datas)?;
+        self.exec_in_savepoint(|| {
+            let count = self.insert_row_with_table_name(&datas, &self.table_name)?;
+            let asset_id = unsafe { SqliteLastInsertRowId(self.db.handle as _) };
+            self.insert_custom_labels(asset_id as u32, &labels)?;
+            Ok(count)
+        })
     }
 
     pub(crate) fn insert_row_with_table_name(&self, datas: &DbMap, table_name: &str) -> Result<i32> {
@@ -502,6 +747,128 @@ impl<'a> Table<'a> {
         Ok(count)
     }
 
+    fn mark_expired_sync_rows(&self, condition: &DbMap, expire_time: &Value, update_time: &Value) -> Result<i32> {
+        let mut sql = format!("update {} set {}=?,{}=?", self.table_name, column::SYNC_STATUS, column::UPDATE_TIME);
+        build_sql_where(condition, true, &mut sql);
+        sql.push_str(&format!(" and {0} is not null and {0} <= ?", column::EXPIRE_TIME));
+        build_sql_sync(&mut sql);
+        let stmt = Statement::prepare(&sql, self.db)?;
+        let mut index = 1;
+        bind_not_sync(&stmt, &Value::Number(SyncStatus::SyncDel as u32), &mut index)?;
+        bind_not_sync(&stmt, update_time, &mut index)?;
+        bind_where_datas(condition, &stmt, &mut index)?;
+        bind_not_sync(&stmt, expire_time, &mut index)?;
+        bind_sync(&stmt, &Value::Number(SyncType::TrustedAccount as u32), &mut index)?;
+        stmt.step()?;
+        let count = unsafe { SqliteChanges(self.db.handle as _) };
+        Ok(count)
+    }
+
+    fn delete_expired_local_rows(&self, condition: &DbMap, expire_time: &Value) -> Result<i32> {
+        let mut sql = format!("delete from {}", self.table_name);
+        build_sql_where(condition, false, &mut sql);
+        sql.push_str(if condition.is_empty() { " where " } else { " and " });
+        sql.push_str(&format!("{0} is not null and {0} <= ?", column::EXPIRE_TIME));
+        build_sql_not_sync(&mut sql);
+        let stmt = Statement::prepare(&sql, self.db)?;
+        let mut index = 1;
+        bind_where_datas(condition, &stmt, &mut index)?;
+        bind_not_sync(&stmt, expire_time, &mut index)?;
+        bind_not_sync(&stmt, &Value::Number(SyncType::TrustedAccount as u32), &mut index)?;
+        stmt.step()?;
+        let count = unsafe { SqliteChanges(self.db.handle as _) };
+        Ok(count)
+    }
+
+    /// Delete the rows matching the condition whose expiration time is not later than the specified time.
+    /// The expired rows synchronized by trusted account are marked as deleted and left to the cloud synchronization.
+    /// If the operation is successful, the number of deleted rows and marked rows is returned.
+    pub(crate) fn delete_expired_rows(
+        &self,
+        condition: &DbMap,
+        expire_time: u32,
+        update_time: &Value,
+    ) -> Result<(i32, i32)> {
+        let expire_time = Value::Number(expire_time);
+        let mut trans = Transaction::new(self.db);
+        trans.begin()?;
+        let mark_num = match self.mark_expired_sync_rows(condition, &expire_time, update_time) {
+            Ok(count) => count,
+            Err(e) => {
+                trans.rollback()?;
+                return Err(e);
+            },
+        };
+        let delete_num = match self.delete_expired_local_rows(condition, &expire_time) {
+            Ok(count) => count,
+            Err(e) => {
+                trans.rollback()?;
+                return Err(e);
+            },
+        };
+        trans.commit()?;
+        logi!("delete expired data count = {}, mark expired sync data count = {}", delete_num, mark_num);
+        Ok((delete_num, mark_num))
+    }
+
+    fn decrease_access_count(&self, id: u32) -> Result<Option<u32>> {
+        let condition = DbMap::from([(column::ID, Value::Number(id))]);
+        let rows = self.query_row(&vec![], &condition, None, true, COLUMN_INFO)?;
+        let Some(row) = rows.first() else {
+            return Ok(None);
+        };
+        let Some(Value::Number(remaining)) = row.get(column::REMAINING_ACCESS_COUNT) else {
+            return Ok(None);
+        };
+        let remaining = remaining.saturating_sub(1);
+        if remaining > 0 {
+            let datas = DbMap::from([(column::REMAINING_ACCESS_COUNT, Value::Number(remaining))]);
+            self.update_row(&condition, true, &datas)?;
+            return Ok(Some(remaining));
+        }
+
+        match row.get(column::SYNC_TYPE) {
+            Some(Value::Number(sync_type)) if (sync_type & SyncType::TrustedAccount as u32) != 0 => {
+                let datas = DbMap::from([
+                    (column::SYNC_STATUS, Value::Number(SyncStatus::SyncDel as u32)),
+                    (column::UPDATE_TIME, Value::Bytes(time::system_time_in_millis()?)),
+                ]);
+                self.update_row(&condition, true, &datas)?;
+            },
+            _ => {
+                self.delete_row(&condition, None, true)?;
+            },
+        }
+        self.insert_row_with_table_name(&build_access_exhausted_data(row), ACCESS_EXHAUSTED_TABLE)?;
+        Ok(Some(remaining))
+    }
+
+    /// Consume one access of the row with the specified id, and run the callback in the same transaction.
+    /// The row is removed and recorded in the access exhausted table once its last access is consumed.
+    /// If the operation is successful, the remaining access count is returned,
+    /// or None if the row with limited access count does not exist.
+    pub(crate) fn consume_access_row<F: FnOnce() -> Result<()>>(&self, id: u32, callback: F) -> Result<Option<u32>> {
+        let mut trans = Transaction::new(self.db);
+        trans.begin()?;
+        let remaining = match self.decrease_access_count(id) {
+            Ok(Some(remaining)) => remaining,
+            Ok(None) => {
+                trans.rollback()?;
+                return Ok(None);
+            },
+            Err(e) => {
+                trans.rollback()?;
+                return Err(e);
+            },
+        };
+        if let Err(e) = callback() {
+            trans.rollback()?;
+            return Err(e);
+        }
+        trans.commit()?;
+        Ok(Some(remaining))
+    }
+
     fn update_sync_datas_by_aliases(&self, condition: &DbMap, datas: &DbMap, aliases: &[Value]) -> Result<i32> {
         let mut sql = format!("update {} set ", self.table_name);
         for (i, column_name) in datas.keys().enumerate() {
@@ -527,6 +894,11 @@ impl<'a> Table<'a> {
         Ok(count)
     }
 
+    pub(crate) fn delete_rows_by_aliases(&self, condition: &DbMap, aliases: &[Vec<u8>]) -> Result<i32> {
+        let alias_values: Vec<Value> = aliases.iter().map(|alias| Value::Bytes(alias.clone())).collect();
+        self.delete_local_datas_by_aliases(condition, &alias_values, false)
+    }
+
     fn delete_local_datas_by_aliases(&self, condition: &DbMap, aliases: &[Value], need_sync: bool) -> Result<i32> {
         let mut sql = format!("delete from {}", self.table_name);
         build_sql_where(condition, need_sync, &mut sql);
@@ -584,7 +956,8 @@ impl<'a> Table<'a> {
         Ok(count)
     }
 
-    fn insert_batch_datas_inner(&self, datas_array: &[DbMap], column_names: &Vec<String>) -> Result<()> {
+    // The rows are inserted one by one with the same statement, the ids of which are returned in order.
+    fn insert_batch_datas_inner(&self, datas_array: &[DbMap], column_names: &Vec<String>) -> Result<Vec<u32>> {
         if datas_array.is_empty() || column_names.is_empty() {
             return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
                 ErrCode::InvalidArgument, "Data array is empty.");
@@ -595,24 +968,19 @@ impl<'a> Table<'a> {
             sql.push(',');
         }
         sql.pop();
-        sql.push_str(") values ");
-
-        for _ in 0..datas_array.len() {
-            sql.push('(');
-            build_sql_values(column_names.len(), &mut sql);
-            sql.push(')');
-            sql.push(',');
-        }
-        sql.pop();
-        sql.push(';');
-        let mut index = 1;
+        sql.push_str(") values (");
+        build_sql_values(column_names.len(), &mut sql);
+        sql.push_str(");");
         let stmt = Statement::prepare(&sql, self.db)?;
+        let mut ids = Vec::with_capacity(datas_array.len());
         for datas in datas_array {
+            stmt.reset()?;
+            let mut index = 1;
             bind_datas_array(datas, &stmt, &mut index, column_names)?;
+            stmt.step()?;
+            ids.push(unsafe { SqliteLastInsertRowId(self.db.handle as _) } as u32);
         }
-        stmt.step()?;
-        let _count = unsafe { SqliteChanges(self.db.handle as _) };
-        Ok(())
+        Ok(ids)
     }
 
     pub(crate) fn local_insert_batch_datas(
@@ -621,7 +989,7 @@ impl<'a> Table<'a> {
         db_map: &DbMap,
         aliases: &[Vec<u8>],
         column_names: &Vec<String>
-    ) -> Result<()> {
+    ) -> Result<Vec<u32>> {
         let mut trans = Transaction::new(self.db);
         trans.begin()?;
         let mut condition = DbMap::new();
@@ -637,18 +1005,23 @@ impl<'a> Table<'a> {
             trans.rollback()?;
             return Err(e);
         }
-        if let Err(e) = self.insert_batch_datas_inner(db_data_array, column_names) {
-            trans.rollback()?;
-            return Err(e);
-        }
-        trans.commit()
+        let ids = match self.insert_batch_datas_inner(db_data_array, column_names) {
+            Ok(ids) => ids,
+            Err(e) => {
+                trans.rollback()?;
+                return Err(e);
+            },
+        };
+        trans.commit()?;
+        Ok(ids)
     }
 
     pub(crate) fn local_update_batch_datas(
         &self,
         db_data_array: &[DbMap],
         db_map: &DbMap,
-        aliases: &[Vec<u8>]
+        aliases: &[Vec<u8>],
+        revision_conditions: &[DbMap],
     ) -> Result<()> {
         let mut trans = Transaction::new(self.db);
         trans.begin()?;
@@ -658,18 +1031,29 @@ impl<'a> Table<'a> {
         condition.insert_attr(column::OWNER, owner_info.clone());
         condition.insert_attr(column::OWNER_TYPE, owner_type);
 
-        for (alias, db_data) in aliases.iter().zip(db_data_array.iter()) {
-            condition.insert_attr(column::ALIAS, alias.clone());
-            if let Err(e) = self.update_row(&condition, true, db_data) {
-                trans.rollback()?;
-                return Err(e);
+        for ((alias, db_data), revision_condition) in aliases.iter().zip(db_data_array).zip(revision_conditions) {
+            let mut item_condition = condition.clone();
+            item_condition.insert_attr(column::ALIAS, alias.clone());
+            item_condition.extend(revision_condition.clone());
+            match self.update_row(&item_condition, true, db_data) {
+                Ok(0) => {
+                    trans.rollback()?;
+                    return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                        ErrCode::RevisionConflict, reason = ErrReason::RevisionMismatch,
+                        "[FATAL]The asset has been modified concurrently.");
+                },
+                Ok(_) => (),
+                Err(e) => {
+                    trans.rollback()?;
+                    return Err(e);
+                },
             }
         }
 
         trans.commit()
     }
 
-    /// Update a row in table.
+    /// Update a row in table, the custom labels in datas are added or replaced by name.
     ///
     /// # Examples
     ///
@@ -680,6 +1064,25 @@ impl<'a> Table<'a> {
     /// let ret = table.update_row(conditions, false, datas);
     /// ```
     pub(crate) fn update_row(&self, condition: &DbMap, is_filter_sync: bool, datas: &DbMap) -> Result<i32> {
+        if !has_custom_labels(datas) {
+            return self.update_asset_row(condition, is_filter_sync, datas);
+        }
+        let (datas, labels) = split_custom_label_datas(datas)?;
+        self.exec_in_savepoint(|| {
+            let rows = self.query_row(&vec![column::ID], condition, None, is_filter_sync, COLUMN_INFO)?;
+            let count = if datas.is_empty() {
+                rows.len() as i32
+            } else {
+                self.update_asset_row(condition, is_filter_sync, &datas)?
+            };
+            for row in &rows {
+                self.replace_custom_labels(row.get_num_attr(&column::ID)?, &labels)?;
+            }
+            Ok(count)
+        })
+    }
+
+    fn update_asset_row(&self, condition: &DbMap, is_filter_sync: bool, datas: &DbMap) -> Result<i32> {
         let mut sql = format!("update {} set ", self.table_name);
         for (i, column_name) in datas.keys().enumerate() {
             sql.push_str(column_name);
@@ -752,6 +1155,33 @@ impl<'a> Table<'a> {
         Ok(result)
     }
 
+    /// Query row from the asset table.
+    /// If length of columns is 0, all table columns are queried, and the custom labels are attached to the rows.
+    pub(crate) fn query_row_with_custom_labels(
+        &self,
+        columns: &Vec<&'static str>,
+        condition: &DbMap,
+        query_options: Option<&QueryOptions>,
+        is_filter_sync: bool,
+    ) -> Result<Vec<DbMap>> {
+        let mut rows = self.query_row(columns, condition, query_options, is_filter_sync, COLUMN_INFO)?;
+        if !columns.is_empty() || rows.is_empty() {
+            return Ok(rows);
+        }
+        // Only the labels of the returned rows are queried, which are limited by the query options.
+        let sql = format!(
+            "select {},{},{} from {} where {}=?",
+            label_column::KIND, label_column::NAME, label_column::VALUE, CUSTOM_LABEL_TABLE, label_column::ASSET_ID
+        );
+        let stmt = Statement::prepare(&sql, self.db)?;
+        for row in rows.iter_mut() {
+            for (column_name, column_labels) in query_custom_labels(&stmt, row.get_num_attr(&column::ID)?)? {
+                row.insert(column_name, Value::Bytes(CustomLabel::encode_list(&column_labels)));
+            }
+        }
+        Ok(rows)
+    }
+
     /// Query row from table.
     /// If length of columns is 0, all table columns are queried. (eg. select * xxx)
     /// If length of condition is 0, all data in the table is queried.
@@ -820,8 +1250,29 @@ impl<'a> Table<'a> {
     /// let count = table.count_datas(&DbMap::from([("id", Value::Number(3))]), false);
     /// ```
     pub(crate) fn count_datas(&self, condition: &DbMap, is_filter_sync: bool) -> Result<u32> {
+        self.count_datas_with_amend(condition, is_filter_sync, None)
+    }
+
+    /// Count the number of datas with query condition(can be empty), the amend is appended to the where clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// // SQL: select count(*) as count from table_name where SyncStatus <> 2 and id=3 and (ExpireTime is null)
+    /// let count = table.count_datas_with_amend(
+    ///     &DbMap::from([("id", Value::Number(3))]), true, Some(" and (ExpireTime is null)"));
+    /// ```
+    pub(crate) fn count_datas_with_amend(
+        &self,
+        condition: &DbMap,
+        is_filter_sync: bool,
+        amend: Option<&str>,
+    ) -> Result<u32> {
         let mut sql = format!("select count(*) as count from {}", self.table_name);
         build_sql_where(condition, is_filter_sync, &mut sql);
+        if let Some(amend) = amend {
+            sql.push_str(amend);
+        }
         let stmt = Statement::prepare(&sql, self.db)?;
         let mut index = 1;
         bind_where_datas(condition, &stmt, &mut index)?;
@@ -891,29 +1342,24 @@ impl<'a> Table<'a> {
         let mut trans = Transaction::new(self.db);
         trans.begin()?;
     
-        let result = (|| -> Result<()> {
-            let mut new_row = datas.clone();
-            let cols = vec![column::SYNC_TYPE, column::CLOUD_VERSION, column::GLOBAL_ID];
-            if let Ok(rows) = self.query_row(&cols, condition, None, false, COLUMN_INFO) {
-                if !rows.is_empty() {
-                    let old_row = rows.first().unwrap();
-                    let trusted_acc = SyncType::TrustedAccount as u32;
-                    if (old_row.get_num_attr(&column::SYNC_TYPE)? & trusted_acc) == trusted_acc
-                        && (new_row.get_num_attr(&column::SYNC_TYPE)? & trusted_acc) == trusted_acc
-                    {
-                        if let Some(cloud_ver) = old_row.get(column::CLOUD_VERSION) {
-                            new_row.insert(column::CLOUD_VERSION, cloud_ver.clone());
-                        }
-                        if let Some(global_id) = old_row.get(column::GLOBAL_ID) {
-                            new_row.insert(column::GLOBAL_ID, global_id.clone());
-                        }
-                    }
-                }
+        let result = self.replace_row_without_transaction(condition, is_filter_sync, datas);
+
+        match result {
+            Ok(()) => trans.commit(),
+            Err(e) => {
+                trans.rollback()?;
+                Err(e)
             }
-            self.delete_row(condition, None, is_filter_sync)?;
-            self.insert_row(&new_row)?;
-            Ok(())
-        })();
+        }
+    }
+
+    /// Restore the rows matching the condition to the backup queried before they are written, in a transaction.
+    pub(crate) fn restore_rows(&self, condition: &DbMap, backup: &[DbMap]) -> Result<()> {
+        let mut trans = Transaction::new(self.db);
+        trans.begin()?;
+
+        let result = self.delete_row(condition, None, false)
+            .and_then(|_| backup.iter().try_for_each(|row| self.insert_row(row).map(|_| ())));
 
         match result {
             Ok(()) => trans.commit(),
@@ -923,4 +1369,68 @@ impl<'a> Table<'a> {
             }
         }
     }
+
+    fn replace_row_without_transaction(&self, condition: &DbMap, is_filter_sync: bool, datas: &DbMap) -> Result<()> {
+        let mut new_row = datas.clone();
+        let cols = vec![column::SYNC_TYPE, column::CLOUD_VERSION, column::GLOBAL_ID];
+        if let Ok(rows) = self.query_row(&cols, condition, None, false, COLUMN_INFO) {
+            if !rows.is_empty() {
+                let old_row = rows.first().unwrap();
+                let trusted_acc = SyncType::TrustedAccount as u32;
+                if (old_row.get_num_attr(&column::SYNC_TYPE)? & trusted_acc) == trusted_acc
+                    && (new_row.get_num_attr(&column::SYNC_TYPE)? & trusted_acc) == trusted_acc
+                {
+                    if let Some(cloud_ver) = old_row.get(column::CLOUD_VERSION) {
+                        new_row.insert(column::CLOUD_VERSION, cloud_ver.clone());
+                    }
+                    if let Some(global_id) = old_row.get(column::GLOBAL_ID) {
+                        new_row.insert(column::GLOBAL_ID, global_id.clone());
+                    }
+                }
+            }
+        }
+        self.delete_row(condition, None, is_filter_sync)?;
+        self.insert_row(&new_row)?;
+        Ok(())
+    }
+
+    fn exec_write(&self, write: &DbWrite) -> Result<()> {
+        let (condition, datas) = match write {
+            DbWrite::Replace(condition, datas) => return self.replace_row_without_transaction(condition, false, datas),
+            DbWrite::Update(condition, datas) | DbWrite::Remove(condition, datas) => (condition, datas),
+        };
+        if self.update_row(condition, true, datas)? == 0 {
+            return macros_lib::log_throw_error!(macros_lib::hisysevent::function!(),
+                ErrCode::NotFound, reason = ErrReason::AssetNotFound, "[FATAL]The data to be written does not exist.");
+        }
+        if matches!(write, DbWrite::Remove(..)) {
+            let mut reverse_condition = DbMap::new();
+            reverse_condition.insert(column::SYNC_TYPE, Value::Number(SyncType::TrustedAccount as u32));
+            self.delete_row(condition, Some(&reverse_condition), false)?;
+        }
+        Ok(())
+    }
+
+    /// Execute the writes in a transaction, the write of each index is prepared right before it is executed.
+    /// If a write fails to be prepared or executed, all the writes are rolled back and its index is returned.
+    pub(crate) fn exec_writes_in_transaction<F>(
+        &self,
+        count: usize,
+        mut prepare: F,
+    ) -> Result<Option<(usize, AssetError)>>
+    where
+        F: FnMut(usize, &dyn Fn(&DbMap) -> Result<Vec<DbMap>>) -> Result<DbWrite>,
+    {
+        let query = |condition: &DbMap| self.query_row_with_custom_labels(&vec![], condition, None, true);
+        let mut trans = Transaction::new(self.db);
+        trans.begin()?;
+        for index in 0..count {
+            if let Err(e) = prepare(index, &query).and_then(|write| self.exec_write(&write)) {
+                trans.rollback()?;
+                return Ok(Some((index, e)));
+            }
+        }
+        trans.commit()?;
+        Ok(None)
+    }
 }
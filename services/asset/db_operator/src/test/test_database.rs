@@ -21,12 +21,16 @@ use std::{
 };
 
 use asset_common::CallingInfo;
-use asset_definition::{ErrCode, Extension, Value};
+use asset_crypto_manager::crypto::Crypto;
+use asset_definition::{
+    Accessibility, AssetError, AuthType, CustomLabel, ErrCode, Extension, Predicate, SyncStatus, SyncType, Tag, Value,
+};
 
 use crate::{
+    common::{build_aad, build_secret_key},
     database::Database,
     table::Table,
-    types::{column, DbMap, QueryOptions, TABLE_NAME},
+    types::{column, DbMap, QueryOptions, DB_UPGRADE_VERSION, TABLE_NAME},
 };
 
 use crate::test::TEST_CASE_MUTEX;
@@ -100,7 +104,7 @@ fn database_version() {
     fs::create_dir_all("/data/asset_test/0").unwrap();
     let calling_info = CallingInfo::new_self();
     let db = Database::build(&calling_info, None).unwrap();
-    assert_eq!(4, db.get_version().unwrap());
+    assert_eq!(DB_UPGRADE_VERSION, db.get_version().unwrap());
     assert!(db.set_version(2).is_ok());
     assert_eq!(2, db.get_version().unwrap());
     let _ = Database::delete(0, &db.db_name);
@@ -180,6 +184,219 @@ fn delete_data() {
     remove_dir();
 }
 
+#[test]
+fn delete_expired_data() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let mut db = open_db_and_insert_data();
+
+    let mut def = DbMap::from(DB_DATA);
+    add_bytes_column(&mut def);
+    def.insert(column::EXPIRE_TIME, Value::Number(100));
+    def.insert(column::ALIAS, Value::Bytes(b"local_expired".to_vec()));
+    assert_eq!(1, db.insert_datas(&def).unwrap());
+    def.insert(column::ALIAS, Value::Bytes(b"sync_expired".to_vec()));
+    def.insert(column::SYNC_TYPE, Value::Number(SyncType::TrustedAccount as u32));
+    assert_eq!(1, db.insert_datas(&def).unwrap());
+    def.insert(column::ALIAS, Value::Bytes(b"not_expired".to_vec()));
+    def.insert(column::EXPIRE_TIME, Value::Number(200));
+    assert_eq!(1, db.insert_datas(&def).unwrap());
+
+    let update_time = Value::Bytes(b"1".to_vec());
+    assert_eq!((1, 1), db.delete_expired_datas(100, &update_time).unwrap());
+
+    let owner = DbMap::from([(column::OWNER, Value::Bytes(column::OWNER.as_bytes().to_vec()))]);
+    let res = db.query_datas(&vec![column::ALIAS], &owner, None, true).unwrap();
+    assert_eq!(res.len(), 2);
+    let res = db.query_datas(&vec![column::ALIAS, column::SYNC_STATUS], &owner, None, false).unwrap();
+    assert_eq!(res.len(), 3);
+    for data in res {
+        if data.get_bytes_attr(&column::ALIAS).unwrap() == b"sync_expired" {
+            assert_eq!(SyncStatus::SyncDel as u32, data.get_num_attr(&column::SYNC_STATUS).unwrap());
+        }
+    }
+
+    remove_dir();
+}
+
+#[test]
+fn delete_expired_data_by_condition() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let mut db = open_db_and_insert_data();
+
+    let mut def = DbMap::from(DB_DATA);
+    add_bytes_column(&mut def);
+    def.insert(column::EXPIRE_TIME, Value::Number(100));
+    def.insert(column::ALIAS, Value::Bytes(b"expired_1".to_vec()));
+    assert_eq!(1, db.insert_datas(&def).unwrap());
+    def.insert(column::ALIAS, Value::Bytes(b"expired_2".to_vec()));
+    assert_eq!(1, db.insert_datas(&def).unwrap());
+
+    let condition = DbMap::from([(column::ALIAS, Value::Bytes(b"expired_1".to_vec()))]);
+    assert_eq!((1, 0), db.delete_expired_datas_by_condition(&condition).unwrap());
+    assert!(!db.is_data_exists(&condition, false).unwrap());
+    let condition = DbMap::from([(column::ALIAS, Value::Bytes(b"expired_2".to_vec()))]);
+    assert!(db.is_data_exists(&condition, false).unwrap());
+
+    remove_dir();
+}
+
+#[test]
+fn consume_access_count() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let mut db = open_db_and_insert_data();
+
+    let mut def = DbMap::from(DB_DATA);
+    add_bytes_column(&mut def);
+    def.insert(column::ALIAS, Value::Bytes(b"limited".to_vec()));
+    def.insert(column::REMAINING_ACCESS_COUNT, Value::Number(2));
+    assert_eq!(1, db.insert_datas(&def).unwrap());
+
+    let condition = DbMap::from([(column::ALIAS, Value::Bytes(b"limited".to_vec()))]);
+    let res = db.query_datas(&vec![column::ID], &condition, None, true).unwrap();
+    let id = res[0].get_num_attr(&column::ID).unwrap();
+
+    let decrypt_failed = || Err(AssetError::new(ErrCode::CryptoError, "decrypt failed".to_string(), "test"));
+    assert_eq!(ErrCode::CryptoError, db.consume_access_count(id, decrypt_failed).unwrap_err().code);
+    assert_eq!(1, db.consume_access_count(id, || Ok(())).unwrap());
+    assert!(!db.is_access_exhausted(&condition).unwrap());
+    assert_eq!(0, db.consume_access_count(id, || Ok(())).unwrap());
+    assert!(!db.is_data_exists(&condition, false).unwrap());
+    assert!(db.is_access_exhausted(&condition).unwrap());
+    assert_eq!(ErrCode::NotFound, db.consume_access_count(id, || Ok(())).unwrap_err().code);
+
+    assert_eq!(1, db.delete_access_exhausted_datas(&condition).unwrap());
+    assert!(!db.is_access_exhausted(&condition).unwrap());
+
+    // The records of the aliases added or removed in batch are deleted by the alias list.
+    def.insert(column::REMAINING_ACCESS_COUNT, Value::Number(1));
+    assert_eq!(1, db.insert_datas(&def).unwrap());
+    let res = db.query_datas(&vec![column::ID], &condition, None, true).unwrap();
+    assert_eq!(0, db.consume_access_count(res[0].get_num_attr(&column::ID).unwrap(), || Ok(())).unwrap());
+    assert_eq!(0, db.delete_access_exhausted_aliases(&condition, &[]).unwrap());
+    assert_eq!(0, db.delete_access_exhausted_aliases(&DbMap::new(), &[b"other".to_vec()]).unwrap());
+    let aliases = [b"limited".to_vec(), b"other".to_vec()];
+    assert_eq!(1, db.delete_access_exhausted_aliases(&DbMap::new(), &aliases).unwrap());
+    assert!(!db.is_access_exhausted(&condition).unwrap());
+
+    remove_dir();
+}
+
+#[test]
+fn custom_label_data() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let mut db = open_db_and_insert_data();
+
+    let mut def = DbMap::from(DB_DATA);
+    add_bytes_column(&mut def);
+    def.insert(column::ALIAS, Value::Bytes(b"labeled".to_vec()));
+    let critical = CustomLabel::encode_list(&[CustomLabel::new(b"app", b"mail")]);
+    let normal = CustomLabel::encode_list(&[CustomLabel::new(b"env", b"test"), CustomLabel::new(b"tier", b"gold")]);
+    def.insert(column::CUSTOM_CRITICAL, Value::Bytes(critical.clone()));
+    def.insert(column::CUSTOM_NORMAL, Value::Bytes(normal.clone()));
+    assert_eq!(1, db.insert_datas(&def).unwrap());
+
+    let mut condition = DbMap::from([(column::OWNER, Value::Bytes(column::OWNER.as_bytes().to_vec()))]);
+    let tier = CustomLabel::encode_list(&[CustomLabel::new(b"tier", b"gold")]);
+    condition.insert(column::CUSTOM_NORMAL, Value::Bytes(tier.clone()));
+    let res = db.query_datas(&vec![], &condition, None, false).unwrap();
+    assert_eq!(1, res.len());
+    assert!(res[0].get_bytes_attr(&column::ALIAS).unwrap() == b"labeled");
+    assert_eq!(&critical, res[0].get_bytes_attr(&column::CUSTOM_CRITICAL).unwrap());
+    assert_eq!(&normal, res[0].get_bytes_attr(&column::CUSTOM_NORMAL).unwrap());
+    assert!(res[0].get(&column::CUSTOM_NORMAL_LOCAL).is_none());
+
+    let silver = CustomLabel::encode_list(&[CustomLabel::new(b"tier", b"silver")]);
+    let update = DbMap::from([(column::CUSTOM_NORMAL, Value::Bytes(silver.clone()))]);
+    assert_eq!(1, db.update_datas(&condition, false, &update).unwrap());
+    assert!(!db.is_data_exists(&condition, false).unwrap());
+    condition.insert(column::CUSTOM_NORMAL, Value::Bytes(silver));
+    let res = db.query_datas(&vec![], &condition, None, false).unwrap();
+    let expected = CustomLabel::encode_list(&[CustomLabel::new(b"env", b"test"), CustomLabel::new(b"tier", b"silver")]);
+    assert_eq!(&expected, res[0].get_bytes_attr(&column::CUSTOM_NORMAL).unwrap());
+
+    let removed = CustomLabel::encode_list(&[CustomLabel::new(b"env", b"")]);
+    let update = DbMap::from([(column::CUSTOM_NORMAL, Value::Bytes(removed))]);
+    assert_eq!(1, db.update_datas(&condition, false, &update).unwrap());
+    let query_options = QueryOptions { offset: None, limit: Some(1), order_by: None, amend: None };
+    let res = db.query_datas(&vec![], &condition, Some(&query_options), false).unwrap();
+    let expected = CustomLabel::encode_list(&[CustomLabel::new(b"tier", b"silver")]);
+    assert_eq!(&expected, res[0].get_bytes_attr(&column::CUSTOM_NORMAL).unwrap());
+
+    assert_eq!(1, db.delete_datas(&condition, None, false).unwrap());
+    condition.insert(column::ID, res[0].get(&column::ID).unwrap().clone());
+    condition.remove(&column::CUSTOM_NORMAL);
+    def.insert(column::ALIAS, Value::Bytes(b"reused".to_vec()));
+    def.remove(&column::CUSTOM_CRITICAL);
+    def.remove(&column::CUSTOM_NORMAL);
+    def.insert(column::ID, res[0].get(&column::ID).unwrap().clone());
+    assert_eq!(1, db.insert_datas(&def).unwrap());
+    let res = db.query_datas(&vec![], &condition, None, false).unwrap();
+    assert_eq!(1, res.len());
+    assert!(res[0].get(&column::CUSTOM_CRITICAL).is_none());
+
+    remove_dir();
+}
+
+#[test]
+fn custom_critical_label_in_aad() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let calling_info = CallingInfo::new_self();
+    let mut def = DbMap::from(DB_DATA);
+    add_bytes_column(&mut def);
+    def.insert(column::AUTH_TYPE, Value::Number(AuthType::None as u32));
+    def.insert(column::ACCESSIBILITY, Value::Number(Accessibility::DevicePowerOn as u32));
+    let labels = [CustomLabel::new(b"app", b"mail"), CustomLabel::new(b"role", b"admin")];
+    def.insert(column::CUSTOM_CRITICAL, Value::Bytes(CustomLabel::encode_list(&labels)));
+
+    let secret_key = build_secret_key(&calling_info, &def).unwrap();
+    secret_key.generate().unwrap();
+    let cipher = Crypto::encrypt(&secret_key, b"secret", &build_aad(&def).unwrap()).unwrap();
+
+    // The labels stored in another order are covered by the same aad.
+    let mut reordered = [CustomLabel::encode_list(&labels[1..]), CustomLabel::encode_list(&labels[..1])].concat();
+    def.insert(column::CUSTOM_CRITICAL, Value::Bytes(reordered.clone()));
+    assert!(Crypto::decrypt(&secret_key, &cipher, &build_aad(&def).unwrap()).unwrap().ct_eq(b"secret"));
+
+    let last = reordered.len() - 1;
+    reordered[last] ^= 1;
+    def.insert(column::CUSTOM_CRITICAL, Value::Bytes(reordered));
+    assert!(Crypto::decrypt(&secret_key, &cipher, &build_aad(&def).unwrap()).is_err());
+
+    def.remove(&column::CUSTOM_CRITICAL);
+    assert!(Crypto::decrypt(&secret_key, &cipher, &build_aad(&def).unwrap()).is_err());
+    let _ = secret_key.delete();
+}
+
+#[test]
+fn query_filter_data() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let mut db = open_db_and_insert_data();
+
+    let mut def = DbMap::from(DB_DATA);
+    add_bytes_column(&mut def);
+    for (alias, time) in [(b"user_1", b"100"), (b"user_2", b"200"), (b"test_1", b"300")] {
+        def.insert(column::ALIAS, Value::Bytes(alias.to_vec()));
+        def.insert(column::CREATE_TIME, Value::Bytes(time.to_vec()));
+        assert_eq!(1, db.insert_datas(&def).unwrap());
+    }
+
+    let owner = DbMap::from([(column::OWNER, Value::Bytes(column::OWNER.as_bytes().to_vec()))]);
+    let count = |db: &mut Database, predicates: &[Predicate]| {
+        let mut condition = owner.clone();
+        condition.insert(column::QUERY_FILTER, Value::Bytes(Predicate::encode_list(predicates)));
+        db.query_datas(&vec![], &condition, None, false).unwrap().len()
+    };
+    assert_eq!(2, count(&mut db, &[Predicate::Prefix(Tag::Alias, b"user_")]));
+    assert_eq!(2, count(&mut db, &[Predicate::Range(Tag::CreateTime, Some(150), None)]));
+    assert_eq!(1, count(&mut db, &[Predicate::Range(Tag::CreateTime, Some(150), Some(250))]));
+    assert_eq!(2, count(&mut db, &[Predicate::In(Tag::Alias, vec![b"user_1".as_slice(), b"test_1", b"none"])]));
+    let predicates = [Predicate::Prefix(Tag::Alias, b"user_"), Predicate::Range(Tag::CreateTime, None, Some(150))];
+    assert_eq!(1, count(&mut db, &predicates));
+    assert_eq!(0, count(&mut db, &[]));
+
+    remove_dir();
+}
+
 #[test]
 fn update_data() {
     let _lock = TEST_CASE_MUTEX.lock().unwrap();
@@ -205,6 +422,32 @@ fn update_data() {
     remove_dir();
 }
 
+#[test]
+fn restore_replaced_data() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let mut db = open_db_and_insert_data();
+
+    let condition = DbMap::from([
+        (column::OWNER, Value::Bytes(column::OWNER.as_bytes().to_vec())),
+        (column::ALIAS, Value::Bytes(column::ALIAS.as_bytes().to_vec())),
+    ]);
+    let backup = db.query_datas(&vec![], &condition, None, false).unwrap();
+    assert_eq!(1, backup.len());
+    let mut def = DbMap::from(DB_DATA);
+    add_bytes_column(&mut def);
+    def.insert(column::SECRET, Value::Bytes(b"replaced".to_vec()));
+    db.replace_datas(&condition, false, &def).unwrap();
+
+    // The replaced data is restored as it was, and the data not in the backup is deleted.
+    db.restore_datas(&condition, &backup).unwrap();
+    let res = db.query_datas(&vec![], &condition, None, false).unwrap();
+    assert_eq!(backup, res);
+    db.restore_datas(&condition, &[]).unwrap();
+    assert!(!db.is_data_exists(&condition, false).unwrap());
+
+    remove_dir();
+}
+
 #[test]
 fn query_ordered_data() {
     let _lock = TEST_CASE_MUTEX.lock().unwrap();
@@ -226,8 +469,7 @@ fn query_ordered_data() {
     let query = QueryOptions {
         limit: Some(100),
         offset: Some(0),
-        order: Some(Ordering::Greater),
-        order_by: Some(vec![column::ALIAS]),
+        order_by: Some(vec![(column::ALIAS, Ordering::Greater)]),
         amend: None,
     };
     let res = db
@@ -244,6 +486,57 @@ fn query_ordered_data() {
     remove_dir();
 }
 
+#[test]
+fn query_data_ordered_by_keys() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    create_dir();
+    let mut def = DbMap::from(DB_DATA);
+    add_bytes_column(&mut def);
+    let calling_info = CallingInfo::new_self();
+    let mut db = Database::build(&calling_info, None).unwrap();
+    for (alias, time) in [(b"AAA", b"200"), (b"BBB", b"300"), (b"CCC", b"200")] {
+        def.insert(column::ALIAS, Value::Bytes(alias.to_vec()));
+        def.insert(column::UPDATE_TIME, Value::Bytes(time.to_vec()));
+        assert_eq!(1, db.insert_datas(&def).unwrap());
+    }
+
+    let query = QueryOptions {
+        limit: None,
+        offset: None,
+        order_by: Some(vec![(column::UPDATE_TIME, Ordering::Less), (column::ALIAS, Ordering::Less)]),
+        amend: None,
+    };
+    let res = db
+        .query_datas(
+            &vec![column::ALIAS],
+            &DbMap::from([(column::OWNER, Value::Bytes(column::OWNER.as_bytes().to_vec()))]),
+            Some(&query),
+            false,
+        )
+        .unwrap();
+    let aliases: Vec<&[u8]> = res.iter().map(|row| row.get_bytes_attr(&column::ALIAS).unwrap().as_slice()).collect();
+    assert_eq!(vec![b"BBB".as_slice(), b"CCC", b"AAA"], aliases);
+
+    let query = QueryOptions {
+        limit: None,
+        offset: None,
+        order_by: Some(vec![(column::UPDATE_TIME, Ordering::Less), (column::ALIAS, Ordering::Greater)]),
+        amend: None,
+    };
+    let res = db
+        .query_datas(
+            &vec![column::ALIAS],
+            &DbMap::from([(column::OWNER, Value::Bytes(column::OWNER.as_bytes().to_vec()))]),
+            Some(&query),
+            false,
+        )
+        .unwrap();
+    let aliases: Vec<&[u8]> = res.iter().map(|row| row.get_bytes_attr(&column::ALIAS).unwrap().as_slice()).collect();
+    assert_eq!(vec![b"BBB".as_slice(), b"AAA", b"CCC"], aliases);
+
+    remove_dir();
+}
+
 #[test]
 fn insert_error_data() {
     let _lock = TEST_CASE_MUTEX.lock().unwrap();
@@ -28,6 +28,11 @@ pub const TABLE_NAME: &str = "asset_table";
 /// Table name for cloud adapt.
 pub(crate) const ADAPT_CLOUD_TABLE: &str = "adapt_cloud_table";
 
+pub(crate) const ACCESS_EXHAUSTED_TABLE: &str = "access_exhausted_table";
+
+/// Table name of the user-defined custom labels.
+pub(crate) const CUSTOM_LABEL_TABLE: &str = "custom_label_table";
+
 /// Version V0 number for upgrade database
 pub const DB_UPGRADE_VERSION_V0: u32 = 0;
 /// Version V1 number for upgrade database
@@ -36,8 +41,16 @@ pub const DB_UPGRADE_VERSION_V1: u32 = 1;
 pub const DB_UPGRADE_VERSION_V2: u32 = 2;
 /// Version V3 number for upgrade database
 pub const DB_UPGRADE_VERSION_V3: u32 = 3;
+/// Version V4 number for upgrade database
+pub const DB_UPGRADE_VERSION_V4: u32 = 4;
+/// Version V5 number for upgrade database
+pub const DB_UPGRADE_VERSION_V5: u32 = 5;
+/// Version V6 number for upgrade database
+pub const DB_UPGRADE_VERSION_V6: u32 = 6;
+/// Version V7 number for upgrade database
+pub const DB_UPGRADE_VERSION_V7: u32 = 7;
 /// Latest version number for upgrade database
-pub const DB_UPGRADE_VERSION: u32 = 4;
+pub const DB_UPGRADE_VERSION: u32 = 8;
 
 /// Version 1 number
 pub const DB_DATA_VERSION_V1: u32 = 1;
@@ -113,6 +126,24 @@ pub mod column {
     pub const EXT_INFO: &str = "ExtInfo";
     /// Column name of the wrap type info.
     pub const WRAP_TYPE: &str = "WrapType";
+    /// Column name of the data expiration time.
+    pub const EXPIRE_TIME: &str = "ExpireTime";
+    /// Column name of the maximum number of times the secret can be read.
+    pub const MAX_ACCESS_COUNT: &str = "MaxAccessCount";
+    /// Column name of the remaining number of times the secret can be read.
+    pub const REMAINING_ACCESS_COUNT: &str = "RemainingAccessCount";
+    /// Column name of the flag whether the key is invalidated when the biometric enrollment changes.
+    pub const INVALIDATE_ON_BIO_CHANGE: &str = "InvalidateOnBiometricChange";
+    /// Column name of the revision of the Asset, which increases each time the Asset is updated.
+    pub const REVISION: &str = "Revision";
+    /// Column name of the encoded custom critical labels, which are stored in the custom label table.
+    pub const CUSTOM_CRITICAL: &str = "DataLabelCustomCritical";
+    /// Column name of the encoded custom normal labels, which are stored in the custom label table.
+    pub const CUSTOM_NORMAL: &str = "DataLabelCustomNormal";
+    /// Column name of the encoded custom normal local labels, which are stored in the custom label table.
+    pub const CUSTOM_NORMAL_LOCAL: &str = "DataLabelCustomNormalLocal";
+    /// Column name of the encoded query predicates, which are only used as the condition of a query.
+    pub const QUERY_FILTER: &str = "QueryFilter";
 }
 
 /// Column name of asset database.
@@ -125,6 +156,18 @@ pub mod adapt_column {
     pub const NEW_CLOUD_VERSION: &str = "NewCloudVersion";
 }
 
+/// Column name of custom label table.
+pub mod label_column {
+    /// Column name of the id of the asset the label belongs to.
+    pub const ASSET_ID: &str = "AssetId";
+    /// Column name of the label kind.
+    pub const KIND: &str = "LabelKind";
+    /// Column name of the label name.
+    pub const NAME: &str = "LabelName";
+    /// Column name of the label value.
+    pub const VALUE: &str = "LabelValue";
+}
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 pub(crate) struct ColumnInfo {
@@ -167,6 +210,11 @@ pub(crate) const COLUMN_INFO: &[ColumnInfo] = &[
     ColumnInfo { name: column::SYNC_STATUS, data_type: DataType::Number, is_primary_key: false, not_null: true },
     ColumnInfo { name: column::EXT_INFO, data_type: DataType::Bytes, is_primary_key: false, not_null: false },
     ColumnInfo { name: column::WRAP_TYPE, data_type: DataType::Number, is_primary_key: false, not_null: true },
+    ColumnInfo { name: column::EXPIRE_TIME, data_type: DataType::Number, is_primary_key: false, not_null: false },
+    ColumnInfo { name: column::MAX_ACCESS_COUNT, data_type: DataType::Number, is_primary_key: false, not_null: false },
+    ColumnInfo { name: column::REMAINING_ACCESS_COUNT, data_type: DataType::Number, is_primary_key: false, not_null: false },
+    ColumnInfo { name: column::INVALIDATE_ON_BIO_CHANGE, data_type: DataType::Bool, is_primary_key: false, not_null: false },
+    ColumnInfo { name: column::REVISION, data_type: DataType::Number, is_primary_key: false, not_null: false },
 ];
 
 pub(crate) const ADAPT_CLOUD_COLUMN_INFO: &[ColumnInfo] = &[
@@ -208,11 +256,36 @@ pub(crate) const COMBINE_COLUMN_INFO: &[ColumnInfo] = &[
     ColumnInfo { name: column::SYNC_STATUS, data_type: DataType::Number, is_primary_key: false, not_null: true },
     ColumnInfo { name: column::EXT_INFO, data_type: DataType::Bytes, is_primary_key: false, not_null: false },
     ColumnInfo { name: column::WRAP_TYPE, data_type: DataType::Number, is_primary_key: false, not_null: true },
+    ColumnInfo { name: column::EXPIRE_TIME, data_type: DataType::Number, is_primary_key: false, not_null: false },
+    ColumnInfo { name: column::MAX_ACCESS_COUNT, data_type: DataType::Number, is_primary_key: false, not_null: false },
+    ColumnInfo { name: column::REMAINING_ACCESS_COUNT, data_type: DataType::Number, is_primary_key: false, not_null: false },
+    ColumnInfo { name: column::INVALIDATE_ON_BIO_CHANGE, data_type: DataType::Bool, is_primary_key: false, not_null: false },
+    ColumnInfo { name: column::REVISION, data_type: DataType::Number, is_primary_key: false, not_null: false },
     ColumnInfo { name: adapt_column::OLD_GLOBAL_ID, data_type: DataType::Bytes, is_primary_key: false, not_null: false },
     ColumnInfo { name: adapt_column::NEW_GLOBAL_ID, data_type: DataType::Bytes, is_primary_key: false, not_null: false },
     ColumnInfo { name: adapt_column::NEW_CLOUD_VERSION, data_type: DataType::Bytes, is_primary_key: false, not_null: false },
 ];
 
+pub(crate) const ACCESS_EXHAUSTED_COLUMN_INFO: &[ColumnInfo] = &[
+    ColumnInfo { name: column::ID, data_type: DataType::Number, is_primary_key: true, not_null: true },
+    ColumnInfo { name: column::ALIAS, data_type: DataType::Bytes, is_primary_key: false, not_null: true },
+    ColumnInfo { name: column::OWNER, data_type: DataType::Bytes, is_primary_key: false, not_null: true },
+    ColumnInfo { name: column::OWNER_TYPE, data_type: DataType::Number, is_primary_key: false, not_null: true },
+    ColumnInfo { name: column::GROUP_ID, data_type: DataType::Bytes, is_primary_key: false, not_null: false },
+];
+
+pub(crate) const CUSTOM_LABEL_COLUMN_INFO: &[ColumnInfo] = &[
+    ColumnInfo { name: column::ID, data_type: DataType::Number, is_primary_key: true, not_null: true },
+    ColumnInfo { name: label_column::ASSET_ID, data_type: DataType::Number, is_primary_key: false, not_null: true },
+    ColumnInfo { name: label_column::KIND, data_type: DataType::Number, is_primary_key: false, not_null: true },
+    ColumnInfo { name: label_column::NAME, data_type: DataType::Bytes, is_primary_key: false, not_null: true },
+    ColumnInfo { name: label_column::VALUE, data_type: DataType::Bytes, is_primary_key: false, not_null: true },
+];
+
+/// The columns of the encoded custom labels, and the kinds of labels stored in the custom label table for them.
+pub(crate) const CUSTOM_LABEL_COLUMNS: [(&str, u32); 3] =
+    [(column::CUSTOM_CRITICAL, 0), (column::CUSTOM_NORMAL, 1), (column::CUSTOM_NORMAL_LOCAL, 2)];
+
 pub(crate) struct UpgradeColumnInfo {
     pub(crate) base_info: ColumnInfo,
     pub(crate) default_value: Option<Value>,
@@ -305,7 +378,7 @@ pub(crate) const UPGRADE_COLUMN_INFO_V3: &[UpgradeColumnInfo] = &[UpgradeColumnI
 
 pub(crate) const UPGRADE_COLUMN_INFO_V4: &[UpgradeColumnInfo] = &[];
 
-pub(crate) const UPGRADE_COLUMN_INFO: &[UpgradeColumnInfo] = &[UpgradeColumnInfo {
+pub(crate) const UPGRADE_COLUMN_INFO_V5: &[UpgradeColumnInfo] = &[UpgradeColumnInfo {
     base_info: ColumnInfo {
         name: column::WRAP_TYPE,
         data_type: DataType::Number,
@@ -315,6 +388,57 @@ pub(crate) const UPGRADE_COLUMN_INFO: &[UpgradeColumnInfo] = &[UpgradeColumnInfo
     default_value: Some(Value::Number(WrapType::Never as u32)),
 }];
 
+pub(crate) const UPGRADE_COLUMN_INFO_V6: &[UpgradeColumnInfo] = &[UpgradeColumnInfo {
+    base_info: ColumnInfo {
+        name: column::EXPIRE_TIME,
+        data_type: DataType::Number,
+        is_primary_key: false,
+        not_null: false,
+    },
+    default_value: None,
+}];
+
+pub(crate) const UPGRADE_COLUMN_INFO_V7: &[UpgradeColumnInfo] = &[
+    UpgradeColumnInfo {
+        base_info: ColumnInfo {
+            name: column::MAX_ACCESS_COUNT,
+            data_type: DataType::Number,
+            is_primary_key: false,
+            not_null: false,
+        },
+        default_value: None,
+    },
+    UpgradeColumnInfo {
+        base_info: ColumnInfo {
+            name: column::REMAINING_ACCESS_COUNT,
+            data_type: DataType::Number,
+            is_primary_key: false,
+            not_null: false,
+        },
+        default_value: None,
+    },
+];
+
+pub(crate) const UPGRADE_COLUMN_INFO_V8: &[UpgradeColumnInfo] = &[UpgradeColumnInfo {
+    base_info: ColumnInfo {
+        name: column::INVALIDATE_ON_BIO_CHANGE,
+        data_type: DataType::Bool,
+        is_primary_key: false,
+        not_null: false,
+    },
+    default_value: None,
+}];
+
+pub(crate) const UPGRADE_COLUMN_INFO: &[UpgradeColumnInfo] = &[UpgradeColumnInfo {
+    base_info: ColumnInfo {
+        name: column::REVISION,
+        data_type: DataType::Number,
+        is_primary_key: false,
+        not_null: false,
+    },
+    default_value: Some(Value::Number(0)),
+}];
+
 /// Options for batch query.
 #[repr(C)]
 pub struct QueryOptions {
@@ -322,14 +446,22 @@ pub struct QueryOptions {
     pub offset: Option<u32>,
     /// Maximum number of query results.
     pub limit: Option<u32>,
-    /// ordering: Ordering::Greater => ASC and Ordering::Less => DESC
-    pub order: Option<Ordering>,
-    /// Columns used for sorting.
-    pub order_by: Option<Vec<&'static str>>,
+    /// Columns used for sorting, each with its ordering: Ordering::Greater => ASC and Ordering::Less => DESC.
+    pub order_by: Option<Vec<(&'static str, Ordering)>>,
     /// Add extra to where.
     pub amend: Option<String>,
 }
 
+/// A write to the asset table, which is executed in a transaction with the other writes.
+pub enum DbWrite {
+    /// Delete the rows matching the condition, then insert the row.
+    Replace(DbMap, DbMap),
+    /// Update the rows matching the condition, which fails if no row is updated.
+    Update(DbMap, DbMap),
+    /// Mark the rows matching the condition as deleted with the datas, then delete those not to be synchronized.
+    Remove(DbMap, DbMap),
+}
+
 pub(crate) const SQLITE_OK: i32 = 0;
 pub(crate) const SQLITE_NOMEM: i32 = 7;
 pub(crate) const SQLITE_CORRUPT: i32 = 11;
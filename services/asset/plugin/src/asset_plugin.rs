@@ -150,7 +150,6 @@ fn get_query_options(attrs: &ExtDbMap) -> QueryOptions {
             _ => None,
         },
         order_by: None,
-        order: None,
         amend: None,
     }
 }
@@ -262,7 +261,6 @@ impl IAssetPluginCtx for AssetContext {
         let query_options = QueryOptions {
             offset: Some(offset),
             limit: Some(limit),
-            order: None,
             order_by: None,
             amend: Some(sql_where.to_string()),
         };
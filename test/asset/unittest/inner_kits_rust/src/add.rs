@@ -71,6 +71,24 @@ fn add_required_tags() {
     remove_by_alias(func_name).unwrap();
 }
 
+#[test]
+fn add_specific_auth_type() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let func_name = function!().as_bytes();
+    for auth_type in [AuthType::Pin, AuthType::Fingerprint, AuthType::Face, AuthType::Biometric] {
+        let attrs = AssetAttributes::new(func_name, func_name)
+            .accessibility(Accessibility::DevicePowerOn)
+            .auth_type(auth_type)
+            .build();
+        asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap();
+
+        let res = query_attr_by_alias(func_name).unwrap();
+        assert_eq!(1, res.len());
+        assert_eq!(auth_type, res[0].get_enum_attr::<AuthType>(&Tag::AuthType).unwrap());
+        remove_by_alias(func_name).unwrap();
+    }
+}
+
 #[test]
 fn add_english_secret() {
     let _lock = TEST_CASE_MUTEX.lock().unwrap();
@@ -194,3 +212,44 @@ fn add_is_persistent_auth_wrong() {
     attrs.insert_attr(Tag::IsPersistent, false);
     expect_error_eq(ErrCode::PermissionDenied, asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap_err());
 }
+
+#[test]
+fn add_by_builder() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let attrs = AssetAttributes::new(function_name, function_name)
+        .accessibility(Accessibility::DevicePowerOn)
+        .sync_type(SyncType::ThisDevice)
+        .sync_type(SyncType::TrustedDevice)
+        .data_label_critical1(CRITICAL_LABEL1)
+        .data_label_normal1(NORMAL_LABEL1)
+        .build();
+    asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap();
+
+    let query = QueryBuilder::new().alias(function_name).return_type(ReturnType::All).build();
+    let res = asset_sdk::Manager::build().unwrap().lock().unwrap().query(&query).unwrap();
+    assert_eq!(1, res.len());
+    assert_eq!(function_name, *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+    assert_eq!(CRITICAL_LABEL1, *res[0].get_bytes_attr(&Tag::DataLabelCritical1).unwrap());
+    assert_eq!(NORMAL_LABEL1, *res[0].get_bytes_attr(&Tag::DataLabelNormal1).unwrap());
+    let sync_type = (SyncType::ThisDevice as u32) | (SyncType::TrustedDevice as u32);
+    assert_eq!(sync_type, res[0].get_num_attr(&Tag::SyncType).unwrap());
+    remove_by_alias(function_name).unwrap();
+}
+
+#[test]
+fn add_with_expire_time() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs() as u32;
+    let attrs = AssetAttributes::new(function_name, function_name)
+        .accessibility(Accessibility::DevicePowerOn)
+        .expire_time(now + 3600)
+        .build();
+    asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap();
+
+    let res = query_attr_by_alias(function_name).unwrap();
+    assert_eq!(1, res.len());
+    assert_eq!(now + 3600, res[0].get_num_attr(&Tag::ExpireTime).unwrap());
+    remove_by_alias(function_name).unwrap();
+}
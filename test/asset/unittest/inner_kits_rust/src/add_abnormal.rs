@@ -93,6 +93,23 @@ fn add_alias_with_unmatched_type() {
     expect_error_eq(ErrCode::InvalidArgument, asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap_err());
 }
 
+#[test]
+fn add_error_with_reason() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let mut attrs = AssetMap::new();
+    attrs.insert_attr(Tag::Secret, function_name.to_owned());
+    let err = asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap_err();
+    expect_error_eq(ErrCode::InvalidArgument, err.clone());
+    assert_eq!(ErrReason::MissingTag, err.reason);
+
+    attrs.insert_attr(Tag::Alias, function_name.to_owned());
+    attrs.insert_attr(Tag::Accessibility, Accessibility::DeviceUnlocked as u32 + 1);
+    let err = asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap_err();
+    expect_error_eq(ErrCode::InvalidArgument, err.clone());
+    assert_eq!(ErrReason::InvalidEnumVariant, err.reason);
+}
+
 #[test]
 fn add_without_secret() {
     let _lock = TEST_CASE_MUTEX.lock().unwrap();
@@ -183,7 +200,7 @@ fn add_invalid_auth_type() {
     attrs.insert_attr(Tag::Alias, function_name.to_owned());
     attrs.insert_attr(Tag::Secret, function_name.to_owned());
     attrs.insert_attr(Tag::Accessibility, Accessibility::DevicePowerOn);
-    attrs.insert_attr(Tag::AuthType, (AuthType::None as u32) + 1);
+    attrs.insert_attr(Tag::AuthType, (AuthType::Biometric as u32) + 1);
     expect_error_eq(ErrCode::InvalidArgument, asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap_err());
 
     attrs.insert_attr(Tag::AuthType, (AuthType::Any as u32) + 1);
@@ -324,3 +341,32 @@ fn add_unsupported_tags() {
         expect_error_eq(ErrCode::InvalidArgument, asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap_err());
     }
 }
+
+#[test]
+fn add_expired_asset() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let expire_times = [0, 1];
+    for expire_time in expire_times {
+        let attrs = AssetAttributes::new(function_name, function_name)
+            .accessibility(Accessibility::DevicePowerOn)
+            .expire_time(expire_time)
+            .build();
+        expect_error_eq(ErrCode::InvalidArgument, asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap_err());
+    }
+}
+
+#[test]
+fn add_bio_invalidation_without_biometric_auth() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let auth_types = [AuthType::None, AuthType::Pin];
+    for auth_type in auth_types {
+        let attrs = AssetAttributes::new(function_name, function_name)
+            .accessibility(Accessibility::DevicePowerOn)
+            .auth_type(auth_type)
+            .invalidate_on_biometric_change(true)
+            .build();
+        expect_error_eq(ErrCode::InvalidArgument, asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap_err());
+    }
+}
@@ -0,0 +1,54 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::time::Duration;
+
+use crate::common::*;
+use crate::TEST_CASE_MUTEX;
+use asset_sdk::*;
+
+const AUTH_VALIDITY_IN_SECS: u32 = 10;
+
+#[test]
+fn auth_session_release_challenge_on_drop() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    add_default_auth_asset(function_name, function_name).unwrap();
+
+    let manager = asset_sdk::Manager::build().unwrap();
+    let mut query = AssetMap::new();
+    query.insert_attr(Tag::Alias, function_name.to_owned());
+    for _ in 0..=CRYPTO_CAPACITY {
+        let session = AuthSession::begin(manager.clone(), &query).unwrap();
+        assert_eq!(CHALLENGE_SIZE, session.challenge().len());
+    }
+    remove_by_alias(function_name).unwrap();
+}
+
+#[test]
+fn auth_session_remaining_validity() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    add_default_auth_asset(function_name, function_name).unwrap();
+
+    let mut query = AssetMap::new();
+    query.insert_attr(Tag::Alias, function_name.to_owned());
+    query.insert_attr(Tag::AuthValidityPeriod, AUTH_VALIDITY_IN_SECS);
+    let session = AuthSession::begin(asset_sdk::Manager::build().unwrap(), &query).unwrap();
+    assert!(!session.is_expired());
+    assert!(session.remaining_validity() <= Duration::from_secs(AUTH_VALIDITY_IN_SECS as u64));
+    drop(session);
+    remove_by_alias(function_name).unwrap();
+}
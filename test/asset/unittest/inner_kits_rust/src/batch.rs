@@ -0,0 +1,174 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::HashSet;
+
+use crate::common::*;
+use crate::TEST_CASE_MUTEX;
+use asset_sdk::*;
+
+fn build_aliases(function_name: &[u8], count: usize) -> Vec<Vec<u8>> {
+    (0..count).map(|i| [function_name, format!("_{}", i).as_bytes()].concat()).collect()
+}
+
+fn build_alias_maps(aliases: &[Vec<u8>]) -> Vec<AssetMap> {
+    aliases.iter().map(|alias| QueryBuilder::new().alias(alias).build()).collect()
+}
+
+#[test]
+fn batch_add_and_remove_more_than_chunk_size() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let aliases = build_aliases(function_name, MAX_BATCH_CHUNK_SIZE as usize + 50);
+    let attributes_array = aliases.iter().map(|alias| AssetAttributes::new(function_name, alias).build()).collect();
+
+    let manager = asset_sdk::Manager::build().unwrap();
+    let results = manager.lock().unwrap().batch_add_with_results(&attributes_array).unwrap();
+    assert_eq!(aliases.len(), results.len());
+    let mut ids = HashSet::new();
+    for (index, (item, alias)) in results.iter().zip(aliases.iter()).enumerate() {
+        assert!(item.is_success());
+        assert_eq!(index as u32, item.index);
+        assert_eq!(alias, &item.alias);
+        assert!(ids.insert(item.id.unwrap()));
+    }
+
+    let results = manager.lock().unwrap().batch_remove_with_results(&build_alias_maps(&aliases)).unwrap();
+    assert_eq!(aliases.len(), results.len());
+    assert!(results.iter().all(|item| item.is_success()));
+    expect_error_eq(ErrCode::NotFound, query_attr_by_alias(&aliases[0]).unwrap_err());
+}
+
+#[test]
+fn batch_add_with_duplicated_item() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let aliases = build_aliases(function_name, 2);
+    add_default_asset(&aliases[0], function_name).unwrap();
+
+    let attributes_array = aliases.iter().map(|alias| AssetAttributes::new(function_name, alias).build()).collect();
+    let manager = asset_sdk::Manager::build().unwrap();
+    let results = manager.lock().unwrap().batch_add_with_results(&attributes_array).unwrap();
+    assert_eq!(Some(ErrCode::Duplicated), results[0].code);
+    assert!(!results[0].message.is_empty());
+    assert_eq!(None, results[0].id);
+    assert!(results[1].is_success());
+    assert!(results[1].id.is_some());
+
+    let err_info = manager.lock().unwrap().batch_add(&attributes_array).unwrap();
+    assert_eq!(vec![(ErrCode::Duplicated as u32, 0), (ErrCode::Duplicated as u32, 1)], err_info);
+
+    remove_by_alias(&aliases[0]).unwrap();
+    remove_by_alias(&aliases[1]).unwrap();
+}
+
+#[test]
+fn batch_update_and_remove_with_missing_item() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let aliases = build_aliases(function_name, 2);
+    add_default_asset(&aliases[0], function_name).unwrap();
+
+    let update = AssetUpdate::new().secret(b"new_secret").build();
+    let manager = asset_sdk::Manager::build().unwrap();
+    let results = manager.lock().unwrap()
+        .batch_update_with_results(&build_alias_maps(&aliases), &[update.clone(), update]).unwrap();
+    assert!(results[0].is_success());
+    assert!(results[0].id.is_some());
+    assert_eq!(Some(ErrCode::NotFound), results[1].code);
+    assert_eq!(aliases[1], results[1].alias);
+
+    let results = manager.lock().unwrap().batch_remove_with_results(&build_alias_maps(&aliases)).unwrap();
+    assert!(results[0].is_success());
+    assert_eq!(Some(ErrCode::NotFound), results[1].code);
+    expect_error_eq(ErrCode::NotFound, query_attr_by_alias(&aliases[0]).unwrap_err());
+}
+
+#[test]
+fn batch_query_by_aliases() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let aliases = build_aliases(function_name, 3);
+    add_default_asset(&aliases[0], function_name).unwrap();
+    add_default_auth_asset(&aliases[1], function_name).unwrap();
+
+    let results = asset_sdk::Manager::build().unwrap().lock().unwrap()
+        .batch_query(&build_alias_maps(&aliases)).unwrap();
+    assert_eq!(aliases.len(), results.len());
+    assert!(results[0].result.is_success());
+    assert_eq!(function_name, *results[0].attributes.get_bytes_attr(&Tag::Secret).unwrap());
+    assert_eq!(Some(ErrCode::Unsupported), results[1].result.code);
+    assert!(results[1].attributes.is_empty());
+    assert_eq!(Some(ErrCode::NotFound), results[2].result.code);
+    assert_eq!(aliases[2], results[2].result.alias);
+
+    remove_by_alias(&aliases[0]).unwrap();
+    remove_by_alias(&aliases[1]).unwrap();
+}
+
+#[test]
+fn batch_query_by_label_filter() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let aliases = build_aliases(function_name, 3);
+    let attributes_array = aliases.iter()
+        .map(|alias| AssetAttributes::new(alias, alias).data_label_normal1(function_name).build()).collect();
+    let manager = asset_sdk::Manager::build().unwrap();
+    manager.lock().unwrap().batch_add(&attributes_array).unwrap();
+
+    let filter = QueryBuilder::new().data_label_normal1(function_name).build();
+    let results = manager.lock().unwrap().batch_query(&vec![filter]).unwrap();
+    assert_eq!(aliases.len(), results.len());
+    for item in results.iter() {
+        assert!(item.result.is_success());
+        assert_eq!(0, item.result.index);
+        assert_eq!(item.result.alias, *item.attributes.get_bytes_attr(&Tag::Secret).unwrap());
+    }
+
+    manager.lock().unwrap().batch_remove(&build_alias_maps(&aliases)).unwrap();
+}
+
+#[test]
+fn transact_all_or_nothing() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let aliases = build_aliases(function_name, 3);
+    add_default_asset(&aliases[0], function_name).unwrap();
+
+    let update = AssetUpdate::new().secret(b"new_secret").build();
+    let operations = vec![
+        TransactionOperation::Add(AssetAttributes::new(function_name, &aliases[1]).build()),
+        TransactionOperation::Update(QueryBuilder::new().alias(&aliases[0]).build(), update.clone()),
+        TransactionOperation::Remove(RemoveBuilder::new().alias(&aliases[2]).build()),
+    ];
+    let manager = asset_sdk::Manager::build().unwrap();
+    let results = manager.lock().unwrap().transact(&operations).unwrap();
+    assert_eq!(1, results.len());
+    assert_eq!(2, results[0].index);
+    assert_eq!(Some(ErrCode::NotFound), results[0].code);
+    expect_error_eq(ErrCode::NotFound, query_attr_by_alias(&aliases[1]).unwrap_err());
+    let res = query_all_by_alias(&aliases[0]).unwrap();
+    assert_eq!(function_name, *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+
+    let results = manager.lock().unwrap().transact(&operations[..2]).unwrap();
+    assert_eq!(2, results.len());
+    assert!(results.iter().all(|item| item.is_success()));
+    let res = query_all_by_alias(&aliases[0]).unwrap();
+    assert_eq!(b"new_secret".to_vec(), *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+    query_attr_by_alias(&aliases[1]).unwrap();
+
+    remove_by_alias(&aliases[0]).unwrap();
+    remove_by_alias(&aliases[1]).unwrap();
+}
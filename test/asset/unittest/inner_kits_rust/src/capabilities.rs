@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::common::*;
+use crate::TEST_CASE_MUTEX;
+use asset_sdk::*;
+
+#[test]
+fn capabilities_supports() {
+    let capabilities = Capabilities {
+        supported_tags: vec![Tag::Secret, Tag::Accessibility],
+        enum_values: vec![(Tag::Accessibility, vec![Accessibility::DevicePowerOn as u32])],
+        features: Feature::Batch as u32,
+        ..Default::default()
+    };
+    assert!(capabilities.supports_tag(Tag::Secret));
+    assert!(!capabilities.supports_tag(Tag::PageToken));
+    assert!(capabilities.supports_value(Tag::Accessibility, Accessibility::DevicePowerOn as u32));
+    assert!(!capabilities.supports_value(Tag::Accessibility, Accessibility::DeviceUnlocked as u32));
+    assert!(capabilities.supports_value(Tag::ReturnLimit, u32::MAX));
+    assert!(capabilities.supports_feature(Feature::Batch));
+    assert!(!capabilities.supports_feature(Feature::Sync));
+}
+
+#[test]
+fn get_capabilities() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let capabilities = asset_sdk::Manager::build().unwrap().lock().unwrap().get_capabilities().unwrap();
+    assert!(capabilities.protocol_version >= 1);
+    for tag in Tag::ALL {
+        assert!(capabilities.supports_tag(*tag));
+    }
+    for value in Accessibility::VALUES {
+        assert!(capabilities.supports_value(Tag::Accessibility, *value));
+    }
+    assert!(capabilities.supports_feature(Feature::Batch));
+    assert!(capabilities.supports_feature(Feature::QueryPage));
+}
+
+#[test]
+fn add_secret_exceeding_service_limit() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let capabilities = asset_sdk::Manager::build().unwrap().lock().unwrap().get_capabilities().unwrap();
+    let secret = vec![0; capabilities.max_secret_size as usize + 1];
+    let attrs = AssetAttributes::new(&secret, function_name).build();
+    expect_error_eq(ErrCode::InvalidArgument, asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap_err());
+}
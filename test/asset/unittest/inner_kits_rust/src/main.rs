@@ -20,6 +20,9 @@ mod common;
 
 mod add;
 mod add_abnormal;
+mod auth_session;
+mod batch;
+mod capabilities;
 mod post_query;
 mod post_query_abnormal;
 mod pre_query;
@@ -30,6 +33,8 @@ mod query_sync_result;
 mod query_sync_result_abnormal;
 mod remove;
 mod remove_abnormal;
+mod secret_bytes;
+mod serialization;
 mod update;
 mod update_abnormal;
 
@@ -473,3 +473,286 @@ fn query_with_sync_type() {
 
     remove_by_alias(function_name).unwrap();
 }
+
+#[test]
+fn query_with_max_access_count() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let attrs = AssetAttributes::new(function_name, function_name)
+        .accessibility(Accessibility::DevicePowerOn)
+        .max_access_count(2)
+        .build();
+    asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap();
+
+    let res = query_all_by_alias(function_name).unwrap();
+    assert_eq!(function_name, *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+    assert_eq!(2, res[0].get_num_attr(&Tag::MaxAccessCount).unwrap());
+    assert_eq!(1, res[0].get_num_attr(&Tag::RemainingAccessCount).unwrap());
+    let res = query_all_by_alias(function_name).unwrap();
+    assert_eq!(function_name, *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+    assert_eq!(2, res[0].get_num_attr(&Tag::MaxAccessCount).unwrap());
+    assert!(res[0].get(&Tag::RemainingAccessCount).is_none());
+
+    expect_error_eq(ErrCode::AccessCountExhausted, query_all_by_alias(function_name).unwrap_err());
+    expect_error_eq(ErrCode::NotFound, query_attr_by_alias(function_name).unwrap_err());
+
+    // The alias removed is no longer reported as exhausted.
+    expect_error_eq(ErrCode::NotFound, remove_by_alias(function_name).unwrap_err());
+    expect_error_eq(ErrCode::NotFound, query_all_by_alias(function_name).unwrap_err());
+}
+
+#[test]
+fn query_with_custom_labels() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let attrs = AssetAttributes::new(function_name, function_name)
+        .accessibility(Accessibility::DevicePowerOn)
+        .data_label_custom_critical(b"app", b"mail")
+        .data_label_custom_normal(b"env", b"test")
+        .data_label_custom_normal(b"tier", b"gold")
+        .build();
+    asset_sdk::Manager::build().unwrap().lock().unwrap().add(&attrs).unwrap();
+
+    let query = QueryBuilder::new()
+        .data_label_custom_normal(b"tier", b"gold")
+        .return_type(ReturnType::All)
+        .build();
+    let res = asset_sdk::Manager::build().unwrap().lock().unwrap().query(&query).unwrap();
+    assert_eq!(1, res.len());
+    assert_eq!(function_name, *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+    assert_eq!(attrs[&Tag::DataLabelCustomCritical], res[0][&Tag::DataLabelCustomCritical]);
+    assert_eq!(attrs[&Tag::DataLabelCustomNormal], res[0][&Tag::DataLabelCustomNormal]);
+
+    let query = QueryBuilder::new().data_label_custom_normal(b"tier", b"silver").build();
+    expect_error_eq(ErrCode::NotFound, asset_sdk::Manager::build().unwrap().lock().unwrap().query(&query).unwrap_err());
+
+    let query = QueryBuilder::new().alias(function_name).build();
+    let update = AssetUpdate::new().remove_data_label_custom_normal(b"env").build();
+    asset_sdk::Manager::build().unwrap().lock().unwrap().update(&query, &update).unwrap();
+    let res = asset_sdk::Manager::build().unwrap().lock().unwrap().query(&query).unwrap();
+    let expected = CustomLabel::encode_list(&[CustomLabel::new(b"tier", b"gold")]);
+    assert_eq!(expected, *res[0].get_bytes_attr(&Tag::DataLabelCustomNormal).unwrap());
+
+    remove_by_alias(function_name).unwrap();
+}
+
+#[test]
+fn query_key_status() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    add_default_asset(function_name, function_name).unwrap();
+
+    let query = QueryBuilder::new().alias(function_name).return_type(ReturnType::KeyStatus).build();
+    let res = asset_sdk::Manager::build().unwrap().lock().unwrap().query(&query).unwrap();
+    assert_eq!(1, res.len());
+    assert!(res[0].get(&Tag::Secret).is_none());
+    assert_eq!(KeyStatus::Valid, res[0].get_enum_attr::<KeyStatus>(&Tag::KeyStatus).unwrap());
+
+    remove_by_alias(function_name).unwrap();
+}
+
+#[test]
+fn count_and_exists_without_reading_rows() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let query = QueryBuilder::new().alias(function_name).build();
+    let manager = asset_sdk::Manager::build().unwrap();
+    assert!(!manager.lock().unwrap().exists(&query).unwrap());
+
+    add_default_asset(function_name, function_name).unwrap();
+    assert_eq!(1, manager.lock().unwrap().count(&query).unwrap());
+    assert!(manager.lock().unwrap().exists(&query).unwrap());
+
+    let invalid_query = QueryBuilder::new().alias(function_name).return_type(ReturnType::All).build();
+    expect_error_eq(ErrCode::InvalidArgument, manager.lock().unwrap().count(&invalid_query).unwrap_err());
+
+    remove_by_alias(function_name).unwrap();
+}
+
+#[test]
+fn query_by_alias_prefix_and_alias_list() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let aliases: Vec<Vec<u8>> = (0..3).map(|i| format!("{:?}{}", function_name, i).into_bytes()).collect();
+    for alias in &aliases {
+        add_default_asset(alias, alias).unwrap();
+    }
+
+    let manager = asset_sdk::Manager::build().unwrap();
+    let query = QueryBuilder::new().alias_prefix(format!("{:?}", function_name).as_bytes()).build();
+    assert_eq!(3, manager.lock().unwrap().count(&query).unwrap());
+    let query = QueryBuilder::new().aliases(&[aliases[0].as_slice(), aliases[2].as_slice()]).build();
+    let res = manager.lock().unwrap().query(&query).unwrap();
+    assert_eq!(2, res.len());
+    let query = QueryBuilder::new().alias_prefix(&aliases[1]).update_time_range(Some(1), None).build();
+    assert_eq!(1, manager.lock().unwrap().count(&query).unwrap());
+
+    let invalid_query = QueryBuilder::new().update_time_range(None, None).build();
+    expect_error_eq(ErrCode::InvalidArgument, manager.lock().unwrap().count(&invalid_query).unwrap_err());
+    for alias in &aliases {
+        remove_by_alias(alias).unwrap();
+    }
+}
+
+#[test]
+fn query_ordered_by_keys_descending() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let function_name = function!().as_bytes();
+    let labels: [(&[u8], &[u8]); 3] = [(b"1", b"a"), (b"2", b"b"), (b"1", b"c")];
+    let manager = asset_sdk::Manager::build().unwrap();
+    let mut aliases = vec![];
+    for (i, (label, sub_label)) in labels.iter().enumerate() {
+        let alias = format!("{:?}{}", function_name, i).into_bytes();
+        let mut attrs = AssetMap::new();
+        attrs.insert_attr(Tag::Alias, alias.clone());
+        attrs.insert_attr(Tag::Secret, alias.clone());
+        attrs.insert_attr(Tag::Accessibility, Accessibility::DevicePowerOn);
+        attrs.insert_attr(Tag::DataLabelNormal1, label.to_vec());
+        attrs.insert_attr(Tag::DataLabelNormal2, sub_label.to_vec());
+        manager.lock().unwrap().add(&attrs).unwrap();
+        aliases.push(alias);
+    }
+
+    let query = QueryBuilder::new()
+        .alias_prefix(format!("{:?}", function_name).as_bytes())
+        .return_ordered_by_keys(&[
+            (OrderKey::DataLabelNormal1, ReturnOrder::Descending),
+            (OrderKey::DataLabelNormal2, ReturnOrder::Descending),
+        ])
+        .build();
+    let res = manager.lock().unwrap().query(&query).unwrap();
+    let res_aliases: Vec<&Vec<u8>> = res.iter().map(|asset| asset.get_bytes_attr(&Tag::Alias).unwrap()).collect();
+    assert_eq!(vec![&aliases[1], &aliases[2], &aliases[0]], res_aliases);
+
+    let query = QueryBuilder::new()
+        .alias_prefix(format!("{:?}", function_name).as_bytes())
+        .return_ordered_by_keys(&[
+            (OrderKey::DataLabelNormal1, ReturnOrder::Descending),
+            (OrderKey::DataLabelNormal2, ReturnOrder::Ascending),
+        ])
+        .build();
+    let res = manager.lock().unwrap().query(&query).unwrap();
+    let res_aliases: Vec<&Vec<u8>> = res.iter().map(|asset| asset.get_bytes_attr(&Tag::Alias).unwrap()).collect();
+    assert_eq!(vec![&aliases[1], &aliases[0], &aliases[2]], res_aliases);
+
+    let query = QueryBuilder::new()
+        .alias_prefix(format!("{:?}", function_name).as_bytes())
+        .return_ordered_by(OrderKey::UpdateTime)
+        .return_order(ReturnOrder::Descending)
+        .build();
+    assert_eq!(3, manager.lock().unwrap().query(&query).unwrap().len());
+
+    let mut invalid_query = AssetMap::new();
+    let invalid_key = [(Tag::Secret as u32).to_le_bytes(), (ReturnOrder::Ascending as u32).to_le_bytes()].concat();
+    invalid_query.insert_attr(Tag::ReturnOrderedByKeys, invalid_key);
+    expect_error_eq(ErrCode::InvalidArgument, manager.lock().unwrap().query(&invalid_query).unwrap_err());
+    let invalid_query = QueryBuilder::new()
+        .return_ordered_by_keys(&[(OrderKey::DataLabelNormal1, ReturnOrder::Ascending)])
+        .return_order(ReturnOrder::Descending)
+        .build();
+    expect_error_eq(ErrCode::InvalidArgument, manager.lock().unwrap().query(&invalid_query).unwrap_err());
+    for alias in &aliases {
+        remove_by_alias(alias).unwrap();
+    }
+}
+
+#[test]
+fn query_pages_without_skip_or_duplicate() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let _ = remove_all();
+    let function_name = function!().as_bytes();
+    let asset_num = 10;
+    for i in 0..asset_num {
+        let new_name = format!("{:?}{}", function_name, i);
+        add_default_asset(new_name.as_bytes(), new_name.as_bytes()).unwrap();
+    }
+
+    let query = QueryBuilder::new().return_limit(3).build();
+    let manager = asset_sdk::Manager::build().unwrap();
+    let mut manager = manager.lock().unwrap();
+    let mut aliases = vec![];
+    for (i, page) in manager.query_pages(&query).enumerate() {
+        let page = page.unwrap();
+        assert_eq!(if i < 3 { 3 } else { 1 }, page.len());
+        for asset in page {
+            assert!(asset.get(&Tag::Secret).is_none());
+            aliases.push(asset.get_bytes_attr(&Tag::Alias).unwrap().clone());
+        }
+    }
+    drop(manager);
+    assert_eq!(asset_num, aliases.len());
+    for (i, alias) in aliases.iter().enumerate() {
+        assert!(alias.eq(format!("{:?}{}", function_name, i).as_bytes()));
+    }
+
+    for i in 0..asset_num {
+        let new_name = format!("{:?}{}", function_name, i);
+        remove_by_alias(new_name.as_bytes()).unwrap();
+    }
+}
+
+#[test]
+fn query_page_with_changed_data() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let _ = remove_all();
+    let function_name = function!().as_bytes();
+    let asset_num = 4;
+    for i in 0..asset_num {
+        let new_name = format!("{:?}{}", function_name, i);
+        add_default_asset(new_name.as_bytes(), new_name.as_bytes()).unwrap();
+    }
+
+    let query = QueryBuilder::new().return_limit(2).build();
+    let page = asset_sdk::Manager::build().unwrap().lock().unwrap().query_page(&query).unwrap();
+    assert_eq!(2, page.assets.len());
+    let token = page.next_token.unwrap();
+
+    // Removing the Assets in the returned page does not shift the next page.
+    remove_by_alias(format!("{:?}{}", function_name, 0).as_bytes()).unwrap();
+    let query = QueryBuilder::new().return_limit(2).page_token(&token).build();
+    let page = asset_sdk::Manager::build().unwrap().lock().unwrap().query_page(&query).unwrap();
+    assert_eq!(2, page.assets.len());
+    assert!(page.assets[0].get_bytes_attr(&Tag::Alias).unwrap().eq(format!("{:?}{}", function_name, 2).as_bytes()));
+    assert!(page.next_token.is_none());
+
+    for i in 1..asset_num {
+        let new_name = format!("{:?}{}", function_name, i);
+        remove_by_alias(new_name.as_bytes()).unwrap();
+    }
+}
+
+#[test]
+fn query_page_with_invalid_token() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let _ = remove_all();
+    let function_name = function!().as_bytes();
+    for i in 0..2 {
+        let new_name = format!("{:?}{}", function_name, i);
+        add_default_asset(new_name.as_bytes(), new_name.as_bytes()).unwrap();
+    }
+
+    let query = QueryBuilder::new().return_limit(1).build();
+    let page = asset_sdk::Manager::build().unwrap().lock().unwrap().query_page(&query).unwrap();
+    let mut token = page.next_token.unwrap();
+
+    // The token can not be used for another query.
+    let query = QueryBuilder::new().return_limit(1).require_password_set(false).page_token(&token).build();
+    expect_error_eq(ErrCode::InvalidArgument,
+        asset_sdk::Manager::build().unwrap().lock().unwrap().query_page(&query).unwrap_err());
+
+    // The tampered token is rejected.
+    token[1] ^= 1;
+    let query = QueryBuilder::new().return_limit(1).page_token(&token).build();
+    expect_error_eq(ErrCode::InvalidArgument,
+        asset_sdk::Manager::build().unwrap().lock().unwrap().query_page(&query).unwrap_err());
+
+    // The empty page is rejected even if there are Assets matched.
+    let query = QueryBuilder::new().return_limit(0).build();
+    expect_error_eq(ErrCode::InvalidArgument,
+        asset_sdk::Manager::build().unwrap().lock().unwrap().query_page(&query).unwrap_err());
+
+    for i in 0..2 {
+        let new_name = format!("{:?}{}", function_name, i);
+        remove_by_alias(new_name.as_bytes()).unwrap();
+    }
+}
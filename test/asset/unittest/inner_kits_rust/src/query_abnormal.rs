@@ -41,7 +41,7 @@ fn query_invalid_accessibility() {
 fn query_invalid_auth_type() {
     let _lock = TEST_CASE_MUTEX.lock().unwrap();
     let mut query = AssetMap::new();
-    query.insert_attr(Tag::AuthType, (AuthType::None as u32) + 1);
+    query.insert_attr(Tag::AuthType, (AuthType::Biometric as u32) + 1);
     expect_error_eq(ErrCode::InvalidArgument, asset_sdk::Manager::build().unwrap().lock().unwrap().query(&query).unwrap_err());
 
     query.insert_attr(Tag::AuthType, (AuthType::Any as u32) + 1);
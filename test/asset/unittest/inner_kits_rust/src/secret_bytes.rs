@@ -0,0 +1,47 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *    http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use asset_sdk::*;
+
+#[test]
+fn secret_bytes_compare() {
+    let secret = SecretBytes::from_slice(function!().as_bytes());
+    assert!(secret.ct_eq(function!().as_bytes()));
+    assert!(!secret.ct_eq(&function!().as_bytes()[1..]));
+    assert_eq!(secret, secret.duplicate());
+    assert!(!format!("{:?}", secret).contains(function!()));
+}
+
+#[test]
+fn secret_bytes_extend_and_truncate() {
+    let mut secret = SecretBytes::zeroed(0);
+    let _ = secret.lock_memory();
+    secret.extend_from_slice(function!().as_bytes());
+    secret.extend_from_slice(&[0xAB; 64]);
+    assert_eq!(function!().len() + 64, secret.len());
+    secret.truncate(function!().len());
+    assert!(secret.ct_eq(function!().as_bytes()));
+}
+
+#[test]
+fn secret_bytes_into_map() {
+    let mut attrs = AssetMap::new();
+    attrs.insert_attr(Tag::Secret, SecretBytes::from_slice(function!().as_bytes()));
+    assert_eq!(function!().as_bytes(), attrs.get_bytes_attr(&Tag::Secret).unwrap());
+
+    let secret = attrs.take_secret_attr(&Tag::Secret).unwrap();
+    assert!(secret.ct_eq(function!().as_bytes()));
+    assert!(attrs.get(&Tag::Secret).is_none());
+}
@@ -0,0 +1,61 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *    http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::common::*;
+use asset_sdk::{serialization::*, *};
+
+fn build_all_tags_map(alias: &[u8]) -> AssetMap {
+    AssetAttributes::new(alias, alias)
+        .accessibility(Accessibility::DevicePowerOn)
+        .is_persistent(false)
+        .data_label_critical1(CRITICAL_LABEL1)
+        .data_label_normal1(NORMAL_LABEL1)
+        .build()
+}
+
+#[test]
+fn serialize_json_round_trip() {
+    let attrs = build_all_tags_map(function!().as_bytes());
+    let json = to_json_unredacted(&attrs).unwrap();
+    assert!(json.contains("\"Accessibility\""));
+    assert_eq!(attrs, from_json(&json).unwrap());
+}
+
+#[test]
+fn serialize_cbor_round_trip() {
+    let attrs = build_all_tags_map(function!().as_bytes());
+    let cbor = to_cbor_unredacted(&attrs).unwrap();
+    assert_eq!(attrs, from_cbor(&cbor).unwrap());
+    expect_error_eq(ErrCode::InvalidArgument, from_cbor(&cbor[..cbor.len() - 1]).unwrap_err());
+    expect_error_eq(ErrCode::InvalidArgument, from_cbor(&to_cbor(&attrs).unwrap()).unwrap_err());
+}
+
+#[test]
+fn serialize_redacted_json() {
+    let mut attrs = build_all_tags_map("serialize_redacted_json_alias".as_bytes());
+    attrs.insert_attr(Tag::Secret, "serialize_redacted_json_secret".as_bytes().to_vec());
+    attrs.insert_attr(Tag::AuthToken, vec![0xAB; 8]);
+    let json = to_json(&attrs).unwrap();
+    assert!(json.contains(REDACTED_PLACEHOLDER));
+    assert!(!json.contains("abababab"));
+    assert!(!json.contains(&"serialize_redacted_json_secret".bytes().map(|b| format!("{:02x}", b)).collect::<String>()));
+}
+
+#[test]
+fn deserialize_mismatched_type() {
+    expect_error_eq(ErrCode::InvalidArgument, from_json("{\"Alias\":true}").unwrap_err());
+    expect_error_eq(ErrCode::InvalidArgument, from_json("{\"UnknownTag\":1}").unwrap_err());
+    expect_error_eq(ErrCode::InvalidArgument, from_json("{\"Alias\":\"00\",\"Alias\":\"01\"}").unwrap_err());
+}
@@ -95,3 +95,94 @@ fn update_non_exist() {
 
     expect_error_eq(ErrCode::NotFound, asset_sdk::Manager::build().unwrap().lock().unwrap().update(&query, &update).unwrap_err());
 }
+
+#[test]
+fn update_with_expected_revision() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let alias = function!().as_bytes();
+    let secret = function!().as_bytes();
+    add_default_asset(alias, secret).unwrap();
+    let revision = query_attr_by_alias(alias).unwrap()[0].get_num_attr(&Tag::Revision).unwrap();
+
+    let mut query = AssetMap::new();
+    query.insert_attr(Tag::Alias, alias.to_owned());
+    query.insert_attr(Tag::Revision, revision);
+    let mut update = AssetMap::new();
+    update.insert_attr(Tag::DataLabelNormal1, alias.to_owned());
+    asset_sdk::Manager::build().unwrap().lock().unwrap().update(&query, &update).unwrap();
+    assert_eq!(revision + 1, query_attr_by_alias(alias).unwrap()[0].get_num_attr(&Tag::Revision).unwrap());
+
+    // The revision expected by the second update is stale.
+    expect_error_eq(
+        ErrCode::RevisionConflict,
+        asset_sdk::Manager::build().unwrap().lock().unwrap().update(&query, &update).unwrap_err(),
+    );
+
+    remove_by_alias(alias).unwrap();
+}
+
+#[test]
+fn update_with_revision_before_overwrite() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let alias = function!().as_bytes();
+    let secret = function!().as_bytes();
+    add_default_asset(alias, secret).unwrap();
+    let revision = query_attr_by_alias(alias).unwrap()[0].get_num_attr(&Tag::Revision).unwrap();
+
+    let mut query = AssetMap::new();
+    query.insert_attr(Tag::Alias, alias.to_owned());
+    query.insert_attr(Tag::Revision, revision);
+    let mut update = AssetMap::new();
+    update.insert_attr(Tag::DataLabelNormal1, alias.to_owned());
+    asset_sdk::Manager::build().unwrap().lock().unwrap().update(&query, &update).unwrap();
+
+    // The overwritten Asset continues the revision, so the revision read before the update does not match it again.
+    add_default_asset(alias, secret).unwrap();
+    assert_eq!(revision + 2, query_attr_by_alias(alias).unwrap()[0].get_num_attr(&Tag::Revision).unwrap());
+    expect_error_eq(
+        ErrCode::RevisionConflict,
+        asset_sdk::Manager::build().unwrap().lock().unwrap().update(&query, &update).unwrap_err(),
+    );
+
+    remove_by_alias(alias).unwrap();
+}
+
+#[test]
+fn rename_and_query_secret() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let alias = function!().as_bytes();
+    let new_alias = [alias, b"_renamed"].concat();
+    let secret = function!().as_bytes();
+    add_default_asset(alias, secret).unwrap();
+
+    let mut query = AssetMap::new();
+    query.insert_attr(Tag::Alias, alias.to_owned());
+    let manager = asset_sdk::Manager::build().unwrap();
+    manager.lock().unwrap().rename(&query, &new_alias).unwrap();
+    expect_error_eq(ErrCode::NotFound, query_attr_by_alias(alias).unwrap_err());
+    let res = query_all_by_alias(&new_alias).unwrap();
+    assert_eq!(secret, *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+
+    // The new alias of the second rename is occupied.
+    add_default_asset(alias, secret).unwrap();
+    expect_error_eq(ErrCode::Duplicated, manager.lock().unwrap().rename(&query, &new_alias).unwrap_err());
+
+    remove_by_alias(alias).unwrap();
+    remove_by_alias(&new_alias).unwrap();
+}
+
+#[test]
+fn transfer_to_same_space() {
+    let _lock = TEST_CASE_MUTEX.lock().unwrap();
+    let alias = function!().as_bytes();
+    add_default_asset(alias, function!().as_bytes()).unwrap();
+
+    let mut query = AssetMap::new();
+    query.insert_attr(Tag::Alias, alias.to_owned());
+    let manager = asset_sdk::Manager::build().unwrap();
+    let res = manager.lock().unwrap().transfer(&query, &AssetMap::new(), false);
+    expect_error_eq(ErrCode::InvalidArgument, res.unwrap_err());
+    assert_eq!(1, query_attr_by_alias(alias).unwrap().len());
+
+    remove_by_alias(alias).unwrap();
+}
@@ -97,7 +97,7 @@ fn update_query_invalid_auth_type() {
     update.insert_attr(Tag::Secret, function_name.to_owned());
     let mut query = AssetMap::new();
     query.insert_attr(Tag::Alias, function_name.to_owned());
-    query.insert_attr(Tag::AuthType, (AuthType::None as u32) + 1);
+    query.insert_attr(Tag::AuthType, (AuthType::Biometric as u32) + 1);
     expect_error_eq(
         ErrCode::InvalidArgument,
         asset_sdk::Manager::build().unwrap().lock().unwrap().update(&query, &update).unwrap_err(),
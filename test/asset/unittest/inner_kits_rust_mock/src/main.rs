@@ -0,0 +1,437 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *    http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! the unit test for the mock mode of inner kits
+use asset_sdk::mock::Manager;
+use asset_sdk::*;
+
+const SECRET: &[u8] = b"mock_secret";
+
+fn attrs_of(alias: &[u8]) -> AssetMap {
+    AssetAttributes::new(SECRET, alias).build()
+}
+
+fn query_of(alias: &[u8]) -> AssetMap {
+    QueryBuilder::new().alias(alias).build()
+}
+
+fn query_all_of(alias: &[u8]) -> AssetMap {
+    let mut query = query_of(alias);
+    query.insert_attr(Tag::ReturnType, ReturnType::All);
+    query
+}
+
+#[test]
+fn add_duplicated_and_overwrite() {
+    let mut manager = Manager::new();
+    manager.add(&attrs_of(b"alias")).unwrap();
+    assert_eq!(ErrCode::Duplicated, manager.add(&attrs_of(b"alias")).unwrap_err().code);
+
+    let mut attrs = AssetAttributes::new(b"overwritten", b"alias").build();
+    attrs.insert_attr(Tag::ConflictResolution, ConflictResolution::Overwrite);
+    manager.add(&attrs).unwrap();
+    let res = manager.query(&query_all_of(b"alias")).unwrap();
+    assert_eq!(1, res.len());
+    assert_eq!(b"overwritten".to_vec(), *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+}
+
+#[test]
+fn add_missing_required_tag() {
+    let mut manager = Manager::new();
+    let mut attrs = AssetMap::new();
+    attrs.insert_attr(Tag::Alias, b"alias".to_vec());
+    assert_eq!(ErrCode::InvalidArgument, manager.add(&attrs).unwrap_err().code);
+}
+
+#[test]
+fn group_isolation() {
+    let mut manager = Manager::new();
+    manager.add(&attrs_of(b"alias")).unwrap();
+    let mut attrs = attrs_of(b"alias");
+    attrs.insert_attr(Tag::GroupId, b"group_1234".to_vec());
+    manager.add(&attrs).unwrap();
+
+    assert_eq!(1, manager.query(&query_of(b"alias")).unwrap().len());
+    let mut query = query_of(b"alias");
+    query.insert_attr(Tag::GroupId, b"group_1234".to_vec());
+    assert_eq!(1, manager.query(&query).unwrap().len());
+    manager.remove(&query).unwrap();
+    assert_eq!(ErrCode::NotFound, manager.query(&query).unwrap_err().code);
+    assert_eq!(1, manager.query(&query_of(b"alias")).unwrap().len());
+}
+
+#[test]
+fn update_label_rules() {
+    let mut manager = Manager::new();
+    manager.add(&attrs_of(b"alias")).unwrap();
+
+    let mut update = AssetMap::new();
+    update.insert_attr(Tag::DataLabelCritical1, b"critical".to_vec());
+    assert_eq!(ErrCode::InvalidArgument, manager.update(&query_of(b"alias"), &update).unwrap_err().code);
+
+    let mut update = AssetMap::new();
+    update.insert_attr(Tag::Secret, b"updated".to_vec());
+    update.insert_attr(Tag::DataLabelNormal1, b"normal".to_vec());
+    manager.update(&query_of(b"alias"), &update).unwrap();
+    let res = manager.query(&query_all_of(b"alias")).unwrap();
+    assert_eq!(b"updated".to_vec(), *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+    assert_eq!(b"normal".to_vec(), *res[0].get_bytes_attr(&Tag::DataLabelNormal1).unwrap());
+}
+
+#[test]
+fn update_with_expected_revision() {
+    let mut manager = Manager::new();
+    manager.add(&attrs_of(b"alias")).unwrap();
+    let res = manager.query(&query_of(b"alias")).unwrap();
+    assert_eq!(0, res[0].get_num_attr(&Tag::Revision).unwrap());
+
+    let mut query = query_of(b"alias");
+    query.insert_attr(Tag::Revision, 0);
+    let mut update = AssetMap::new();
+    update.insert_attr(Tag::DataLabelNormal1, b"normal".to_vec());
+    manager.update(&query, &update).unwrap();
+    assert_eq!(ErrCode::RevisionConflict, manager.update(&query, &update).unwrap_err().code);
+
+    query.insert_attr(Tag::Revision, 1);
+    manager.update(&query, &update).unwrap();
+    let res = manager.query(&query_of(b"alias")).unwrap();
+    assert_eq!(2, res[0].get_num_attr(&Tag::Revision).unwrap());
+}
+
+#[test]
+fn update_with_revision_before_overwrite() {
+    let mut manager = Manager::new();
+    manager.add(&attrs_of(b"alias")).unwrap();
+    let mut query = query_of(b"alias");
+    query.insert_attr(Tag::Revision, 0);
+    let mut update = AssetMap::new();
+    update.insert_attr(Tag::DataLabelNormal1, b"normal".to_vec());
+    manager.update(&query, &update).unwrap();
+
+    let mut attrs = attrs_of(b"alias");
+    attrs.insert_attr(Tag::ConflictResolution, ConflictResolution::Overwrite);
+    manager.add(&attrs).unwrap();
+    let res = manager.query(&query_of(b"alias")).unwrap();
+    assert_eq!(2, res[0].get_num_attr(&Tag::Revision).unwrap());
+    assert_eq!(ErrCode::RevisionConflict, manager.update(&query, &update).unwrap_err().code);
+}
+
+#[test]
+fn query_with_max_access_count() {
+    let mut manager = Manager::new();
+    let mut attrs = attrs_of(b"alias");
+    attrs.insert_attr(Tag::MaxAccessCount, 2u32);
+    manager.add(&attrs).unwrap();
+
+    let res = manager.query(&query_all_of(b"alias")).unwrap();
+    assert_eq!(1, res[0].get_num_attr(&Tag::RemainingAccessCount).unwrap());
+    let res = manager.query(&query_all_of(b"alias")).unwrap();
+    assert!(!res[0].contains_key(&Tag::RemainingAccessCount));
+    let err = manager.query(&query_all_of(b"alias")).unwrap_err();
+    assert_eq!(ErrCode::AccessCountExhausted, err.code);
+
+    assert_eq!(ErrCode::NotFound, manager.remove(&query_of(b"alias")).unwrap_err().code);
+    assert_eq!(ErrCode::NotFound, manager.query(&query_all_of(b"alias")).unwrap_err().code);
+    manager.add(&attrs).unwrap();
+    let res = manager.query(&query_all_of(b"alias")).unwrap();
+    assert_eq!(1, res[0].get_num_attr(&Tag::RemainingAccessCount).unwrap());
+}
+
+#[test]
+fn query_ordered_with_limit_and_offset() {
+    let mut manager = Manager::new();
+    for i in 0..5u8 {
+        let mut attrs = attrs_of(&[b'a', i]);
+        attrs.insert_attr(Tag::DataLabelNormal1, vec![9 - i]);
+        manager.add(&attrs).unwrap();
+    }
+
+    let mut query = AssetMap::new();
+    query.insert_attr(Tag::ReturnOrderedBy, Tag::DataLabelNormal1 as u32);
+    query.insert_attr(Tag::ReturnLimit, 2u32);
+    query.insert_attr(Tag::ReturnOffset, 1u32);
+    let res = manager.query(&query).unwrap();
+    assert_eq!(2, res.len());
+    assert_eq!(vec![6u8], *res[0].get_bytes_attr(&Tag::DataLabelNormal1).unwrap());
+    assert_eq!(vec![7u8], *res[1].get_bytes_attr(&Tag::DataLabelNormal1).unwrap());
+    assert!(res[0].get_bytes_attr(&Tag::Secret).is_err());
+
+    let mut query = AssetMap::new();
+    query.insert_attr(Tag::ReturnLimit, 2u32);
+    assert_eq!(3, manager.query_pages(&query).count());
+
+    query.insert_attr(Tag::ReturnLimit, 0u32);
+    assert_eq!(ErrCode::InvalidArgument, manager.query_page(&query).unwrap_err().code);
+}
+
+#[test]
+fn auth_flow_with_fake_challenge() {
+    let mut manager = Manager::new();
+    let mut attrs = attrs_of(b"auth");
+    attrs.insert_attr(Tag::AuthType, AuthType::Any);
+    manager.add(&attrs).unwrap();
+
+    let mut query = query_all_of(b"auth");
+    assert_eq!(ErrCode::InvalidArgument, manager.query(&query).unwrap_err().code);
+
+    let challenge = manager.pre_query(&query_of(b"auth")).unwrap();
+    query.insert_attr(Tag::AuthChallenge, challenge.clone());
+    query.insert_attr(Tag::AuthToken, vec![0u8; 148]);
+    let res = manager.query(&query).unwrap();
+    assert_eq!(SECRET.to_vec(), *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+
+    let mut handle = AssetMap::new();
+    handle.insert_attr(Tag::AuthChallenge, challenge);
+    manager.post_query(&handle).unwrap();
+    assert_eq!(ErrCode::NotFound, manager.query(&query).unwrap_err().code);
+}
+
+#[test]
+fn batch_query_by_alias_and_filter() {
+    let mut manager = Manager::new();
+    for i in 0..3u8 {
+        let mut attrs = attrs_of(&[b'a', i]);
+        attrs.insert_attr(Tag::DataLabelNormal1, b"app".to_vec());
+        manager.add(&attrs).unwrap();
+    }
+    let mut auth = attrs_of(b"auth");
+    auth.insert_attr(Tag::AuthType, AuthType::Any);
+    auth.insert_attr(Tag::DataLabelNormal1, b"app".to_vec());
+    manager.add(&auth).unwrap();
+    let res = manager.batch_query(&[query_of(&[b'a', 0]), query_of(b"missing")]).unwrap();
+    assert_eq!(2, res.len());
+    assert_eq!(SECRET.to_vec(), *res[0].attributes.get_bytes_attr(&Tag::Secret).unwrap());
+    assert_eq!(Some(ErrCode::NotFound), res[1].result.code);
+    let mut filter = AssetMap::new();
+    filter.insert_attr(Tag::DataLabelNormal1, b"app".to_vec());
+    let res = manager.batch_query(&[filter]).unwrap();
+    assert_eq!(4, res.len());
+    assert_eq!(3, res.iter().filter(|item| item.result.is_success()).count());
+    let mut bad = query_of(b"x");
+    bad.insert_attr(Tag::ReturnLimit, 1u32);
+    assert_eq!(ErrCode::InvalidArgument, manager.batch_query(&[bad]).unwrap_err().code);
+}
+
+#[test]
+fn batch_query_filter_exceeding_chunk_size() {
+    let mut manager = Manager::new();
+    for i in 0..100u8 {
+        let mut attrs = attrs_of(&[b'a', i]);
+        attrs.insert_attr(Tag::DataLabelNormal1, b"app".to_vec());
+        manager.add(&attrs).unwrap();
+    }
+    let mut filter = AssetMap::new();
+    filter.insert_attr(Tag::DataLabelNormal1, b"app".to_vec());
+    let res = manager.batch_query(&[filter, query_of(&[b'a', 0])]).unwrap();
+    assert_eq!(2, res.len());
+    assert_eq!(Some(ErrCode::LimitExceeded), res[0].result.code);
+    assert!(res[1].result.is_success());
+}
+
+#[test]
+fn batch_remove_per_item_result() {
+    let mut manager = Manager::new();
+    manager.add(&attrs_of(b"alias")).unwrap();
+    let res = manager.batch_remove_with_results(&[query_of(b"alias"), query_of(b"missing")]).unwrap();
+    assert!(res[0].is_success());
+    assert_eq!(Some(ErrCode::NotFound), res[1].code);
+}
+
+#[test]
+fn batch_add_and_remove_with_err_info() {
+    let mut manager = Manager::new();
+    manager.add(&attrs_of(b"alias")).unwrap();
+    let err_info = manager.batch_add(&[attrs_of(b"alias"), attrs_of(b"added")]).unwrap();
+    assert_eq!(vec![(ErrCode::Duplicated as u32, 0)], err_info);
+    manager.batch_remove(&[query_of(b"added"), query_of(b"missing")]).unwrap();
+    assert_eq!(ErrCode::NotFound, manager.query(&query_of(b"added")).unwrap_err().code);
+}
+
+#[test]
+fn transact_rolled_back_on_failure() {
+    let mut manager = Manager::new();
+    manager.add(&attrs_of(b"alias")).unwrap();
+    let update = AssetUpdate::new().secret(b"updated").build();
+    let operations = [
+        TransactionOperation::Update(query_of(b"alias"), update),
+        TransactionOperation::Add(attrs_of(b"added")),
+        TransactionOperation::Add(attrs_of(b"alias")),
+    ];
+    let res = manager.transact(&operations).unwrap();
+    assert_eq!(1, res.len());
+    assert_eq!((2, Some(ErrCode::Duplicated)), (res[0].index, res[0].code));
+    assert_eq!(ErrCode::NotFound, manager.query(&query_of(b"added")).unwrap_err().code);
+    let res = manager.query(&query_all_of(b"alias")).unwrap();
+    assert_eq!(SECRET.to_vec(), *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+
+    let res = manager.transact(&operations[..2]).unwrap();
+    assert!(res.iter().all(|item| item.is_success()));
+    manager.query(&query_of(b"added")).unwrap();
+}
+
+#[test]
+fn rename_with_auth_token() {
+    let mut manager = Manager::new();
+    let mut attrs = attrs_of(b"auth");
+    attrs.insert_attr(Tag::AuthType, AuthType::Any);
+    manager.add(&attrs).unwrap();
+    manager.add(&attrs_of(b"other")).unwrap();
+
+    let mut query = query_of(b"auth");
+    assert_eq!(ErrCode::InvalidArgument, manager.rename(&query, b"renamed").unwrap_err().code);
+    assert_eq!(ErrCode::Duplicated, manager.rename(&query, b"other").unwrap_err().code);
+    let challenge = manager.pre_query(&query_of(b"auth")).unwrap();
+    query.insert_attr(Tag::AuthChallenge, challenge);
+    query.insert_attr(Tag::AuthToken, vec![0u8; 148]);
+    manager.rename(&query, b"renamed").unwrap();
+
+    assert_eq!(ErrCode::NotFound, manager.query(&query_of(b"auth")).unwrap_err().code);
+    let res = manager.query(&query_of(b"renamed")).unwrap();
+    assert_eq!(1, res[0].get_num_attr(&Tag::Revision).unwrap());
+}
+
+#[test]
+fn transfer_between_private_space_and_group() {
+    let mut manager = Manager::new();
+    manager.add(&attrs_of(b"alias")).unwrap();
+    let mut group_query = query_of(b"alias");
+    group_query.insert_attr(Tag::GroupId, b"group_1234".to_vec());
+    let mut target = AssetMap::new();
+    target.insert_attr(Tag::GroupId, b"group_1234".to_vec());
+
+    let same_space = manager.transfer(&query_of(b"alias"), &AssetMap::new(), false);
+    assert_eq!(ErrCode::InvalidArgument, same_space.unwrap_err().code);
+    manager.transfer(&query_of(b"alias"), &target, false).unwrap();
+    assert_eq!(ErrCode::NotFound, manager.query(&query_of(b"alias")).unwrap_err().code);
+    assert_eq!(1, manager.query(&group_query).unwrap().len());
+
+    manager.transfer(&group_query, &AssetMap::new(), true).unwrap();
+    assert_eq!(1, manager.query(&query_of(b"alias")).unwrap().len());
+    assert_eq!(1, manager.query(&group_query).unwrap().len());
+    assert_eq!(ErrCode::Duplicated, manager.transfer(&group_query, &AssetMap::new(), true).unwrap_err().code);
+}
+
+#[test]
+fn count_by_label() {
+    let mut manager = Manager::new();
+    for alias in [b"first".as_slice(), b"second", b"third"] {
+        let mut attrs = attrs_of(alias);
+        let label = if alias == b"third" { b"other".to_vec() } else { b"label".to_vec() };
+        attrs.insert_attr(Tag::DataLabelNormal1, label);
+        manager.add(&attrs).unwrap();
+    }
+
+    let mut query = AssetMap::new();
+    query.insert_attr(Tag::DataLabelNormal1, b"label".to_vec());
+    assert_eq!(2, manager.count(&query).unwrap());
+    assert_eq!(3, manager.count(&AssetMap::new()).unwrap());
+    assert!(manager.exists(&query_of(b"third")).unwrap());
+    assert!(!manager.exists(&query_of(b"fourth")).unwrap());
+}
+
+#[test]
+fn query_by_predicates() {
+    let mut manager = Manager::new();
+    for alias in [b"user_first".as_slice(), b"user_second", b"other"] {
+        manager.add(&attrs_of(alias)).unwrap();
+    }
+
+    assert_eq!(2, manager.count(&QueryBuilder::new().alias_prefix(b"user_").build()).unwrap());
+    let query = QueryBuilder::new().aliases(&[b"user_first".as_slice(), b"other", b"missing"]).build();
+    assert_eq!(2, manager.count(&query).unwrap());
+    let query = QueryBuilder::new().alias_prefix(b"user_").aliases(&[b"user_first".as_slice(), b"other"]).build();
+    assert_eq!(1, manager.count(&query).unwrap());
+    assert_eq!(3, manager.count(&QueryBuilder::new().create_time_range(Some(1), None).build()).unwrap());
+    assert_eq!(0, manager.count(&QueryBuilder::new().update_time_range(None, Some(1)).build()).unwrap());
+
+    let invalid_query = QueryBuilder::new().create_time_range(Some(2), Some(1)).build();
+    assert_eq!(ErrCode::InvalidArgument, manager.count(&invalid_query).unwrap_err().code);
+    let invalid_query = QueryBuilder::new().label_prefix(Tag::Secret, b"user_").build();
+    assert_eq!(ErrCode::InvalidArgument, manager.count(&invalid_query).unwrap_err().code);
+}
+
+#[test]
+fn query_ordered_by_keys_descending() {
+    let mut manager = Manager::new();
+    for (alias, label) in [(b"a".as_slice(), b"1".as_slice()), (b"b", b"2"), (b"c", b"1")] {
+        let mut attrs = attrs_of(alias);
+        attrs.insert_attr(Tag::DataLabelNormal1, label.to_vec());
+        attrs.insert_attr(Tag::DataLabelNormal2, alias.to_vec());
+        manager.add(&attrs).unwrap();
+    }
+
+    let query = QueryBuilder::new()
+        .return_ordered_by_keys(&[
+            (OrderKey::DataLabelNormal1, ReturnOrder::Descending),
+            (OrderKey::DataLabelNormal2, ReturnOrder::Descending),
+        ])
+        .build();
+    let aliases: Vec<Vec<u8>> =
+        manager.query(&query).unwrap().iter().map(|res| res.get_bytes_attr(&Tag::Alias).unwrap().clone()).collect();
+    assert_eq!(vec![b"b".to_vec(), b"c".to_vec(), b"a".to_vec()], aliases);
+
+    let query = QueryBuilder::new()
+        .return_ordered_by_keys(&[
+            (OrderKey::DataLabelNormal1, ReturnOrder::Descending),
+            (OrderKey::DataLabelNormal2, ReturnOrder::Ascending),
+        ])
+        .build();
+    let aliases: Vec<Vec<u8>> =
+        manager.query(&query).unwrap().iter().map(|res| res.get_bytes_attr(&Tag::Alias).unwrap().clone()).collect();
+    assert_eq!(vec![b"b".to_vec(), b"a".to_vec(), b"c".to_vec()], aliases);
+
+    let duplicated_keys =
+        [(OrderKey::DataLabelNormal1, ReturnOrder::Ascending), (OrderKey::DataLabelNormal1, ReturnOrder::Descending)];
+    let invalid_query = QueryBuilder::new().return_ordered_by_keys(&duplicated_keys).build();
+    assert_eq!(ErrCode::InvalidArgument, manager.query(&invalid_query).unwrap_err().code);
+    let invalid_query = QueryBuilder::new()
+        .return_ordered_by(OrderKey::UpdateTime)
+        .return_ordered_by_keys(&[(OrderKey::DataLabelNormal1, ReturnOrder::Ascending)])
+        .build();
+    assert_eq!(ErrCode::InvalidArgument, manager.query(&invalid_query).unwrap_err().code);
+    let invalid_query = QueryBuilder::new()
+        .return_ordered_by_keys(&[(OrderKey::DataLabelNormal1, ReturnOrder::Ascending)])
+        .return_order(ReturnOrder::Descending)
+        .build();
+    assert_eq!(ErrCode::InvalidArgument, manager.query(&invalid_query).unwrap_err().code);
+}
+
+#[test]
+fn error_with_reason_of_family() {
+    let mut manager = Manager::new();
+    let err = manager.query(&query_of(b"missing")).unwrap_err();
+    assert_eq!(ErrReason::AssetNotFound, err.reason);
+    assert_eq!(Some(err.code), err.reason.family());
+    #[allow(deprecated)]
+    let call_chain = err.call_chain.clone();
+    assert_eq!(err.call_chain(), call_chain);
+
+    manager.add(&attrs_of(b"alias")).unwrap();
+    let err = manager.update(&query_of(b"alias"), &AssetMap::new()).unwrap_err();
+    assert_eq!(ErrReason::MissingTag, err.reason);
+    assert_eq!(Some(err.code), err.reason.family());
+
+    let err = manager.rename(&query_of(b"missing"), b"renamed").unwrap_err();
+    assert_eq!(ErrReason::AssetNotFound, err.reason);
+    assert_eq!(Some(err.code), err.reason.family());
+
+    let mut query = query_of(b"alias");
+    query.insert_attr(Tag::Revision, 5);
+    let mut update = AssetMap::new();
+    update.insert_attr(Tag::DataLabelNormal1, b"normal".to_vec());
+    let err = manager.update(&query, &update).unwrap_err();
+    assert_eq!(ErrReason::RevisionMismatch, err.reason);
+    assert_eq!(Some(err.code), err.reason.family());
+}
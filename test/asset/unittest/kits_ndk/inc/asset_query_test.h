@@ -24,6 +24,7 @@ int AssetQueryTest004(void);
 int AssetQueryTest005(void);
 int AssetQueryTest006(void);
 int AssetQueryTest007(void);
+int AssetQueryTest008(void);
 }
 
 #endif // ASSET_QUERY_TEST_H
@@ -172,4 +172,37 @@ HWTEST_F(AssetQueryTest, AssetQueryTest007, TestSize.Level0)
     };
     ASSERT_EQ(ASSET_INVALID_ARGUMENT, OH_Asset_Query(attr, ARRAY_SIZE(attr), nullptr));
 }
+
+/**
+ * @tc.name: AssetQueryTest.AssetQueryTest008
+ * @tc.desc: Add asset, then count and check existence with correct attr, expect success
+ * @tc.type: FUNC
+ * @tc.result:0
+ */
+HWTEST_F(AssetQueryTest, AssetQueryTest008, TestSize.Level0)
+{
+    Asset_Blob funcName = { .size = strlen(__func__), .data = reinterpret_cast<uint8_t*>(const_cast<char*>(__func__)) };
+    Asset_Attr queryAttr[] = {
+        { .tag = ASSET_TAG_ALIAS, .value.blob = funcName },
+    };
+    bool exists = true;
+    ASSERT_EQ(ASSET_SUCCESS, OH_Asset_Exists(queryAttr, ARRAY_SIZE(queryAttr), &exists));
+    ASSERT_FALSE(exists);
+
+    Asset_Attr addAttr[] = {
+        { .tag = ASSET_TAG_ALIAS, .value.blob = funcName },
+        { .tag = ASSET_TAG_SECRET, .value.blob = funcName },
+        { .tag = ASSET_TAG_ACCESSIBILITY, .value.u32 = ASSET_ACCESSIBILITY_DEVICE_POWERED_ON },
+    };
+    ASSERT_EQ(ASSET_SUCCESS, OH_Asset_Add(addAttr, ARRAY_SIZE(addAttr)));
+
+    uint32_t count = 0;
+    ASSERT_EQ(ASSET_SUCCESS, OH_Asset_Count(queryAttr, ARRAY_SIZE(queryAttr), &count));
+    ASSERT_EQ(1, count);
+    ASSERT_EQ(ASSET_SUCCESS, OH_Asset_Exists(queryAttr, ARRAY_SIZE(queryAttr), &exists));
+    ASSERT_TRUE(exists);
+    ASSERT_EQ(ASSET_PARAM_VERIFICATION_FAILED, OH_Asset_Count(queryAttr, ARRAY_SIZE(queryAttr), nullptr));
+
+    ASSERT_EQ(ASSET_SUCCESS, RemoveByAliasNdk(__func__));
+}
 }
\ No newline at end of file
@@ -150,7 +150,7 @@ fn encrypt_and_decrypt() {
 
     // decrypt data
     let plaintext = Crypto::decrypt(&secret_key, &cipher, &aad).unwrap();
-    assert!(plaintext.eq(&msg));
+    assert!(plaintext.ct_eq(&msg));
 
     // delete key
     let _ = secret_key.delete();
@@ -14,6 +14,7 @@
  */
 
 //! the module test for core_service
+mod loopback;
 mod operations;
 
 #[macro_export]
@@ -0,0 +1,194 @@
+/*
+ * Copyright (c) 2026 Huawei Device Co., Ltd.
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *    http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! the module test for the loopback transport
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use asset_common::CallingInfo;
+use asset_definition::*;
+use asset_ipc::IpcCode;
+use asset_sdk::{AsyncManager, Manager, RetryPolicy, Transport};
+use asset_service::loopback::LoopbackTransport;
+use ipc::parcel::MsgParcel;
+use crate::function;
+
+fn build_manager() -> Manager {
+    Manager::with_transport(Box::new(LoopbackTransport::new(CallingInfo::new_self())))
+}
+
+fn build_query(alias: &[u8]) -> AssetMap {
+    let mut query = AssetMap::new();
+    query.insert_attr(Tag::Alias, alias.to_vec());
+    query
+}
+
+#[test]
+fn test_loopback_add_query_update_remove() {
+    let func_name = function!().as_bytes();
+    let mut manager = build_manager();
+    let mut attrs = build_query(func_name);
+    attrs.insert_attr(Tag::Secret, func_name.to_vec());
+    attrs.insert_attr(Tag::DataLabelNormal1, func_name.to_vec());
+    manager.add(&attrs).unwrap();
+
+    let mut update = AssetMap::new();
+    update.insert_attr(Tag::Secret, b"new_secret".to_vec());
+    manager.update(&build_query(func_name), &update).unwrap();
+
+    let mut query = build_query(func_name);
+    query.insert_attr(Tag::ReturnType, ReturnType::All);
+    let res = manager.query(&query).unwrap();
+    assert_eq!(1, res.len());
+    assert_eq!(b"new_secret".to_vec(), *res[0].get_bytes_attr(&Tag::Secret).unwrap());
+    assert_eq!(func_name.to_vec(), *res[0].get_bytes_attr(&Tag::DataLabelNormal1).unwrap());
+
+    manager.remove(&build_query(func_name)).unwrap();
+    assert_eq!(ErrCode::NotFound, manager.query(&build_query(func_name)).unwrap_err().code);
+}
+
+#[test]
+fn test_loopback_get_capabilities() {
+    let capabilities = build_manager().get_capabilities().unwrap();
+    assert!(capabilities.supports_tag(Tag::Alias));
+}
+
+#[test]
+fn test_async_manager_concurrent_requests() {
+    let func_name = function!().as_bytes();
+    let manager = AsyncManager::with_transport_factory(|| -> Result<Box<dyn Transport>> {
+        Ok(Box::new(LoopbackTransport::new(CallingInfo::new_self())))
+    });
+    let aliases: Vec<Vec<u8>> = (0..4).map(|i| [func_name, format!("_{}", i).as_bytes()].concat()).collect();
+
+    let handles: Vec<_> = aliases.iter().map(|alias| {
+        let (manager, mut attrs) = (manager.clone(), build_query(alias));
+        attrs.insert_attr(Tag::Secret, alias.clone());
+        ylong_runtime::spawn(async move { manager.add(&attrs).await })
+    }).collect();
+    for handle in handles {
+        ylong_runtime::block_on(handle).unwrap().unwrap();
+    }
+
+    ylong_runtime::block_on(async {
+        for alias in aliases.iter() {
+            assert_eq!(1, manager.query(&build_query(alias)).await.unwrap().len());
+            manager.remove(&build_query(alias)).await.unwrap();
+        }
+    });
+}
+
+// The transport which reports the service as unavailable for the first requests.
+struct FlakyTransport {
+    failures: u32,
+    inner: LoopbackTransport,
+}
+
+impl Transport for FlakyTransport {
+    fn send_request(&mut self, ipc_code: IpcCode, data: &mut MsgParcel) -> Result<MsgParcel> {
+        if ipc_code != IpcCode::GetCapabilities && self.failures > 0 {
+            self.failures -= 1;
+            return Err(AssetError::new(ErrCode::ServiceUnavailable, "flaky".to_string(), function!()));
+        }
+        self.inner.send_request(ipc_code, data)
+    }
+
+    fn reconnect(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+fn build_flaky_manager(failures: u32, attempts: Arc<Mutex<Vec<u32>>>) -> Manager {
+    let transport = FlakyTransport { failures, inner: LoopbackTransport::new(CallingInfo::new_self()) };
+    let mut manager = Manager::with_transport(Box::new(transport));
+    let retry_policy = RetryPolicy { max_attempts: 3, initial_backoff: Duration::from_millis(1), ..Default::default() }
+        .on_retry(move |event| attempts.lock().unwrap().push(event.attempt));
+    manager.set_retry_policy(retry_policy);
+    manager
+}
+
+#[test]
+fn test_retry_idempotent_request() {
+    let func_name = function!().as_bytes();
+    let attempts = Arc::new(Mutex::new(vec![]));
+    let mut manager = build_flaky_manager(2, attempts.clone());
+    assert_eq!(ErrCode::NotFound, manager.remove(&build_query(func_name)).unwrap_err().code);
+    assert_eq!(vec![1, 2], *attempts.lock().unwrap());
+}
+
+#[test]
+fn test_retry_query() {
+    let func_name = function!().as_bytes();
+    let attempts = Arc::new(Mutex::new(vec![]));
+    let mut manager = build_flaky_manager(2, attempts.clone());
+    assert_eq!(ErrCode::NotFound, manager.query(&build_query(func_name)).unwrap_err().code);
+    assert_eq!(vec![1, 2], *attempts.lock().unwrap());
+
+    attempts.lock().unwrap().clear();
+    let mut manager = build_flaky_manager(3, attempts.clone());
+    assert_eq!(ErrCode::ServiceUnavailable, manager.pre_query(&build_query(func_name)).unwrap_err().code);
+    assert_eq!(vec![1, 2], *attempts.lock().unwrap());
+}
+
+#[test]
+fn test_retry_add_only_with_overwrite() {
+    let func_name = function!().as_bytes();
+    let attempts = Arc::new(Mutex::new(vec![]));
+    let mut manager = build_flaky_manager(2, attempts.clone());
+    let mut attrs = build_query(func_name);
+    attrs.insert_attr(Tag::Secret, func_name.to_vec());
+    assert_eq!(ErrCode::ServiceUnavailable, manager.add(&attrs).unwrap_err().code);
+    assert!(attempts.lock().unwrap().is_empty());
+
+    let mut manager = build_flaky_manager(2, attempts.clone());
+    attrs.insert_attr(Tag::ConflictResolution, ConflictResolution::Overwrite);
+    manager.add(&attrs).unwrap();
+    assert_eq!(vec![1, 2], *attempts.lock().unwrap());
+    manager.remove(&build_query(func_name)).unwrap();
+}
+
+// The transport which records the requests sent to the service.
+struct RecordingTransport {
+    requests: Arc<Mutex<Vec<IpcCode>>>,
+    inner: LoopbackTransport,
+}
+
+impl Transport for RecordingTransport {
+    fn send_request(&mut self, ipc_code: IpcCode, data: &mut MsgParcel) -> Result<MsgParcel> {
+        self.requests.lock().unwrap().push(ipc_code);
+        self.inner.send_request(ipc_code, data)
+    }
+
+    fn reconnect(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_add_secret_exceeding_service_limit_without_ipc() {
+    let func_name = function!().as_bytes();
+    let requests = Arc::new(Mutex::new(vec![]));
+    let transport =
+        RecordingTransport { requests: requests.clone(), inner: LoopbackTransport::new(CallingInfo::new_self()) };
+    let mut manager = Manager::with_transport(Box::new(transport));
+    let capabilities = manager.get_capabilities().unwrap();
+
+    let mut attrs = build_query(func_name);
+    attrs.insert_attr(Tag::Secret, vec![0; capabilities.max_secret_size as usize + 1]);
+    assert_eq!(ErrCode::InvalidArgument, manager.add(&attrs).unwrap_err().code);
+    assert!(*requests.lock().unwrap() == vec![IpcCode::GetCapabilities]);
+}
@@ -15,15 +15,15 @@
 
 /// the module test for core_service
 use asset_common::CallingInfo;
-use asset_definition::{AssetMap, Result};
+use asset_definition::{AssetMap, Result, SecretBytes};
 use asset_db_operator::types::DbMap;
 use crate::operations::operation_add::*;
 
 
 #[allow(dead_code)]
 /// encrypt_secret_stub
-pub fn encrypt_secret_stub(calling_info: &CallingInfo, db_data: &mut DbMap) -> Result<()> {
-    encrypt_secret(calling_info, db_data)
+pub fn encrypt_secret_stub(calling_info: &CallingInfo, db_data: &mut DbMap, secret: &SecretBytes) -> Result<()> {
+    encrypt_secret(calling_info, db_data, secret)
 }
 
 #[allow(dead_code)]
@@ -33,8 +33,9 @@ pub fn resolve_conflict_stub(
     db: &mut Database,
     attrs: &AssetMap,
     query: &DbMap,
-    db_data: &mut DbMap) -> Result<()> {
-    resolve_conflict(calling, db, attrs, query, db_data)
+    db_data: &mut DbMap,
+    secret: &SecretBytes) -> Result<()> {
+    resolve_conflict(calling, db, attrs, query, db_data, secret)
 }
 
 #[allow(dead_code)]
@@ -64,5 +65,5 @@ pub fn modify_sync_type_stub(db: &mut DbMap) -> Result<()> {
 #[allow(dead_code)]
 /// local_add_stub
 pub fn local_add_stub(attributes: &AssetMap, calling_info: &CallingInfo) -> Result<()> {
-    local_add(attributes, calling_info)
+    local_add(&mut attributes.clone(), calling_info)
 }
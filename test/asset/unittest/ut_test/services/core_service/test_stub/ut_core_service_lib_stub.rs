@@ -15,14 +15,14 @@
 
 /// the module test for core_service
 use asset_common::CallingInfo;
-use asset_definition::{AssetMap, Result, SyncResult};
+use asset_definition::{AssetMap, AssetWithSecret, Result, SyncResult};
 use crate::operations;
 pub use operations::ut_operation_add_stub::*;
 
 #[allow(dead_code)]
 /// add stub
 pub fn add_stub(calling_info: &CallingInfo, attributes: &AssetMap) -> Result<()> {
-    operations::add(calling_info, attributes)
+    operations::add(calling_info, &mut attributes.clone())
 }
 
 #[allow(dead_code)]
@@ -39,7 +39,7 @@ pub fn post_query_stub(calling_info: &CallingInfo, attributes: &AssetMap) -> Res
 
 #[allow(dead_code)]
 /// query_stub
-pub fn query_stub(calling_info: &CallingInfo, attributes: &AssetMap) -> Result<Vec<AssetMap>> {
+pub fn query_stub(calling_info: &CallingInfo, attributes: &AssetMap) -> Result<Vec<AssetWithSecret>> {
     operations::query(calling_info, attributes)
 }
 
@@ -58,6 +58,6 @@ pub fn remove_stub(calling_info: &CallingInfo, attributes: &AssetMap) -> Result<
 #[allow(dead_code)]
 /// update_stub
 pub fn update_stub(calling_info: &CallingInfo, attributes: &AssetMap, attributes_to_update: &AssetMap) -> Result<()> {
-    operations::update(calling_info, attributes, attributes_to_update)
+    operations::update(calling_info, attributes, &mut attributes_to_update.clone())
 }
 